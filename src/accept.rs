@@ -0,0 +1,575 @@
+use super::{
+    error::*, media_type::*, media_type_list::*, names::*, params::*, parse::*, qvalue::*,
+};
+use std::{borrow::Cow, cmp::Ordering};
+
+/// A media range with its quality value, as found in the HTTP `Accept` header.
+///
+/// The `q` parameter and any parameters after it are not included in the media range.
+/// ([RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-12.5.1))
+///
+/// ```
+/// use mediatype::{MediaType, WeightedRange};
+///
+/// let range = WeightedRange::parse("text/html; level=1; q=0.5; ext=1").unwrap();
+/// assert_eq!(range.media_type(), &MediaType::parse("text/html; level=1").unwrap());
+/// assert_eq!(range.quality(), 0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WeightedRange<'a> {
+    media_type: MediaType<'a>,
+    quality: QValue,
+}
+
+impl<'a> WeightedRange<'a> {
+    /// Constructs a `WeightedRange` from a media range string.
+    ///
+    /// If the `q` parameter is omitted, the quality value is `1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string fails to be parsed or the quality value is not valid.
+    /// The wildcard type only accepts the wildcard subtype, so `*/html` is rejected
+    /// with [`MediaTypeError::InvalidSubtypeName`].
+    pub fn parse<'s: 'a>(s: &'s str) -> Result<Self, ParseError> {
+        let (indices, _) = Indices::parse(s)?;
+        let mut media_type = MediaType::from_indices(s, &indices);
+        if media_type.ty == _STAR && media_type.subty != _STAR {
+            return Err(ParseError::at(
+                MediaTypeError::InvalidSubtypeName,
+                s,
+                indices.subty(),
+            ));
+        }
+        let q = media_type.params.iter().position(|&(name, _)| name == Q);
+        let quality = if let Some(q) = q {
+            let quality = QValue::new(media_type.params[q].1.as_str()).ok_or_else(|| {
+                let [_, _, start, end] = indices.params()[q];
+                ParseError::at(MediaTypeError::InvalidParamValue, s, start..end)
+            })?;
+            let mut params = media_type.params.into_owned();
+            params.truncate(q);
+            media_type.params = if params.is_empty() {
+                Cow::Borrowed(&[])
+            } else {
+                Cow::Owned(params)
+            };
+            quality
+        } else {
+            QValue::ONE
+        };
+        Ok(Self {
+            media_type,
+            quality,
+        })
+    }
+
+    /// Returns the media range.
+    #[must_use]
+    pub const fn media_type(&self) -> &MediaType<'a> {
+        &self.media_type
+    }
+
+    /// Returns the media range, consuming `self`.
+    #[must_use]
+    pub fn into_media_type(self) -> MediaType<'a> {
+        self.media_type
+    }
+
+    /// Returns the quality value between `0` and `1`.
+    #[must_use]
+    pub fn quality(&self) -> f32 {
+        self.quality.as_f32()
+    }
+
+    /// Returns the quality value.
+    ///
+    /// ```
+    /// # use mediatype::{QValue, WeightedRange};
+    /// let range = WeightedRange::parse("text/html; q=0.25").unwrap();
+    /// assert_eq!(range.qvalue(), QValue::from_thousandths(250).unwrap());
+    /// ```
+    #[must_use]
+    pub const fn qvalue(&self) -> QValue {
+        self.quality
+    }
+
+    /// Returns `true` if `media_type` matches the media range.
+    ///
+    /// `*/*` matches any media type and `type/*` matches any subtype of the type.
+    /// `type/*+suffix` and `*/*+suffix` additionally require the suffix.
+    /// Every parameter of the range must appear in `media_type` with the same value,
+    /// but `media_type` may have other parameters.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, WeightedRange};
+    /// let html = MediaType::parse("text/html; level=1; charset=UTF-8").unwrap();
+    /// assert!(WeightedRange::parse("*/*").unwrap().matches(&html));
+    /// assert!(WeightedRange::parse("text/*").unwrap().matches(&html));
+    /// assert!(WeightedRange::parse("text/html; level=1").unwrap().matches(&html));
+    /// assert!(!WeightedRange::parse("text/html; level=2").unwrap().matches(&html));
+    /// assert!(!WeightedRange::parse("text/plain").unwrap().matches(&html));
+    ///
+    /// let problem = MediaType::parse("application/problem+json").unwrap();
+    /// assert!(WeightedRange::parse("application/*+json").unwrap().matches(&problem));
+    /// assert!(!WeightedRange::parse("application/*+xml").unwrap().matches(&problem));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, media_type: &MediaType) -> bool {
+        let range = &self.media_type;
+        let suffix = || range.suffix.is_none() || range.suffix == media_type.suffix;
+        let essence = if range.ty == _STAR {
+            suffix()
+        } else if range.subty == _STAR {
+            range.ty == media_type.ty && suffix()
+        } else {
+            range.eq_essence(media_type.essence())
+        };
+        essence
+            && range
+                .params()
+                .all(|(name, value)| media_type.get_param(name) == Some(value))
+    }
+
+    fn specificity(&self) -> (u8, usize) {
+        let suffix = u8::from(self.media_type.suffix.is_some());
+        let level = if self.media_type.ty == _STAR {
+            suffix
+        } else if self.media_type.subty == _STAR {
+            2 + suffix
+        } else {
+            4
+        };
+        (level, self.media_type.params.len())
+    }
+
+    fn cmp_precedence(&self, other: &Self) -> Ordering {
+        other
+            .quality
+            .cmp(&self.quality)
+            .then_with(|| other.specificity().cmp(&self.specificity()))
+    }
+}
+
+/// A parsed HTTP `Accept` header.
+///
+/// The media ranges are sorted in precedence order:
+/// higher quality values first, and more specific ranges first among the same quality.
+///
+/// ```
+/// use mediatype::AcceptHeader;
+///
+/// let accept = AcceptHeader::parse("text/*;q=0.3, text/html;q=0.7, text/html;level=1, */*;q=0.5")
+///     .unwrap();
+///
+/// let ranges = accept
+///     .iter()
+///     .map(|range| (range.media_type().to_string(), range.quality()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     ranges,
+///     [
+///         ("text/html; level=1".to_string(), 1.0),
+///         ("text/html".to_string(), 0.7),
+///         ("*/*".to_string(), 0.5),
+///         ("text/*".to_string(), 0.3),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AcceptHeader<'a> {
+    ranges: Vec<WeightedRange<'a>>,
+}
+
+impl<'a> AcceptHeader<'a> {
+    /// Parses a comma-separated list of media ranges.
+    ///
+    /// Empty list elements are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the media ranges fails to be parsed.
+    ///
+    /// The span of the error is a byte range in the whole header.
+    pub fn parse<'s: 'a>(s: &'s str) -> Result<Self, ParseError> {
+        let mut list = MediaTypeList::new(s);
+        let mut ranges = Vec::new();
+        while let Some((offset, item)) = list.next_str() {
+            if !item.is_empty() {
+                ranges.push(WeightedRange::parse(item).map_err(|err| err.offset(offset))?);
+            }
+        }
+        sort_by_precedence(&mut ranges);
+        Ok(Self { ranges })
+    }
+
+    /// Parses a comma-separated list of media ranges, skipping invalid ones.
+    ///
+    /// Returns the valid media ranges and the errors of the invalid ones.
+    /// The span of an error is a byte range in the whole header.
+    ///
+    /// ```
+    /// # use mediatype::{AcceptHeader, MediaTypeError};
+    /// let (accept, errors) = AcceptHeader::parse_lenient("text/html, text, */*; q=0.5");
+    /// assert_eq!(accept.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(errors[0].kind(), MediaTypeError::InvalidTypeName);
+    /// assert_eq!(errors[0].span(), 11..15);
+    /// ```
+    #[must_use]
+    pub fn parse_lenient<'s: 'a>(s: &'s str) -> (Self, Vec<ParseError>) {
+        let mut list = MediaTypeList::new(s);
+        let mut ranges = Vec::new();
+        let mut errors = Vec::new();
+        while let Some((offset, item)) = list.next_str() {
+            if !item.is_empty() {
+                match WeightedRange::parse(item) {
+                    Ok(range) => ranges.push(range),
+                    Err(err) => errors.push(err.offset(offset)),
+                }
+            }
+        }
+        sort_by_precedence(&mut ranges);
+        (Self { ranges }, errors)
+    }
+
+    /// Returns the quality value of `media_type`.
+    ///
+    /// The most specific media range matching `media_type` determines the value:
+    /// `type/subtype` takes precedence over `type/*`, which takes precedence over `*/*`,
+    /// and ranges with more parameters take precedence among the same level.
+    ///
+    /// Returns `None` if no media range matches.
+    ///
+    /// ```
+    /// # use mediatype::{AcceptHeader, MediaType};
+    /// let accept = AcceptHeader::parse(
+    ///     "text/*;q=0.3, text/html;q=0.7, text/html;level=1, text/html;level=2;q=0.4, */*;q=0.5",
+    /// )
+    /// .unwrap();
+    ///
+    /// let quality = |s| accept.quality_of(&MediaType::parse(s).unwrap()).unwrap().to_string();
+    /// assert_eq!(quality("text/html;level=1"), "1");
+    /// assert_eq!(quality("text/html"), "0.7");
+    /// assert_eq!(quality("text/plain"), "0.3");
+    /// assert_eq!(quality("image/jpeg"), "0.5");
+    /// assert_eq!(quality("text/html;level=2"), "0.4");
+    /// assert_eq!(quality("text/html;level=3"), "0.7");
+    /// ```
+    #[must_use]
+    pub fn quality_of(&self, media_type: &MediaType) -> Option<QValue> {
+        self.ranges
+            .iter()
+            .filter(|range| range.matches(media_type))
+            // The first of the most specific ranges, which has the highest quality among them.
+            .min_by(|a, b| b.specificity().cmp(&a.specificity()))
+            .map(WeightedRange::qvalue)
+    }
+
+    /// Selects the best variant for the media ranges.
+    ///
+    /// Each variant has a server-side quality value (`qs`). The variant with the highest
+    /// product of the client quality value from [`quality_of`](#method.quality_of) and `qs`
+    /// is selected; ties are resolved by the higher `qs`, and then by the order of `variants`.
+    ///
+    /// Returns `None` if no variant is acceptable.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, AcceptHeader, MediaType, QValue};
+    /// let accept = AcceptHeader::parse("text/html, application/json").unwrap();
+    /// let variants = [
+    ///     (MediaType::new(APPLICATION, JSON), QValue::new("0.5").unwrap()),
+    ///     (MediaType::new(TEXT, HTML), QValue::ONE),
+    /// ];
+    /// assert_eq!(accept.negotiate(&variants), Some(&variants[1].0));
+    ///
+    /// let accept = AcceptHeader::parse("text/plain, */*; q=0").unwrap();
+    /// assert_eq!(accept.negotiate(&variants), None);
+    /// ```
+    #[must_use]
+    pub fn negotiate<'v, 'm>(
+        &self,
+        variants: &'v [(MediaType<'m>, QValue)],
+    ) -> Option<&'v MediaType<'m>> {
+        variants
+            .iter()
+            .filter_map(|(media_type, qs)| {
+                let q = self.quality_of(media_type)?;
+                let score = u32::from(q.as_thousandths()) * u32::from(qs.as_thousandths());
+                (score > 0).then_some((score, *qs, media_type))
+            })
+            .min_by(|(a, a_qs, _), (b, b_qs, _)| b.cmp(a).then_with(|| b_qs.cmp(a_qs)))
+            .map(|(_, _, media_type)| media_type)
+    }
+
+    /// Returns an iterator over the media ranges in precedence order.
+    pub fn iter(&self) -> std::slice::Iter<'_, WeightedRange<'a>> {
+        self.ranges.iter()
+    }
+
+    /// Returns the media ranges in precedence order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[WeightedRange<'a>] {
+        &self.ranges
+    }
+
+    /// Returns the number of the media ranges.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if there are no media ranges.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+/// Sorts media ranges in precedence order.
+///
+/// Higher quality values come first. Among the same quality, `type/subtype` comes before
+/// `type/*`, which comes before `*/*`, and ranges with more parameters come first.
+/// The sort is stable, so equivalent ranges keep their order.
+///
+/// ```
+/// # use mediatype::{sort_by_precedence, WeightedRange};
+/// let mut ranges = ["*/*", "text/*", "text/html; q=0.5", "text/html; level=1"]
+///     .map(|s| WeightedRange::parse(s).unwrap());
+/// sort_by_precedence(&mut ranges);
+///
+/// let ranges = ranges.map(|range| range.media_type().to_string());
+/// assert_eq!(ranges, ["text/html; level=1", "text/*", "*/*", "text/html"]);
+/// ```
+pub fn sort_by_precedence(ranges: &mut [WeightedRange]) {
+    ranges.sort_by(WeightedRange::cmp_precedence);
+}
+
+impl<'a> IntoIterator for AcceptHeader<'a> {
+    type Item = WeightedRange<'a>;
+    type IntoIter = std::vec::IntoIter<WeightedRange<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.into_iter()
+    }
+}
+
+impl<'a, 'b> IntoIterator for &'b AcceptHeader<'a> {
+    type Item = &'b WeightedRange<'a>;
+    type IntoIter = std::slice::Iter<'b, WeightedRange<'a>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.ranges.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::params::ReadParams;
+
+    #[test]
+    fn weighted_range() {
+        let range = WeightedRange::parse("text/html").unwrap();
+        assert_eq!(range.quality(), 1.0);
+        assert_eq!(range.media_type().to_string(), "text/html");
+
+        let range = WeightedRange::parse("text/html; Q=0.25").unwrap();
+        assert_eq!(range.quality(), 0.25);
+        assert_eq!(range.media_type().params().count(), 0);
+
+        assert_eq!(
+            WeightedRange::parse("text/html; q=2"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "2",
+                13..14
+            ))
+        );
+        assert_eq!(
+            WeightedRange::parse("text/html; q=\"0.5\""),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "\"0.5\"",
+                13..18
+            ))
+        );
+        assert_eq!(
+            WeightedRange::parse("*/html"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidSubtypeName,
+                "html",
+                2..6
+            ))
+        );
+        assert_eq!(
+            AcceptHeader::parse("text/plain, */html").map(|_| ()),
+            Err(ParseError::new(
+                MediaTypeError::InvalidSubtypeName,
+                "html",
+                14..18
+            ))
+        );
+        assert!(WeightedRange::parse("*/*+xml").is_ok());
+    }
+
+    #[test]
+    fn parse_lenient() {
+        let (accept, errors) =
+            AcceptHeader::parse_lenient("text/html;q=2, image/png, ;, application/json;q=0.5");
+        assert_eq!(
+            accept
+                .iter()
+                .map(|range| range.media_type().to_string())
+                .collect::<Vec<_>>(),
+            ["image/png", "application/json"]
+        );
+        assert_eq!(
+            errors,
+            [
+                ParseError::new(MediaTypeError::InvalidParamValue, "2", 12..13),
+                ParseError::new(MediaTypeError::InvalidTypeName, "", 26..26),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches() {
+        let matches = |range, media_type| {
+            WeightedRange::parse(range)
+                .unwrap()
+                .matches(&MediaType::parse(media_type).unwrap())
+        };
+        assert!(matches("*/*", "application/json"));
+        assert!(matches("*/*; q=0", "application/json"));
+        assert!(matches("IMAGE/*", "image/svg+xml"));
+        assert!(matches("image/svg+xml", "Image/SVG+XML; charset=UTF-8"));
+        assert!(matches(
+            "text/plain; charset=\"UTF-8\"",
+            "text/plain; Charset=UTF-8"
+        ));
+        assert!(!matches("image/svg", "image/svg+xml"));
+        assert!(!matches("image/svg+xml", "image/svg"));
+        assert!(!matches("text/*", "image/png"));
+        assert!(matches("application/*+json", "application/vnd.api+JSON"));
+        assert!(matches("*/*+xml", "image/svg+xml"));
+        assert!(!matches("application/*+json", "application/json"));
+        assert!(!matches("application/*+json", "text/vnd.api+json"));
+        assert!(!matches("*/*+xml", "application/json"));
+        assert!(!matches("text/plain; charset=UTF-8", "text/plain"));
+        assert!(!matches(
+            "*/*; charset=UTF-8",
+            "text/plain; charset=US-ASCII"
+        ));
+    }
+
+    #[test]
+    fn quality_of() {
+        let accept = AcceptHeader::parse("text/*;q=0.5, text/plain;q=0, */*;q=0.1").unwrap();
+        let quality = |s| accept.quality_of(&MediaType::parse(s).unwrap());
+        assert_eq!(quality("text/plain"), Some(QValue::ZERO));
+        assert_eq!(quality("text/html"), QValue::new("0.5"));
+        assert_eq!(quality("image/png"), QValue::new("0.1"));
+        assert_eq!(
+            AcceptHeader::parse("text/*")
+                .unwrap()
+                .quality_of(&MediaType::parse("image/png").unwrap()),
+            None
+        );
+
+        let accept = AcceptHeader::parse(
+            "*/*;q=0.1, */*+json;q=0.2, application/*;q=0.3, application/*+json;q=0.4",
+        )
+        .unwrap();
+        let quality = |s| accept.quality_of(&MediaType::parse(s).unwrap());
+        assert_eq!(quality("application/problem+json"), QValue::new("0.4"));
+        assert_eq!(quality("application/problem+xml"), QValue::new("0.3"));
+        assert_eq!(quality("text/vnd.api+json"), QValue::new("0.2"));
+        assert_eq!(quality("text/plain"), QValue::new("0.1"));
+    }
+
+    #[test]
+    fn negotiate() {
+        let variants = [
+            (MediaType::new(TEXT, PLAIN), QValue::new("0.4").unwrap()),
+            (MediaType::new(TEXT, HTML), QValue::new("0.8").unwrap()),
+            (
+                MediaType::new(APPLICATION, JSON),
+                QValue::new("0.8").unwrap(),
+            ),
+            (MediaType::new(IMAGE, PNG), QValue::ZERO),
+        ];
+        let negotiate = |s| {
+            AcceptHeader::parse(s)
+                .unwrap()
+                .negotiate(&variants)
+                .map(ToString::to_string)
+        };
+        assert_eq!(negotiate("*/*"), Some("text/html".into()));
+        assert_eq!(
+            negotiate("application/json, */*; q=0.9"),
+            Some("application/json".into())
+        );
+        assert_eq!(
+            negotiate("text/*; q=0.5, image/png"),
+            Some("text/html".into())
+        );
+        assert_eq!(
+            negotiate("text/plain, text/html; q=0.5"),
+            Some("text/html".into())
+        );
+        assert_eq!(
+            negotiate("text/plain, text/html; q=0.25"),
+            Some("text/plain".into())
+        );
+        assert_eq!(
+            negotiate("text/plain; q=0.5, application/json; q=0.2"),
+            Some("text/plain".into())
+        );
+        assert_eq!(negotiate("image/png"), None);
+        assert_eq!(negotiate(""), None);
+    }
+
+    #[test]
+    fn accept_header() {
+        assert!(AcceptHeader::parse("").unwrap().is_empty());
+        assert!(AcceptHeader::parse(" , ,").unwrap().is_empty());
+        assert_eq!(
+            AcceptHeader::parse("text/html, invalid"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "invalid",
+                11..18
+            ))
+        );
+
+        let accept = AcceptHeader::parse(
+            "text/html, application/xhtml+xml, application/xml;q=0.9, */*;q=0.8",
+        )
+        .unwrap();
+        let ranges = accept
+            .iter()
+            .map(|range| range.media_type().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            [
+                "text/html",
+                "application/xhtml+xml",
+                "application/xml",
+                "*/*"
+            ]
+        );
+
+        let accept =
+            AcceptHeader::parse("*/*, text/*, text/plain;format=flowed, text/plain").unwrap();
+        let ranges = accept
+            .into_iter()
+            .map(|range| range.into_media_type().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranges,
+            ["text/plain; format=flowed", "text/plain", "text/*", "*/*"]
+        );
+    }
+}
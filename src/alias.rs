@@ -0,0 +1,234 @@
+use super::{media_type::*, media_type_buf::*, name::*, names::*, params::*};
+
+// A pair of the top-level type and the subtype.
+type Essence = (Name<'static>, Name<'static>);
+
+// The deprecated or unofficial essences and their preferred equivalents.
+const ALIASES: &[(Essence, Essence)] = &[
+    // RFC 7303, Section 9.2
+    ((TEXT, XML), (APPLICATION, XML)),
+    // RFC 9239, Section 6
+    ((APPLICATION, ECMASCRIPT), (TEXT, JAVASCRIPT)),
+    ((APPLICATION, JAVASCRIPT), (TEXT, JAVASCRIPT)),
+    ((APPLICATION, x_::ECMASCRIPT), (TEXT, JAVASCRIPT)),
+    ((APPLICATION, x_::JAVASCRIPT), (TEXT, JAVASCRIPT)),
+    ((TEXT, ECMASCRIPT), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_0), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_1), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_2), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_3), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_4), (TEXT, JAVASCRIPT)),
+    ((TEXT, JAVASCRIPT1_5), (TEXT, JAVASCRIPT)),
+    ((TEXT, JSCRIPT), (TEXT, JAVASCRIPT)),
+    ((TEXT, LIVESCRIPT), (TEXT, JAVASCRIPT)),
+    ((TEXT, x_::ECMASCRIPT), (TEXT, JAVASCRIPT)),
+    ((TEXT, x_::JAVASCRIPT), (TEXT, JAVASCRIPT)),
+    // Common misspellings and pre-registration names
+    ((IMAGE, JPG), (IMAGE, JPEG)),
+    ((IMAGE, x_::ICON), (IMAGE, vnd::MICROSOFT_ICON)),
+    ((APPLICATION, x_::GZIP), (APPLICATION, GZIP)),
+];
+
+// The historical `x-` types and their registered equivalents.
+// `application/x-www-form-urlencoded` is intentionally absent, as it has no registered equivalent.
+const UNREGISTERED: &[(Essence, Essence)] = &[
+    ((APPLICATION, x_::FONT_OTF), (FONT, OTF)),
+    ((APPLICATION, x_::FONT_TTF), (FONT, TTF)),
+    ((APPLICATION, x_::FONT_WOFF), (FONT, WOFF)),
+    ((APPLICATION, x_::JSON), (APPLICATION, JSON)),
+    ((APPLICATION, x_::PDF), (APPLICATION, PDF)),
+    ((APPLICATION, x_::YAML), (APPLICATION, YAML)),
+    ((APPLICATION, x_::ZIP_COMPRESSED), (APPLICATION, ZIP)),
+    ((AUDIO, x_::AAC), (AUDIO, AAC)),
+    ((AUDIO, x_::FLAC), (AUDIO, FLAC)),
+    ((AUDIO, x_::M4A), (AUDIO, MP4)),
+    ((IMAGE, x_::MS_BMP), (IMAGE, BMP)),
+    ((IMAGE, x_::PNG), (IMAGE, PNG)),
+    ((TEXT, x_::CSV), (TEXT, CSV)),
+    ((TEXT, x_::MARKDOWN), (TEXT, MARKDOWN)),
+    ((TEXT, x_::YAML), (APPLICATION, YAML)),
+    ((VIDEO, x_::MATROSKA), (VIDEO, MATROSKA)),
+];
+
+impl<'a> MediaType<'a> {
+    /// Replaces a well-known deprecated or unofficial essence with its preferred equivalent.
+    ///
+    /// Returns `true` if the media type has been replaced.
+    /// The suffix and the parameters are kept as they are.
+    ///
+    /// The known aliases are `text/xml` ([RFC 7303](https://www.rfc-editor.org/rfc/rfc7303#section-9.2)),
+    /// the obsolete JavaScript types ([RFC 9239](https://www.rfc-editor.org/rfc/rfc9239#section-6)),
+    /// `image/jpg`, `image/x-icon` and `application/x-gzip`.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// let mut media_type = MediaType::parse("application/x-javascript; charset=UTF-8").unwrap();
+    /// assert!(media_type.normalize_alias());
+    /// assert_eq!(media_type.to_string(), "text/javascript; charset=UTF-8");
+    ///
+    /// let mut media_type = MediaType::new(IMAGE, PNG);
+    /// assert!(!media_type.normalize_alias());
+    /// ```
+    pub fn normalize_alias(&mut self) -> bool {
+        if let Some((ty, subty)) = alias_target(self.ty, self.subty) {
+            self.ty = ty;
+            self.subty = subty;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces a historical type with the legacy `x-` prefix with its registered equivalent.
+    ///
+    /// Returns `true` if the media type has been replaced.
+    /// This covers the `x-` types of [`normalize_alias`](#method.normalize_alias)
+    /// and common ones like `image/x-png`, `text/x-markdown` and `application/x-font-woff`.
+    /// The types without a registered equivalent, like `application/x-www-form-urlencoded`,
+    /// are kept as they are.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let mut media_type = MediaType::parse("text/x-markdown; charset=UTF-8").unwrap();
+    /// assert!(media_type.normalize_unregistered_prefix());
+    /// assert_eq!(media_type.to_string(), "text/markdown; charset=UTF-8");
+    ///
+    /// let mut media_type = MediaType::parse("application/x-www-form-urlencoded").unwrap();
+    /// assert!(!media_type.normalize_unregistered_prefix());
+    /// ```
+    pub fn normalize_unregistered_prefix(&mut self) -> bool {
+        if let Some((ty, subty)) = unregistered_target(self.ty, self.subty) {
+            self.ty = ty;
+            self.subty = subty;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl MediaTypeBuf {
+    /// Replaces a well-known deprecated or unofficial essence with its preferred equivalent.
+    ///
+    /// Returns `true` if the media type has been replaced.
+    /// See [`MediaType::normalize_alias`](./struct.MediaType.html#method.normalize_alias)
+    /// for the known aliases.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let mut media_type: MediaTypeBuf = "Image/JPG".parse().unwrap();
+    /// assert!(media_type.normalize_alias());
+    /// assert_eq!(media_type.as_str(), "image/jpeg");
+    /// ```
+    pub fn normalize_alias(&mut self) -> bool {
+        self.normalize_essence(|essence| essence.normalize_alias())
+    }
+
+    /// Replaces a historical type with the legacy `x-` prefix with its registered equivalent.
+    ///
+    /// Returns `true` if the media type has been replaced.
+    /// See [`MediaType::normalize_unregistered_prefix`](./struct.MediaType.html#method.normalize_unregistered_prefix)
+    /// for the details.
+    pub fn normalize_unregistered_prefix(&mut self) -> bool {
+        self.normalize_essence(|essence| essence.normalize_unregistered_prefix())
+    }
+
+    fn normalize_essence(&mut self, normalize: impl FnOnce(&mut MediaType) -> bool) -> bool {
+        let mut essence = self.essence();
+        if !normalize(&mut essence) {
+            return false;
+        }
+        *self = Self::build(
+            essence.ty,
+            essence.subty,
+            essence.suffix,
+            self.params(),
+            false,
+        )
+        .expect("all names should be valid");
+        true
+    }
+}
+
+pub(crate) fn alias_target(ty: Name, subty: Name) -> Option<Essence> {
+    ALIASES
+        .iter()
+        .find(|&&((t, s), _)| t == ty && s == subty)
+        .map(|&(_, target)| target)
+}
+
+fn unregistered_target(ty: Name, subty: Name) -> Option<Essence> {
+    if !MediaType::new(ty, subty).has_unregistered_prefix() {
+        return None;
+    }
+    UNREGISTERED
+        .iter()
+        .find(|&&((t, s), _)| t == ty && s == subty)
+        .map(|&(_, target)| target)
+        .or_else(|| alias_target(ty, subty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn normalize_alias() {
+        let normalize = |s: &str| {
+            let mut media_type = MediaTypeBuf::from_str(s).unwrap();
+            (media_type.normalize_alias(), media_type.to_string())
+        };
+        assert_eq!(normalize("text/xml"), (true, "application/xml".into()));
+        assert_eq!(
+            normalize("TEXT/XML; charset=UTF-8"),
+            (true, "application/xml; charset=UTF-8".into())
+        );
+        assert_eq!(
+            normalize("application/javascript"),
+            (true, "text/javascript".into())
+        );
+        assert_eq!(
+            normalize("text/x-javascript"),
+            (true, "text/javascript".into())
+        );
+        assert_eq!(normalize("image/jpg"), (true, "image/jpeg".into()));
+        assert_eq!(
+            normalize("image/x-icon"),
+            (true, "image/vnd.microsoft.icon".into())
+        );
+        assert_eq!(normalize("image/jpeg"), (false, "image/jpeg".into()));
+        assert_eq!(
+            normalize("text/xml+zip"),
+            (true, "application/xml+zip".into())
+        );
+        assert_eq!(normalize("text/*"), (false, "text/*".into()));
+    }
+
+    #[test]
+    fn normalize_unregistered_prefix() {
+        let normalize = |s: &str| {
+            let mut media_type = MediaTypeBuf::from_str(s).unwrap();
+            (
+                media_type.normalize_unregistered_prefix(),
+                media_type.to_string(),
+            )
+        };
+        assert_eq!(normalize("image/X-PNG"), (true, "image/png".into()));
+        assert_eq!(
+            normalize("application/x-font-woff"),
+            (true, "font/woff".into())
+        );
+        assert_eq!(normalize("text/x-yaml"), (true, "application/yaml".into()));
+        assert_eq!(
+            normalize("application/x-javascript; charset=UTF-8"),
+            (true, "text/javascript; charset=UTF-8".into())
+        );
+        assert_eq!(
+            normalize("application/x-www-form-urlencoded"),
+            (false, "application/x-www-form-urlencoded".into())
+        );
+        assert_eq!(normalize("text/xml"), (false, "text/xml".into()));
+        assert_eq!(normalize("image/png"), (false, "image/png".into()));
+    }
+}
@@ -0,0 +1,388 @@
+use super::{error::*, media_type_buf::*};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+/// A bounded LRU cache of parsed media types.
+///
+/// Raw header values are used as keys, and parsed values are shared via [`Arc`].
+/// When the cache is full, the least recently used entry is evicted.
+///
+/// ```
+/// use mediatype::{names::*, ParseCache};
+///
+/// let mut cache = ParseCache::new(64);
+/// let a = cache.get_or_parse(b"text/html; charset=UTF-8").unwrap();
+/// let b = cache.get_or_parse(b"text/html; charset=UTF-8").unwrap();
+/// assert!(std::sync::Arc::ptr_eq(&a, &b));
+/// assert_eq!(a.subty(), HTML);
+/// assert_eq!(cache.len(), 1);
+/// ```
+///
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+#[derive(Debug)]
+pub struct ParseCache {
+    capacity: usize,
+    // The entries form a doubly linked list from `head` (the most recently used)
+    // to `tail` (the least recently used), linked by the indices in `entries`.
+    entries: Vec<CacheEntry>,
+    slots: HashMap<Box<[u8]>, usize>,
+    head: usize,
+    tail: usize,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    key: Box<[u8]>,
+    value: Arc<MediaTypeBuf>,
+    prev: usize,
+    next: usize,
+}
+
+// The index of no entry.
+const NIL: usize = usize::MAX;
+
+impl ParseCache {
+    /// Constructs a `ParseCache` holding at most `capacity` entries.
+    ///
+    /// The memory is allocated as entries are added.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::new(),
+            slots: HashMap::new(),
+            head: NIL,
+            tail: NIL,
+        }
+    }
+
+    /// Returns the cached value for `bytes`, parsing and caching it on a miss.
+    ///
+    /// Failed parses are not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    pub fn get_or_parse(&mut self, bytes: &[u8]) -> Result<Arc<MediaTypeBuf>, ParseError> {
+        if let Some(&index) = self.slots.get(bytes) {
+            self.unlink(index);
+            self.push_front(index);
+            return Ok(self.entries[index].value.clone());
+        }
+
+        let value = Arc::new(MediaTypeBuf::parse_bytes(bytes)?);
+        if self.capacity == 0 {
+            return Ok(value);
+        }
+        let entry = CacheEntry {
+            key: bytes.into(),
+            value: value.clone(),
+            prev: NIL,
+            next: NIL,
+        };
+        let index = if self.entries.len() < self.capacity {
+            self.entries.push(entry);
+            self.entries.len() - 1
+        } else {
+            // Reuses the entry of the least recently used value.
+            let index = self.tail;
+            self.unlink(index);
+            let evicted = std::mem::replace(&mut self.entries[index], entry);
+            self.slots.remove(&evicted.key);
+            index
+        };
+        self.slots.insert(bytes.into(), index);
+        self.push_front(index);
+        Ok(value)
+    }
+
+    /// Returns the number of cached entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the maximum number of entries.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.slots.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let CacheEntry { prev, next, .. } = self.entries[index];
+        if prev == NIL {
+            self.head = next;
+        } else {
+            self.entries[prev].next = next;
+        }
+        if next == NIL {
+            self.tail = prev;
+        } else {
+            self.entries[next].prev = prev;
+        }
+    }
+
+    fn push_front(&mut self, index: usize) {
+        self.entries[index].prev = NIL;
+        self.entries[index].next = self.head;
+        if self.head == NIL {
+            self.tail = index;
+        } else {
+            self.entries[self.head].prev = index;
+        }
+        self.head = index;
+    }
+}
+
+/// A thread-safe version of [`ParseCache`].
+///
+/// ```
+/// use mediatype::SyncParseCache;
+/// use std::{sync::Arc, thread};
+///
+/// let cache = Arc::new(SyncParseCache::new(64));
+/// let handles = (0..4)
+///     .map(|_| {
+///         let cache = cache.clone();
+///         thread::spawn(move || cache.get_or_parse(b"application/json").unwrap())
+///     })
+///     .collect::<Vec<_>>();
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap().as_str(), "application/json");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SyncParseCache(Mutex<ParseCache>);
+
+impl SyncParseCache {
+    /// Constructs a `SyncParseCache` holding at most `capacity` entries.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self(Mutex::new(ParseCache::new(capacity)))
+    }
+
+    /// Returns the cached value for `bytes`, parsing and caching it on a miss.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    pub fn get_or_parse(&self, bytes: &[u8]) -> Result<Arc<MediaTypeBuf>, ParseError> {
+        self.lock().get_or_parse(bytes)
+    }
+
+    /// Returns the number of cached entries.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ParseCache> {
+        // The cache is always left in a consistent state, so a poisoned lock is still usable.
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// A thread-safe interner of media types.
+///
+/// Equal raw header values are deduplicated into one shared [`MediaTypeBuf`].
+/// Unlike [`ParseCache`], entries are never evicted, so the interner should only be fed
+/// a bounded set of values, or cleared periodically. Untrusted headers with unbounded
+/// variety are better handled by [`ParseCache`].
+///
+/// ```
+/// use mediatype::{MediaTypeBuf, MediaTypeInterner};
+/// use std::sync::Arc;
+///
+/// let interner = MediaTypeInterner::new();
+/// let a = interner.intern(b"application/json").unwrap();
+/// let b = interner.intern(b"application/json").unwrap();
+/// assert!(Arc::ptr_eq(&a, &b));
+///
+/// let c = interner.intern_value("application/json".parse().unwrap());
+/// assert!(Arc::ptr_eq(&a, &c));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct MediaTypeInterner(Mutex<HashMap<Box<[u8]>, Arc<MediaTypeBuf>>>);
+
+impl MediaTypeInterner {
+    /// Constructs an empty `MediaTypeInterner`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared value for `bytes`, parsing and storing it if it is not interned yet.
+    ///
+    /// Failed parses are not stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    pub fn intern(&self, bytes: &[u8]) -> Result<Arc<MediaTypeBuf>, ParseError> {
+        if let Some(value) = self.lock().get(bytes) {
+            return Ok(value.clone());
+        }
+        let value = Arc::new(MediaTypeBuf::parse_bytes(bytes)?);
+        Ok(self.lock().entry(bytes.into()).or_insert(value).clone())
+    }
+
+    /// Returns the shared value equal to `media_type`, storing it if it is not interned yet.
+    ///
+    /// Values are looked up by [`MediaTypeBuf::as_str`].
+    pub fn intern_value(&self, media_type: MediaTypeBuf) -> Arc<MediaTypeBuf> {
+        let mut entries = self.lock();
+        if let Some(value) = entries.get(media_type.as_str().as_bytes()) {
+            return value.clone();
+        }
+        let key = media_type.as_str().as_bytes().into();
+        entries.entry(key).or_insert(Arc::new(media_type)).clone()
+    }
+
+    /// Returns the number of interned values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns `true` if there are no interned values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all interned values.
+    ///
+    /// The values shared so far remain valid.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Box<[u8]>, Arc<MediaTypeBuf>>> {
+        // The map is always left in a consistent state, so a poisoned lock is still usable.
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn get_or_parse() {
+        let mut cache = ParseCache::new(2);
+        let a = cache.get_or_parse(b"text/plain").unwrap();
+        let b = cache.get_or_parse(b"text/plain").unwrap();
+        assert!(Arc::ptr_eq(&a, &b));
+        assert_eq!(cache.len(), 1);
+
+        assert_eq!(
+            cache.get_or_parse(b"text/plain;;").unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidParams, ";;", 10..12)
+        );
+        assert_eq!(
+            cache.get_or_parse(b"text/\xffplain").unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidSubtypeName, "�", 5..6)
+        );
+        assert_eq!(
+            cache
+                .get_or_parse(b"text/plain; title=\"\xff\"")
+                .unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidParamValue, "�", 19..20)
+        );
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evict() {
+        let mut cache = ParseCache::new(2);
+        let a = cache.get_or_parse(b"text/plain").unwrap();
+        cache.get_or_parse(b"text/html").unwrap();
+        cache.get_or_parse(b"text/plain").unwrap();
+        cache.get_or_parse(b"text/css").unwrap();
+        assert_eq!(cache.len(), 2);
+
+        assert!(Arc::ptr_eq(&a, &cache.get_or_parse(b"text/plain").unwrap()));
+        cache.get_or_parse(b"text/html").unwrap();
+        cache.get_or_parse(b"text/css").unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(!Arc::ptr_eq(
+            &a,
+            &cache.get_or_parse(b"text/plain").unwrap()
+        ));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut cache = ParseCache::new(0);
+        assert_eq!(
+            cache.get_or_parse(b"text/plain").unwrap().as_str(),
+            "text/plain"
+        );
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn large_capacity() {
+        let mut cache = ParseCache::new(usize::MAX);
+        cache.get_or_parse(b"text/plain").unwrap();
+        assert_eq!(cache.len(), 1);
+        cache.clear();
+        assert!(cache.is_empty());
+        cache.get_or_parse(b"text/html").unwrap();
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn intern() {
+        let interner = MediaTypeInterner::new();
+        assert!(interner.is_empty());
+        let a = interner.intern(b"text/html; charset=utf-8").unwrap();
+        let b = interner.intern(b"TEXT/HTML; charset=utf-8").unwrap();
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert_eq!(a, b);
+        assert_eq!(
+            interner.intern(b"text/html;;").unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidParams, ";;", 9..11)
+        );
+        assert_eq!(interner.len(), 2);
+
+        let c = interner.intern_value(MediaTypeBuf::from_str("text/html; charset=utf-8").unwrap());
+        assert!(Arc::ptr_eq(&a, &c));
+        let d = interner.intern_value(MediaTypeBuf::from_str("image/png").unwrap());
+        assert!(Arc::ptr_eq(&d, &interner.intern(b"image/png").unwrap()));
+        assert_eq!(interner.len(), 3);
+
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!(a.as_str(), "text/html; charset=utf-8");
+    }
+}
@@ -0,0 +1,184 @@
+use super::{error::*, media_type::*, media_type_buf::*, params::*, value::*};
+use std::{
+    borrow::Cow,
+    collections::BTreeMap,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+/// A media type stored in the canonical form for fast comparisons.
+///
+/// The names are converted to lowercase, the duplicated parameters are removed
+/// keeping the last value, the parameters are sorted by name,
+/// and the values are quoted only if necessary.
+/// Equivalent media types have the same canonical form,
+/// so comparing and hashing compare the bytes of the string without case folding.
+/// It dereferences to [`MediaTypeBuf`].
+///
+/// ```
+/// use mediatype::CanonicalMediaType;
+/// use std::collections::HashMap;
+///
+/// let mut handlers = HashMap::new();
+/// let html: CanonicalMediaType = "text/html; charset=utf-8".parse().unwrap();
+/// handlers.insert(html, "html");
+///
+/// let key: CanonicalMediaType = "TEXT/HTML; CHARSET=\"utf-8\"".parse().unwrap();
+/// assert_eq!(key.as_str(), "text/html; charset=utf-8");
+/// assert_eq!(handlers.get(&key), Some(&"html"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CanonicalMediaType(MediaTypeBuf);
+
+impl CanonicalMediaType {
+    /// Constructs a `CanonicalMediaType` from a media type.
+    #[must_use]
+    pub fn new(media_type: &MediaTypeBuf) -> Self {
+        let params = media_type.params().collect::<BTreeMap<_, _>>();
+        let values = params
+            .values()
+            .map(|value| match value.unquoted_str() {
+                Cow::Borrowed(s) => Value::quote(s),
+                Cow::Owned(s) => Cow::Owned(Value::quote(&s).into_owned()),
+            })
+            .collect::<Vec<_>>();
+        let params = params
+            .keys()
+            .zip(&values)
+            .map(|(&name, value)| (name, Value::new_unchecked(value)));
+        Self(
+            MediaTypeBuf::build(
+                media_type.ty(),
+                media_type.subty(),
+                media_type.suffix(),
+                params,
+                true,
+            )
+            .expect("`media_type` should be valid"),
+        )
+    }
+
+    /// Returns the inner [`MediaTypeBuf`].
+    #[must_use]
+    pub fn into_inner(self) -> MediaTypeBuf {
+        self.0
+    }
+}
+
+impl Deref for CanonicalMediaType {
+    type Target = MediaTypeBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<MediaTypeBuf> for CanonicalMediaType {
+    fn as_ref(&self) -> &MediaTypeBuf {
+        &self.0
+    }
+}
+
+impl From<MediaTypeBuf> for CanonicalMediaType {
+    fn from(media_type: MediaTypeBuf) -> Self {
+        Self::new(&media_type)
+    }
+}
+
+impl From<&MediaTypeBuf> for CanonicalMediaType {
+    fn from(media_type: &MediaTypeBuf) -> Self {
+        Self::new(media_type)
+    }
+}
+
+impl From<MediaType<'_>> for CanonicalMediaType {
+    fn from(media_type: MediaType) -> Self {
+        Self::new(&media_type.into())
+    }
+}
+
+impl From<CanonicalMediaType> for MediaTypeBuf {
+    fn from(media_type: CanonicalMediaType) -> Self {
+        media_type.0
+    }
+}
+
+impl FromStr for CanonicalMediaType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MediaTypeBuf::from_str(s).map(Self::from)
+    }
+}
+
+impl PartialEq for CanonicalMediaType {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for CanonicalMediaType {}
+
+impl PartialEq<MediaTypeBuf> for CanonicalMediaType {
+    fn eq(&self, other: &MediaTypeBuf) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<MediaType<'_>> for CanonicalMediaType {
+    fn eq(&self, other: &MediaType) -> bool {
+        self.0 == *other
+    }
+}
+
+impl Hash for CanonicalMediaType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl fmt::Display for CanonicalMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{names::*, values::*};
+
+    #[test]
+    fn canonical() {
+        let canonical = |s: &str| CanonicalMediaType::from_str(s).unwrap();
+        for (s, expected) in [
+            ("text/plain", "text/plain"),
+            ("IMAGE/SVG+XML", "image/svg+xml"),
+            (
+                "text/plain; Format=Flowed; CHARSET=\"UTF-8\"",
+                "text/plain; charset=UTF-8; format=Flowed",
+            ),
+            ("text/plain; a=1; b=2; A=3", "text/plain; a=3; b=2"),
+            ("text/plain; title=\"a\\ b\"", "text/plain; title=\"a b\""),
+            ("text/plain; title=\"\\\"\"", "text/plain; title=\"\\\"\""),
+        ] {
+            let media_type = canonical(s);
+            assert_eq!(media_type.as_str(), expected);
+            assert_eq!(media_type, MediaTypeBuf::from_str(s).unwrap());
+            assert_eq!(canonical(expected), media_type);
+        }
+
+        let a = canonical("text/plain; charset=utf-8; format=fixed");
+        let b = CanonicalMediaType::from(MediaType::from_parts(
+            TEXT,
+            PLAIN,
+            None,
+            &[(FORMAT, FIXED), (CHARSET, Value::new("\"utf-8\"").unwrap())],
+        ));
+        assert_eq!(a, b);
+        assert_ne!(a, canonical("text/plain; charset=UTF-8; format=fixed"));
+        assert_eq!(MediaTypeBuf::from(b).as_str(), a.as_str());
+    }
+}
@@ -0,0 +1,234 @@
+use super::{media_type::*, media_type_buf::*, names::*, params::*, value::*};
+use std::fmt;
+
+/// A charset of the `charset` parameter.
+///
+/// The common charsets of the
+/// [IANA registry](https://www.iana.org/assignments/character-sets/character-sets.xhtml)
+/// are matched case-insensitively, including some of their aliases.
+///
+/// ```
+/// # use mediatype::{Charset, MediaType};
+/// let media_type = MediaType::parse("text/html; charset=\"utf-8\"").unwrap();
+/// match media_type.charset() {
+///     Some(Charset::Utf8) => (),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Charset<'a> {
+    /// `UTF-8`
+    Utf8,
+    /// `UTF-16`
+    Utf16,
+    /// `UTF-16BE`
+    Utf16Be,
+    /// `UTF-16LE`
+    Utf16Le,
+    /// `US-ASCII`
+    UsAscii,
+    /// `ISO-8859-1`
+    Iso8859_1,
+    /// `ISO-8859-2`
+    Iso8859_2,
+    /// `ISO-8859-15`
+    Iso8859_15,
+    /// `windows-1252`
+    Windows1252,
+    /// `Shift_JIS`
+    ShiftJis,
+    /// `EUC-JP`
+    EucJp,
+    /// `ISO-2022-JP`
+    Iso2022Jp,
+    /// `EUC-KR`
+    EucKr,
+    /// `GB2312`
+    Gb2312,
+    /// `GBK`
+    Gbk,
+    /// `GB18030`
+    Gb18030,
+    /// `Big5`
+    Big5,
+    /// `KOI8-R`
+    Koi8R,
+    /// Any other charset.
+    Other(&'a str),
+}
+
+const CHARSETS: &[(Charset<'static>, &[&str])] = &[
+    (Charset::Utf8, &["UTF-8"]),
+    (Charset::Utf16, &["UTF-16"]),
+    (Charset::Utf16Be, &["UTF-16BE"]),
+    (Charset::Utf16Le, &["UTF-16LE"]),
+    (
+        Charset::UsAscii,
+        &["US-ASCII", "ASCII", "us", "ANSI_X3.4-1968", "iso-ir-6"],
+    ),
+    (
+        Charset::Iso8859_1,
+        &["ISO-8859-1", "ISO_8859-1", "latin1", "l1", "iso-ir-100"],
+    ),
+    (
+        Charset::Iso8859_2,
+        &["ISO-8859-2", "ISO_8859-2", "latin2", "l2", "iso-ir-101"],
+    ),
+    (
+        Charset::Iso8859_15,
+        &["ISO-8859-15", "ISO_8859-15", "Latin-9"],
+    ),
+    (Charset::Windows1252, &["windows-1252", "cp1252"]),
+    (Charset::ShiftJis, &["Shift_JIS", "MS_Kanji", "csShiftJIS"]),
+    (Charset::EucJp, &["EUC-JP"]),
+    (Charset::Iso2022Jp, &["ISO-2022-JP"]),
+    (Charset::EucKr, &["EUC-KR"]),
+    (Charset::Gb2312, &["GB2312"]),
+    (Charset::Gbk, &["GBK", "CP936"]),
+    (Charset::Gb18030, &["GB18030"]),
+    (Charset::Big5, &["Big5"]),
+    (Charset::Koi8R, &["KOI8-R"]),
+];
+
+impl<'a> Charset<'a> {
+    /// Constructs a `Charset` from a name or an alias, ignoring case.
+    ///
+    /// ```
+    /// # use mediatype::Charset;
+    /// assert_eq!(Charset::from_name("utf-8"), Charset::Utf8);
+    /// assert_eq!(Charset::from_name("Latin1"), Charset::Iso8859_1);
+    /// assert_eq!(Charset::from_name("x-user-defined"), Charset::Other("x-user-defined"));
+    /// ```
+    #[must_use]
+    pub fn from_name(name: &'a str) -> Self {
+        CHARSETS
+            .iter()
+            .find(|(_, names)| names.iter().any(|known| known.eq_ignore_ascii_case(name)))
+            .map_or(Self::Other(name), |&(charset, _)| charset)
+    }
+
+    /// Constructs a `Charset` from a parameter value.
+    ///
+    /// The surrounding quotes of a quoted string are removed.
+    ///
+    /// ```
+    /// # use mediatype::{values::*, Charset, Value};
+    /// assert_eq!(Charset::from_value(UTF_8), Charset::Utf8);
+    /// assert_eq!(Charset::from_value(Value::new("\"us-ascii\"").unwrap()), Charset::UsAscii);
+    /// ```
+    #[must_use]
+    pub fn from_value(value: Value<'a>) -> Self {
+        let s = value.as_str();
+        Self::from_name(
+            s.strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .unwrap_or(s),
+        )
+    }
+
+    /// Returns the preferred name of the charset.
+    ///
+    /// ```
+    /// # use mediatype::Charset;
+    /// assert_eq!(Charset::from_name("cp1252").name(), "windows-1252");
+    /// ```
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Utf16 => "UTF-16",
+            Self::Utf16Be => "UTF-16BE",
+            Self::Utf16Le => "UTF-16LE",
+            Self::UsAscii => "US-ASCII",
+            Self::Iso8859_1 => "ISO-8859-1",
+            Self::Iso8859_2 => "ISO-8859-2",
+            Self::Iso8859_15 => "ISO-8859-15",
+            Self::Windows1252 => "windows-1252",
+            Self::ShiftJis => "Shift_JIS",
+            Self::EucJp => "EUC-JP",
+            Self::Iso2022Jp => "ISO-2022-JP",
+            Self::EucKr => "EUC-KR",
+            Self::Gb2312 => "GB2312",
+            Self::Gbk => "GBK",
+            Self::Gb18030 => "GB18030",
+            Self::Big5 => "Big5",
+            Self::Koi8R => "KOI8-R",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl<'a> From<Value<'a>> for Charset<'a> {
+    fn from(value: Value<'a>) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl<'a> fmt::Display for Charset<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns the charset of the `charset` parameter.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, Charset, MediaType};
+    /// let media_type = MediaType::from_parts(TEXT, PLAIN, None, &[(CHARSET, ISO_8859_1)]);
+    /// assert_eq!(media_type.charset(), Some(Charset::Iso8859_1));
+    /// assert_eq!(MediaType::new(TEXT, PLAIN).charset(), None);
+    /// ```
+    #[must_use]
+    pub fn charset(&self) -> Option<Charset<'_>> {
+        self.get_param(CHARSET).map(Charset::from_value)
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the charset of the `charset` parameter.
+    #[must_use]
+    pub fn charset(&self) -> Option<Charset<'_>> {
+        self.get_param(CHARSET).map(Charset::from_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_name() {
+        for &(charset, names) in CHARSETS {
+            assert_eq!(charset.name(), names[0]);
+            for name in names {
+                assert_eq!(Charset::from_name(name), charset);
+                assert_eq!(Charset::from_name(&name.to_ascii_uppercase()), charset);
+                assert_eq!(Charset::from_name(&name.to_ascii_lowercase()), charset);
+            }
+        }
+        assert_eq!(Charset::from_name("UTF8"), Charset::Other("UTF8"));
+        assert_eq!(Charset::Other("UTF8").name(), "UTF8");
+        assert_eq!(Charset::Utf16Le.to_string(), "UTF-16LE");
+    }
+
+    #[test]
+    fn charset() {
+        let charset = |s| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            media_type.charset().map(|charset| charset.to_string())
+        };
+        assert_eq!(charset("text/plain; charset=utf-8"), Some("UTF-8".into()));
+        assert_eq!(
+            charset("text/plain; CHARSET=\"Shift_jis\""),
+            Some("Shift_JIS".into())
+        );
+        assert_eq!(
+            charset("text/plain; charset=x-unknown"),
+            Some("x-unknown".into())
+        );
+        assert_eq!(charset("text/plain"), None);
+    }
+}
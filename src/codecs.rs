@@ -0,0 +1,105 @@
+use super::{media_type::*, media_type_buf::*, names::*, params::*};
+use std::borrow::Cow;
+
+/// An iterator over the codecs in the `codecs` parameter.
+///
+/// This is created by [`MediaType::codecs`](./struct.MediaType.html#method.codecs)
+/// and [`MediaTypeBuf::codecs`](./struct.MediaTypeBuf.html#method.codecs).
+#[derive(Debug, Clone)]
+pub struct Codecs<'a> {
+    list: Cow<'a, str>,
+    pos: usize,
+}
+
+impl<'a> Codecs<'a> {
+    fn new(list: Cow<'a, str>) -> Self {
+        Self { list, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Codecs<'a> {
+    type Item = Cow<'a, str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let is_separator = |c: char| c == ',' || c == ' ' || c == '\t';
+        let rest = &self.list[self.pos..];
+        let start = self.pos + rest.find(|c| !is_separator(c))?;
+        let end = self.list[start..]
+            .find(',')
+            .map_or(self.list.len(), |len| start + len);
+        self.pos = end;
+        let end = start + self.list[start..end].trim_end_matches(is_separator).len();
+        Some(match &self.list {
+            Cow::Borrowed(list) => Cow::Borrowed(&list[start..end]),
+            Cow::Owned(list) => Cow::Owned(list[start..end].to_string()),
+        })
+    }
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns an iterator over the codecs in the `codecs` parameter,
+    /// or `None` if the parameter is missing.
+    ///
+    /// The value is a comma-separated list of codecs
+    /// ([RFC 6381](https://www.rfc-editor.org/rfc/rfc6381#section-3)),
+    /// which is usually quoted. Whitespace and empty items are skipped.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("video/mp4; codecs=\"avc1.42E01E, mp4a.40.2\"").unwrap();
+    /// let codecs = media_type.codecs().unwrap().collect::<Vec<_>>();
+    /// assert_eq!(codecs, ["avc1.42E01E", "mp4a.40.2"]);
+    ///
+    /// let media_type = MediaType::parse("audio/ogg; codecs=opus").unwrap();
+    /// assert_eq!(media_type.codecs().unwrap().collect::<Vec<_>>(), ["opus"]);
+    /// ```
+    #[must_use]
+    pub fn codecs(&self) -> Option<Codecs<'_>> {
+        self.get_param(CODECS)
+            .map(|value| Codecs::new(value.unquoted_str()))
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns an iterator over the codecs in the `codecs` parameter,
+    /// or `None` if the parameter is missing.
+    ///
+    /// The value is a comma-separated list of codecs
+    /// ([RFC 6381](https://www.rfc-editor.org/rfc/rfc6381#section-3)),
+    /// which is usually quoted. Whitespace and empty items are skipped.
+    #[must_use]
+    pub fn codecs(&self) -> Option<Codecs<'_>> {
+        self.get_param(CODECS)
+            .map(|value| Codecs::new(value.unquoted_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn codecs() {
+        let codecs = |s: &str| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            media_type
+                .codecs()
+                .map(|codecs| codecs.map(Cow::into_owned).collect::<Vec<_>>())
+        };
+        assert_eq!(codecs("video/mp4"), None);
+        assert_eq!(
+            codecs("video/mp4; codecs=\"avc1.4d002a,mp4a.40.2\""),
+            Some(vec!["avc1.4d002a".into(), "mp4a.40.2".into()])
+        );
+        assert_eq!(
+            codecs("video/webm; Codecs=\" vp8 ,, vorbis , \""),
+            Some(vec!["vp8".into(), "vorbis".into()])
+        );
+        assert_eq!(
+            codecs("video/mp4; codecs=\"a\\\"b, c\""),
+            Some(vec!["a\"b".into(), "c".into()])
+        );
+        assert_eq!(codecs("video/mp4; codecs=\" \""), Some(vec![]));
+    }
+}
@@ -0,0 +1,364 @@
+// Compile-time parser used by the `media_type!` macro.
+//
+// The grammar is the strict one: `type "/" subtype ["+" suffix] *( OWS ";" OWS parameter )`.
+// Errors are reported by panicking, which fails the compilation in a const context.
+
+use super::{media_type::*, name::*, value::*};
+
+/// Validates `s` and returns the number of the parameters.
+pub const fn count_params(s: &str) -> usize {
+    let b = s.as_bytes();
+    let mut pos = parse_essence(b)[2];
+    let mut count = 0;
+    while let Some(param) = parse_param(b, pos) {
+        let mut prev = parse_essence(b)[2];
+        while let Some(other) = parse_param(b, prev) {
+            if other[0] == param[0] {
+                break;
+            }
+            if eq_ignore_ascii_case(
+                subslice(b, other[0], other[1]),
+                subslice(b, param[0], param[1]),
+            ) {
+                panic!("Duplicate param");
+            }
+            prev = other[3];
+        }
+        count += 1;
+        pos = param[3];
+    }
+    count
+}
+
+/// Returns the parameters of `s`. `N` must be the result of [`count_params`].
+pub const fn params<const N: usize>(s: &str) -> [(Name<'_>, Value<'_>); N] {
+    let b = s.as_bytes();
+    let mut params = [(Name::new_unchecked(""), Value::new_unchecked("")); N];
+    let mut pos = parse_essence(b)[2];
+    let mut i = 0;
+    while let Some(param) = parse_param(b, pos) {
+        params[i] = (
+            Name::new_unchecked(substr(s, param[0], param[1])),
+            Value::new_unchecked(substr(s, param[2], param[3])),
+        );
+        i += 1;
+        pos = param[3];
+    }
+    params
+}
+
+/// Validates the names and the values, and panics if any of them is not valid
+/// or a parameter name appears more than once.
+pub const fn check_parts(ty: Name, subty: Name, suffix: Option<Name>, params: &[(Name, Value)]) {
+    if !is_valid_name(ty.as_str()) {
+        panic!("Invalid type name");
+    }
+    if !is_valid_name(subty.as_str()) {
+        panic!("Invalid subtype name");
+    }
+    if let Some(suffix) = suffix {
+        if !is_valid_name(suffix.as_str()) {
+            panic!("Invalid suffix");
+        }
+    }
+    let mut i = 0;
+    while i < params.len() {
+        let (name, value) = params[i];
+        if !is_valid_name(name.as_str()) {
+            panic!("Invalid param name");
+        }
+        if !is_valid_value(value.as_str()) {
+            panic!("Invalid param value");
+        }
+        let mut j = 0;
+        while j < i {
+            if eq_ignore_ascii_case(params[j].0.as_str().as_bytes(), name.as_str().as_bytes()) {
+                panic!("Duplicate param");
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Returns the media type of `s` with `params`, which must be the result of [`params`].
+pub const fn media_type<'a>(s: &'a str, params: &'a [(Name<'a>, Value<'a>)]) -> MediaType<'a> {
+    let [ty, subty, end] = parse_essence(s.as_bytes());
+    let suffix = if subty < end {
+        Some(Name::new_unchecked(substr(s, subty + 1, end)))
+    } else {
+        None
+    };
+    MediaType::from_parts(
+        Name::new_unchecked(substr(s, 0, ty)),
+        Name::new_unchecked(substr(s, ty + 1, subty)),
+        suffix,
+        params,
+    )
+}
+
+// Returns the end positions of the type, the subtype and the essence.
+const fn parse_essence(b: &[u8]) -> [usize; 3] {
+    let mut ty = 0;
+    while ty < b.len() && is_restricted_byte(b[ty]) {
+        ty += 1;
+    }
+    if ty == b.len() || b[ty] != b'/' || !is_restricted_name(b, 0, ty) {
+        panic!("Invalid type name");
+    }
+
+    let mut end = ty + 1;
+    let mut plus = None;
+    while end < b.len() && is_restricted_byte(b[end]) {
+        if b[end] == b'+' {
+            plus = Some(end);
+        }
+        end += 1;
+    }
+
+    let subty = match plus {
+        Some(plus) if plus + 1 < end => plus,
+        _ => end,
+    };
+    if !is_restricted_name(b, ty + 1, subty) {
+        panic!("Invalid subtype name");
+    }
+    if subty < end && !is_restricted_name(b, subty + 1, end) {
+        panic!("Invalid suffix");
+    }
+    [ty, subty, end]
+}
+
+// Returns the ranges of the name and the value of the parameter at `pos`.
+const fn parse_param(b: &[u8], mut pos: usize) -> Option<[usize; 4]> {
+    if pos == b.len() {
+        return None;
+    }
+    pos = skip_ows(b, pos);
+    if pos == b.len() || b[pos] != b';' {
+        panic!("Invalid params");
+    }
+    pos = skip_ows(b, pos + 1);
+
+    let name_start = pos;
+    while pos < b.len() && is_restricted_byte(b[pos]) {
+        pos += 1;
+    }
+    if pos == b.len() || b[pos] != b'=' {
+        panic!("Invalid params");
+    }
+    if !is_restricted_name(b, name_start, pos) {
+        panic!("Invalid param name");
+    }
+    let name_end = pos;
+
+    let value_start = pos + 1;
+    let mut pos = value_start;
+    if pos < b.len() && b[pos] == b'"' {
+        pos = match quoted_end(b, pos) {
+            Some(end) => end,
+            None => panic!("Invalid param value"),
+        };
+    } else {
+        while pos < b.len() && is_restricted_byte(b[pos]) {
+            pos += 1;
+        }
+    }
+    Some([name_start, name_end, value_start, pos])
+}
+
+// Returns the end position of the quoted string at `start`.
+const fn quoted_end(b: &[u8], start: usize) -> Option<usize> {
+    let mut pos = start + 1;
+    let mut escaped = false;
+    loop {
+        if pos == b.len() || b[pos] == b'\n' {
+            return None;
+        }
+        match b[pos] {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'"' => break,
+            _ => (),
+        }
+        pos += 1;
+    }
+    Some(pos + 1)
+}
+
+/// Returns `s` as a [`Name`], and panics if it is not valid.
+pub const fn name(s: &str) -> Name<'_> {
+    if !is_valid_name(s) {
+        panic!("Invalid name");
+    }
+    Name::new_unchecked(s)
+}
+
+/// Returns `s` as a [`Value`], and panics if it is not valid.
+pub const fn value(s: &str) -> Value<'_> {
+    if !is_valid_value(s) {
+        panic!("Invalid value");
+    }
+    Value::new_unchecked(s)
+}
+
+/// Returns `true` if `s` is valid as a [`Name`].
+pub const fn is_valid_name(s: &str) -> bool {
+    let b = s.as_bytes();
+    is_restricted_name(b, 0, b.len()) && is_restricted_bytes(b)
+}
+
+/// Returns `true` if `s` is valid as a [`Value`].
+pub const fn is_valid_value(s: &str) -> bool {
+    let b = s.as_bytes();
+    if !b.is_empty() && b[0] == b'"' {
+        matches!(quoted_end(b, 0), Some(end) if end == b.len())
+    } else {
+        is_restricted_bytes(b)
+    }
+}
+
+const fn is_restricted_bytes(b: &[u8]) -> bool {
+    let mut i = 0;
+    while i < b.len() {
+        if !is_restricted_byte(b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn skip_ows(b: &[u8], mut pos: usize) -> usize {
+    while pos < b.len() && (b[pos] == b' ' || b[pos] == b'\t') {
+        pos += 1;
+    }
+    pos
+}
+
+const fn is_restricted_name(b: &[u8], start: usize, end: usize) -> bool {
+    start < end
+        && end - start <= Name::MAX_LENGTH
+        && (b[start].is_ascii_alphanumeric() || b[start] == b'*')
+}
+
+const fn is_restricted_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'!' | b'#' | b'$' | b'&' | b'-' | b'^' | b'_' | b'.' | b'+' | b'%' | b'*' | b'\''
+        )
+}
+
+const fn eq_ignore_ascii_case(x: &[u8], y: &[u8]) -> bool {
+    if x.len() != y.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < x.len() {
+        if !x[i].eq_ignore_ascii_case(&y[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn subslice(b: &[u8], start: usize, end: usize) -> &[u8] {
+    let (_, rest) = b.split_at(start);
+    let (b, _) = rest.split_at(end - start);
+    b
+}
+
+const fn substr(s: &str, start: usize, end: usize) -> &str {
+    match std::str::from_utf8(subslice(s.as_bytes(), start, end)) {
+        Ok(s) => s,
+        Err(_) => panic!("`start` and `end` should be on char boundaries"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> String {
+        match count_params(s) {
+            0 => media_type(s, &params::<0>(s)).to_string(),
+            1 => media_type(s, &params::<1>(s)).to_string(),
+            2 => media_type(s, &params::<2>(s)).to_string(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn parse_valid() {
+        assert_eq!(parse("text/plain"), "text/plain");
+        assert_eq!(parse("*/*"), "*/*");
+        assert_eq!(parse("a/b+c"), "a/b+c");
+        assert_eq!(parse("audio/amr-wb+"), "audio/amr-wb+");
+        assert_eq!(
+            parse("application/vnd.api+json; charset=utf-8"),
+            "application/vnd.api+json; charset=utf-8"
+        );
+        assert_eq!(
+            parse("text/plain;charset=UTF-8 ;\ttitle=\"a; b=\\\"c\\\"\""),
+            "text/plain; charset=UTF-8; title=\"a; b=\\\"c\\\"\""
+        );
+        assert_eq!(
+            parse("text/plain; a=; title=\"\""),
+            "text/plain; a=; title=\"\""
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "",
+            "text",
+            "/plain",
+            "text/",
+            "text/plain+-a",
+            "-text/plain",
+            "text/plain;",
+            "text/plain ",
+            "text/plain; charset",
+            "text/plain; charset=\"UTF-8",
+            "text/plain; =UTF-8",
+            "text/plain; charset=UTF-8; CHARSET=UTF-8",
+        ] {
+            assert!(
+                std::panic::catch_unwind(|| count_params(s)).is_err(),
+                "{:?} should be rejected",
+                s
+            );
+        }
+    }
+
+    #[test]
+    fn check() {
+        use crate::{names::*, values::*};
+
+        check_parts(TEXT, PLAIN, None, &[(CHARSET, UTF_8), (FORMAT, FLOWED)]);
+        check_parts(IMAGE, SVG, Some(XML), &[]);
+        assert!(is_valid_value("\"a b\""));
+        assert!(!is_valid_value("a b"));
+        assert!(is_valid_value("\"\""));
+        assert!(is_valid_value(""));
+        assert!(!is_valid_name("-a"));
+        assert_eq!(name("vnd.example"), "vnd.example");
+        assert_eq!(value("\"a b\"").unquoted_str(), "a b");
+        assert!(std::panic::catch_unwind(|| name("a b")).is_err());
+        assert!(std::panic::catch_unwind(|| value("a b")).is_err());
+
+        let invalid = Name::new_unchecked("a b");
+        for (ty, subty, suffix, params) in [
+            (invalid, PLAIN, None, [].as_slice()),
+            (TEXT, invalid, None, &[]),
+            (TEXT, PLAIN, Some(invalid), &[]),
+            (TEXT, PLAIN, None, &[(invalid, UTF_8)]),
+            (TEXT, PLAIN, None, &[(CHARSET, UTF_8), (CHARSET, US_ASCII)]),
+        ] {
+            assert!(std::panic::catch_unwind(|| check_parts(ty, subty, suffix, params)).is_err());
+        }
+    }
+}
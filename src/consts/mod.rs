@@ -2,3 +2,6 @@
 pub mod names;
 #[rustfmt::skip]
 pub mod values;
+#[cfg(feature = "full-registry")]
+#[rustfmt::skip]
+pub mod registry;
@@ -2274,6 +2274,8 @@ pub mod vnd {
 pub mod x_ {
     /// `x-7z-compressed`
     pub const _7Z_COMPRESSED: crate::Name = crate::Name::new_unchecked("x-7z-compressed");
+    /// `x-aac`
+    pub const AAC: crate::Name = crate::Name::new_unchecked("x-aac");
     /// `x-abiword`
     pub const ABIWORD: crate::Name = crate::Name::new_unchecked("x-abiword");
     /// `x-bzip`
@@ -2284,28 +2286,58 @@ pub mod x_ {
     pub const CDF: crate::Name = crate::Name::new_unchecked("x-cdf");
     /// `x-csh`
     pub const CSH: crate::Name = crate::Name::new_unchecked("x-csh");
+    /// `x-csv`
+    pub const CSV: crate::Name = crate::Name::new_unchecked("x-csv");
     /// `x-ecmascript`
     pub const ECMASCRIPT: crate::Name = crate::Name::new_unchecked("x-ecmascript");
     /// `x-emf`
     pub const EMF: crate::Name = crate::Name::new_unchecked("x-emf");
+    /// `x-flac`
+    pub const FLAC: crate::Name = crate::Name::new_unchecked("x-flac");
+    /// `x-font-otf`
+    pub const FONT_OTF: crate::Name = crate::Name::new_unchecked("x-font-otf");
+    /// `x-font-ttf`
+    pub const FONT_TTF: crate::Name = crate::Name::new_unchecked("x-font-ttf");
+    /// `x-font-woff`
+    pub const FONT_WOFF: crate::Name = crate::Name::new_unchecked("x-font-woff");
     /// `x-freearc`
     pub const FREEARC: crate::Name = crate::Name::new_unchecked("x-freearc");
+    /// `x-gzip`
+    pub const GZIP: crate::Name = crate::Name::new_unchecked("x-gzip");
     /// `x-httpd-php`
     pub const HTTPD_PHP: crate::Name = crate::Name::new_unchecked("x-httpd-php");
     /// `x-icon`
     pub const ICON: crate::Name = crate::Name::new_unchecked("x-icon");
     /// `x-javascript`
     pub const JAVASCRIPT: crate::Name = crate::Name::new_unchecked("x-javascript");
+    /// `x-json`
+    pub const JSON: crate::Name = crate::Name::new_unchecked("x-json");
+    /// `x-m4a`
+    pub const M4A: crate::Name = crate::Name::new_unchecked("x-m4a");
+    /// `x-markdown`
+    pub const MARKDOWN: crate::Name = crate::Name::new_unchecked("x-markdown");
+    /// `x-matroska`
+    pub const MATROSKA: crate::Name = crate::Name::new_unchecked("x-matroska");
     /// `x-midi`
     pub const MIDI: crate::Name = crate::Name::new_unchecked("x-midi");
     /// `x-mixed-replace`
     pub const MIXED_REPLACE: crate::Name = crate::Name::new_unchecked("x-mixed-replace");
+    /// `x-ms-bmp`
+    pub const MS_BMP: crate::Name = crate::Name::new_unchecked("x-ms-bmp");
     /// `x-msgpack`
     pub const MSGPACK: crate::Name = crate::Name::new_unchecked("x-msgpack");
     /// `x-msvideo`
     pub const MSVIDEO: crate::Name = crate::Name::new_unchecked("x-msvideo");
+    /// `x-ndjson`
+    pub const NDJSON: crate::Name = crate::Name::new_unchecked("x-ndjson");
+    /// `x-pdf`
+    pub const PDF: crate::Name = crate::Name::new_unchecked("x-pdf");
     /// `x-pki-message`
     pub const PKI_MESSAGE: crate::Name = crate::Name::new_unchecked("x-pki-message");
+    /// `x-png`
+    pub const PNG: crate::Name = crate::Name::new_unchecked("x-png");
+    /// `x-protobuf`
+    pub const PROTOBUF: crate::Name = crate::Name::new_unchecked("x-protobuf");
     /// `x-sh`
     pub const SH: crate::Name = crate::Name::new_unchecked("x-sh");
     /// `x-shockwave-flash`
@@ -2324,6 +2356,8 @@ pub mod x_ {
     pub const X509_NEXT_CA_CERT: crate::Name = crate::Name::new_unchecked("x-x509-next-ca-cert");
     /// `x-yaml`
     pub const YAML: crate::Name = crate::Name::new_unchecked("x-yaml");
+    /// `x-zip-compressed`
+    pub const ZIP_COMPRESSED: crate::Name = crate::Name::new_unchecked("x-zip-compressed");
 }
 
 /// `*`
@@ -2548,6 +2582,8 @@ pub const CNRP: crate::Name = crate::Name::new_unchecked("cnrp");
 pub const COAP_GROUP: crate::Name = crate::Name::new_unchecked("coap-group");
 /// `coap-payload`
 pub const COAP_PAYLOAD: crate::Name = crate::Name::new_unchecked("coap-payload");
+/// `codecs`
+pub const CODECS: crate::Name = crate::Name::new_unchecked("codecs");
 /// `collection`
 pub const COLLECTION: crate::Name = crate::Name::new_unchecked("collection");
 /// `commonground`
@@ -2750,6 +2786,8 @@ pub const FHIR: crate::Name = crate::Name::new_unchecked("fhir");
 pub const FHIRPATH: crate::Name = crate::Name::new_unchecked("fhirpath");
 /// `fits`
 pub const FITS: crate::Name = crate::Name::new_unchecked("fits");
+/// `flac`
+pub const FLAC: crate::Name = crate::Name::new_unchecked("flac");
 /// `flexfec`
 pub const FLEXFEC: crate::Name = crate::Name::new_unchecked("flexfec");
 /// `font`
@@ -2818,6 +2856,8 @@ pub const GLTF_BUFFER: crate::Name = crate::Name::new_unchecked("gltf-buffer");
 pub const GML: crate::Name = crate::Name::new_unchecked("gml");
 /// `grammar-ref-list`
 pub const GRAMMAR_REF_LIST: crate::Name = crate::Name::new_unchecked("grammar-ref-list");
+/// `graphql`
+pub const GRAPHQL: crate::Name = crate::Name::new_unchecked("graphql");
 /// `GSM`
 pub const GSM: crate::Name = crate::Name::new_unchecked("GSM");
 /// `GSM-EFR`
@@ -2844,6 +2884,8 @@ pub const H264_RCDO: crate::Name = crate::Name::new_unchecked("H264-RCDO");
 pub const H264_SVC: crate::Name = crate::Name::new_unchecked("H264-SVC");
 /// `H265`
 pub const H265: crate::Name = crate::Name::new_unchecked("H265");
+/// `haptics`
+pub const HAPTICS: crate::Name = crate::Name::new_unchecked("haptics");
 /// `header-set`
 pub const HEADER_SET: crate::Name = crate::Name::new_unchecked("header-set");
 /// `heic`
@@ -2938,6 +2980,8 @@ pub const JP2: crate::Name = crate::Name::new_unchecked("jp2");
 pub const JPEG: crate::Name = crate::Name::new_unchecked("jpeg");
 /// `jpeg2000`
 pub const JPEG2000: crate::Name = crate::Name::new_unchecked("jpeg2000");
+/// `jpg`
+pub const JPG: crate::Name = crate::Name::new_unchecked("jpg");
 /// `jph`
 pub const JPH: crate::Name = crate::Name::new_unchecked("jph");
 /// `jphc`
@@ -2958,6 +3002,8 @@ pub const JSON: crate::Name = crate::Name::new_unchecked("json");
 pub const JSON_PATCH: crate::Name = crate::Name::new_unchecked("json-patch");
 /// `json-seq`
 pub const JSON_SEQ: crate::Name = crate::Name::new_unchecked("json-seq");
+/// `jsonl`
+pub const JSONL: crate::Name = crate::Name::new_unchecked("jsonl");
 /// `jwk`
 pub const JWK: crate::Name = crate::Name::new_unchecked("jwk");
 /// `jwk-set`
@@ -3038,6 +3084,8 @@ pub const MATHML: crate::Name = crate::Name::new_unchecked("mathml");
 pub const MATHML_CONTENT: crate::Name = crate::Name::new_unchecked("mathml-content");
 /// `mathml-presentation`
 pub const MATHML_PRESENTATION: crate::Name = crate::Name::new_unchecked("mathml-presentation");
+/// `matroska`
+pub const MATROSKA: crate::Name = crate::Name::new_unchecked("matroska");
 /// `mbms-associated-procedure-description`
 pub const MBMS_ASSOCIATED_PROCEDURE_DESCRIPTION: crate::Name = crate::Name::new_unchecked("mbms-associated-procedure-description");
 /// `mbms-deregister`
@@ -3182,6 +3230,8 @@ pub const N3: crate::Name = crate::Name::new_unchecked("n3");
 pub const NAPLPS: crate::Name = crate::Name::new_unchecked("naplps");
 /// `nasdata`
 pub const NASDATA: crate::Name = crate::Name::new_unchecked("nasdata");
+/// `ndjson`
+pub const NDJSON: crate::Name = crate::Name::new_unchecked("ndjson");
 /// `news-checkgroups`
 pub const NEWS_CHECKGROUPS: crate::Name = crate::Name::new_unchecked("news-checkgroups");
 /// `news-groupinfo`
@@ -3302,6 +3352,8 @@ pub const POSTSCRIPT: crate::Name = crate::Name::new_unchecked("postscript");
 pub const PPSP_TRACKER: crate::Name = crate::Name::new_unchecked("ppsp-tracker");
 /// `problem`
 pub const PROBLEM: crate::Name = crate::Name::new_unchecked("problem");
+/// `protobuf`
+pub const PROTOBUF: crate::Name = crate::Name::new_unchecked("protobuf");
 /// `provenance`
 pub const PROVENANCE: crate::Name = crate::Name::new_unchecked("provenance");
 /// `provenance-notation`
@@ -3524,6 +3576,8 @@ pub const SPEEX: crate::Name = crate::Name::new_unchecked("speex");
 pub const SPIRITS_EVENT: crate::Name = crate::Name::new_unchecked("spirits-event");
 /// `sql`
 pub const SQL: crate::Name = crate::Name::new_unchecked("sql");
+/// `sqlite3`
+pub const SQLITE3: crate::Name = crate::Name::new_unchecked("sqlite3");
 /// `srgs`
 pub const SRGS: crate::Name = crate::Name::new_unchecked("srgs");
 /// `sru`
@@ -3604,10 +3658,14 @@ pub const TIMESTAMP_REPLY: crate::Name = crate::Name::new_unchecked("timestamp-r
 pub const TIMESTAMPED_DATA: crate::Name = crate::Name::new_unchecked("timestamped-data");
 /// `tlsrpt`
 pub const TLSRPT: crate::Name = crate::Name::new_unchecked("tlsrpt");
+/// `tlv`
+pub const TLV: crate::Name = crate::Name::new_unchecked("tlv");
 /// `tnauthlist`
 pub const TNAUTHLIST: crate::Name = crate::Name::new_unchecked("tnauthlist");
 /// `token-introspection`
 pub const TOKEN_INTROSPECTION: crate::Name = crate::Name::new_unchecked("token-introspection");
+/// `toml`
+pub const TOML: crate::Name = crate::Name::new_unchecked("toml");
 /// `tone`
 pub const TONE: crate::Name = crate::Name::new_unchecked("tone");
 /// `trickle-ice-sdpfrag`
@@ -3760,6 +3818,8 @@ pub const XOP: crate::Name = crate::Name::new_unchecked("xop");
 pub const XSLT: crate::Name = crate::Name::new_unchecked("xslt");
 /// `xv`
 pub const XV: crate::Name = crate::Name::new_unchecked("xv");
+/// `yaml`
+pub const YAML: crate::Name = crate::Name::new_unchecked("yaml");
 /// `yang`
 pub const YANG: crate::Name = crate::Name::new_unchecked("yang");
 /// `yang-data`
@@ -0,0 +1,4120 @@
+//! Registered media types, @generated in tests/codegen.rs
+//!
+//! # Sources
+//! - <https://www.iana.org/assignments/media-types/media-types.xhtml>
+
+/// Media types with the `application` top-level type.
+pub mod application {
+    /// `application/1d-interleaved-parityfec`
+    pub const _1D_INTERLEAVED_PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("1d-interleaved-parityfec"), None, &[]);
+    /// `application/3gpdash-qoe-report+xml`
+    pub const _3GPDASH_QOE_REPORT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("3gpdash-qoe-report"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/3gpp-ims+xml`
+    pub const _3GPP_IMS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("3gpp-ims"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/3gppHal+json`
+    pub const _3GPP_HAL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("3gppHal"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/3gppHalForms+json`
+    pub const _3GPP_HAL_FORMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("3gppHalForms"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/A2L`
+    pub const A2L: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("A2L"), None, &[]);
+    /// `application/ace+cbor`
+    pub const ACE_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ace"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/ace+json`
+    pub const ACE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ace"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/activemessage`
+    pub const ACTIVEMESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("activemessage"), None, &[]);
+    /// `application/activity+json`
+    pub const ACTIVITY_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("activity"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/aif+cbor`
+    pub const AIF_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("aif"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/aif+json`
+    pub const AIF_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("aif"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-cdni+json`
+    pub const ALTO_CDNI_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-cdni"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-cdnifilter+json`
+    pub const ALTO_CDNIFILTER_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-cdnifilter"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-costmap+json`
+    pub const ALTO_COSTMAP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-costmap"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-costmapfilter+json`
+    pub const ALTO_COSTMAPFILTER_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-costmapfilter"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-directory+json`
+    pub const ALTO_DIRECTORY_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-directory"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-endpointcost+json`
+    pub const ALTO_ENDPOINTCOST_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-endpointcost"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-endpointcostparams+json`
+    pub const ALTO_ENDPOINTCOSTPARAMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-endpointcostparams"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-endpointprop+json`
+    pub const ALTO_ENDPOINTPROP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-endpointprop"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-endpointpropparams+json`
+    pub const ALTO_ENDPOINTPROPPARAMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-endpointpropparams"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-error+json`
+    pub const ALTO_ERROR_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-error"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-networkmap+json`
+    pub const ALTO_NETWORKMAP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-networkmap"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-networkmapfilter+json`
+    pub const ALTO_NETWORKMAPFILTER_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-networkmapfilter"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-propmap+json`
+    pub const ALTO_PROPMAP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-propmap"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-propmapparams+json`
+    pub const ALTO_PROPMAPPARAMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-propmapparams"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-updatestreamcontrol+json`
+    pub const ALTO_UPDATESTREAMCONTROL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-updatestreamcontrol"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/alto-updatestreamparams+json`
+    pub const ALTO_UPDATESTREAMPARAMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("alto-updatestreamparams"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/AML`
+    pub const AML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("AML"), None, &[]);
+    /// `application/andrew-inset`
+    pub const ANDREW_INSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("andrew-inset"), None, &[]);
+    /// `application/annodex`
+    pub const ANNODEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("annodex"), None, &[]);
+    /// `application/applefile`
+    pub const APPLEFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("applefile"), None, &[]);
+    /// `application/at+jwt`
+    pub const AT_PLUS_JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("at"), Some(crate::Name::new_unchecked("jwt")), &[]);
+    /// `application/ATF`
+    pub const ATF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ATF"), None, &[]);
+    /// `application/ATFX`
+    pub const ATFX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ATFX"), None, &[]);
+    /// `application/atom+xml`
+    pub const ATOM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atom"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atomcat+xml`
+    pub const ATOMCAT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atomcat"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atomdeleted+xml`
+    pub const ATOMDELETED_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atomdeleted"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atomicmail`
+    pub const ATOMICMAIL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atomicmail"), None, &[]);
+    /// `application/atomserv+xml`
+    pub const ATOMSERV_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atomserv"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atomsvc+xml`
+    pub const ATOMSVC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atomsvc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atsc-dwd+xml`
+    pub const ATSC_DWD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atsc-dwd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atsc-dynamic-event-message`
+    pub const ATSC_DYNAMIC_EVENT_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atsc-dynamic-event-message"), None, &[]);
+    /// `application/atsc-held+xml`
+    pub const ATSC_HELD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atsc-held"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/atsc-rdt+json`
+    pub const ATSC_RDT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atsc-rdt"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/atsc-rsat+xml`
+    pub const ATSC_RSAT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("atsc-rsat"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ATXML`
+    pub const ATXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ATXML"), None, &[]);
+    /// `application/auth-policy+xml`
+    pub const AUTH_POLICY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("auth-policy"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/automationml-aml+xml`
+    pub const AUTOMATIONML_AML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("automationml-aml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/automationml-amlx+zip`
+    pub const AUTOMATIONML_AMLX_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("automationml-amlx"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/bacnet-xdd+zip`
+    pub const BACNET_XDD_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("bacnet-xdd"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/batch-SMTP`
+    pub const BATCH_SMTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("batch-SMTP"), None, &[]);
+    /// `application/bbolin`
+    pub const BBOLIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("bbolin"), None, &[]);
+    /// `application/beep+xml`
+    pub const BEEP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("beep"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/calendar+json`
+    pub const CALENDAR_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("calendar"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/calendar+xml`
+    pub const CALENDAR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("calendar"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/call-completion`
+    pub const CALL_COMPLETION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("call-completion"), None, &[]);
+    /// `application/CALS-1840`
+    pub const CALS_1840: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("CALS-1840"), None, &[]);
+    /// `application/captive+json`
+    pub const CAPTIVE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("captive"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/cbor`
+    pub const CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cbor"), None, &[]);
+    /// `application/cbor-seq`
+    pub const CBOR_SEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cbor-seq"), None, &[]);
+    /// `application/cccex`
+    pub const CCCEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cccex"), None, &[]);
+    /// `application/ccmp+xml`
+    pub const CCMP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ccmp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ccxml+xml`
+    pub const CCXML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ccxml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/cda+xml`
+    pub const CDA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cda"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/CDFX+XML`
+    pub const CDFX_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("CDFX"), Some(crate::Name::new_unchecked("XML")), &[]);
+    /// `application/cdmi-capability`
+    pub const CDMI_CAPABILITY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdmi-capability"), None, &[]);
+    /// `application/cdmi-container`
+    pub const CDMI_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdmi-container"), None, &[]);
+    /// `application/cdmi-domain`
+    pub const CDMI_DOMAIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdmi-domain"), None, &[]);
+    /// `application/cdmi-object`
+    pub const CDMI_OBJECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdmi-object"), None, &[]);
+    /// `application/cdmi-queue`
+    pub const CDMI_QUEUE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdmi-queue"), None, &[]);
+    /// `application/cdni`
+    pub const CDNI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cdni"), None, &[]);
+    /// `application/CEA`
+    pub const CEA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("CEA"), None, &[]);
+    /// `application/cea-2018+xml`
+    pub const CEA_2018_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cea-2018"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/cellml+xml`
+    pub const CELLML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cellml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/cfw`
+    pub const CFW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cfw"), None, &[]);
+    /// `application/city+json`
+    pub const CITY_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("city"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/clr`
+    pub const CLR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("clr"), None, &[]);
+    /// `application/clue+xml`
+    pub const CLUE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("clue"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/clue_info+xml`
+    pub const CLUE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("clue_info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/cms`
+    pub const CMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cms"), None, &[]);
+    /// `application/cnrp+xml`
+    pub const CNRP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cnrp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/coap-group+json`
+    pub const COAP_GROUP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("coap-group"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/coap-payload`
+    pub const COAP_PAYLOAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("coap-payload"), None, &[]);
+    /// `application/commonground`
+    pub const COMMONGROUND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("commonground"), None, &[]);
+    /// `application/concise-problem-details+cbor`
+    pub const CONCISE_PROBLEM_DETAILS_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("concise-problem-details"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/conference-info+xml`
+    pub const CONFERENCE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("conference-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/cose`
+    pub const COSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cose"), None, &[]);
+    /// `application/cose-key`
+    pub const COSE_KEY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cose-key"), None, &[]);
+    /// `application/cose-key-set`
+    pub const COSE_KEY_SET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cose-key-set"), None, &[]);
+    /// `application/cose-x509`
+    pub const COSE_X509: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cose-x509"), None, &[]);
+    /// `application/cpl+xml`
+    pub const CPL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cpl"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/csrattrs`
+    pub const CSRATTRS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("csrattrs"), None, &[]);
+    /// `application/csta+xml`
+    pub const CSTA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("csta"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/CSTAdata+xml`
+    pub const CSTADATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("CSTAdata"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/csvm+json`
+    pub const CSVM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("csvm"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/cu-seeme`
+    pub const CU_SEEME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cu-seeme"), None, &[]);
+    /// `application/cwl`
+    pub const CWL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cwl"), None, &[]);
+    /// `application/cwl+json`
+    pub const CWL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cwl"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/cwt`
+    pub const CWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cwt"), None, &[]);
+    /// `application/cybercash`
+    pub const CYBERCASH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("cybercash"), None, &[]);
+    /// `application/dash+xml`
+    pub const DASH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dash"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dash-patch+xml`
+    pub const DASH_PATCH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dash-patch"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dashdelta`
+    pub const DASHDELTA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dashdelta"), None, &[]);
+    /// `application/davmount+xml`
+    pub const DAVMOUNT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("davmount"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dca-rft`
+    pub const DCA_RFT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dca-rft"), None, &[]);
+    /// `application/DCD`
+    pub const DCD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("DCD"), None, &[]);
+    /// `application/dec-dx`
+    pub const DEC_DX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dec-dx"), None, &[]);
+    /// `application/dialog-info+xml`
+    pub const DIALOG_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dialog-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dicom`
+    pub const DICOM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dicom"), None, &[]);
+    /// `application/dicom+json`
+    pub const DICOM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dicom"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/dicom+xml`
+    pub const DICOM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dicom"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/DII`
+    pub const DII: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("DII"), None, &[]);
+    /// `application/DIT`
+    pub const DIT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("DIT"), None, &[]);
+    /// `application/dns`
+    pub const DNS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dns"), None, &[]);
+    /// `application/dns+json`
+    pub const DNS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dns"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/dns-message`
+    pub const DNS_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dns-message"), None, &[]);
+    /// `application/dots+cbor`
+    pub const DOTS_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dots"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/dskpp+xml`
+    pub const DSKPP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dskpp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dsptype`
+    pub const DSPTYPE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dsptype"), None, &[]);
+    /// `application/dssc+der`
+    pub const DSSC_PLUS_DER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dssc"), Some(crate::Name::new_unchecked("der")), &[]);
+    /// `application/dssc+xml`
+    pub const DSSC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dssc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/dvcs`
+    pub const DVCS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("dvcs"), None, &[]);
+    /// `application/EDI-consent`
+    pub const EDI_CONSENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EDI-consent"), None, &[]);
+    /// `application/EDI-X12`
+    pub const EDI_X12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EDI-X12"), None, &[]);
+    /// `application/EDIFACT`
+    pub const EDIFACT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EDIFACT"), None, &[]);
+    /// `application/efi`
+    pub const EFI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("efi"), None, &[]);
+    /// `application/elm+json`
+    pub const ELM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("elm"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/elm+xml`
+    pub const ELM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("elm"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.cap+xml`
+    pub const EMERGENCY_CALL_DATA_CAP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.cap"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.Comment+xml`
+    pub const EMERGENCY_CALL_DATA_COMMENT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.Comment"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.Control+xml`
+    pub const EMERGENCY_CALL_DATA_CONTROL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.Control"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.DeviceInfo+xml`
+    pub const EMERGENCY_CALL_DATA_DEVICE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.DeviceInfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.eCall.MSD`
+    pub const EMERGENCY_CALL_DATA_E_CALL_MSD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.eCall.MSD"), None, &[]);
+    /// `application/EmergencyCallData.LegacyESN+json`
+    pub const EMERGENCY_CALL_DATA_LEGACY_ESN_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.LegacyESN"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/EmergencyCallData.ProviderInfo+xml`
+    pub const EMERGENCY_CALL_DATA_PROVIDER_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.ProviderInfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.ServiceInfo+xml`
+    pub const EMERGENCY_CALL_DATA_SERVICE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.ServiceInfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.SubscriberInfo+xml`
+    pub const EMERGENCY_CALL_DATA_SUBSCRIBER_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.SubscriberInfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/EmergencyCallData.VEDS+xml`
+    pub const EMERGENCY_CALL_DATA_VEDS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("EmergencyCallData.VEDS"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/emma+xml`
+    pub const EMMA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("emma"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/emotionml+xml`
+    pub const EMOTIONML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("emotionml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/encaprtp`
+    pub const ENCAPRTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("encaprtp"), None, &[]);
+    /// `application/epp+xml`
+    pub const EPP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("epp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/epub+zip`
+    pub const EPUB_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("epub"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/eshop`
+    pub const ESHOP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("eshop"), None, &[]);
+    /// `application/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `application/exi`
+    pub const EXI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("exi"), None, &[]);
+    /// `application/expect-ct-report+json`
+    pub const EXPECT_CT_REPORT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("expect-ct-report"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/express`
+    pub const EXPRESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("express"), None, &[]);
+    /// `application/fastinfoset`
+    pub const FASTINFOSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fastinfoset"), None, &[]);
+    /// `application/fastsoap`
+    pub const FASTSOAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fastsoap"), None, &[]);
+    /// `application/fdf`
+    pub const FDF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fdf"), None, &[]);
+    /// `application/fdt+xml`
+    pub const FDT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fdt"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/fhir+json`
+    pub const FHIR_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fhir"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/fhir+xml`
+    pub const FHIR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fhir"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/fits`
+    pub const FITS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("fits"), None, &[]);
+    /// `application/flexfec`
+    pub const FLEXFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("flexfec"), None, &[]);
+    /// `application/font-tdpfr`
+    pub const FONT_TDPFR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("font-tdpfr"), None, &[]);
+    /// `application/framework-attributes+xml`
+    pub const FRAMEWORK_ATTRIBUTES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("framework-attributes"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/futuresplash`
+    pub const FUTURESPLASH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("futuresplash"), None, &[]);
+    /// `application/geo+json`
+    pub const GEO_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("geo"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/geo+json-seq`
+    pub const GEO_PLUS_JSON_SEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("geo"), Some(crate::Name::new_unchecked("json-seq")), &[]);
+    /// `application/geopackage+sqlite3`
+    pub const GEOPACKAGE_PLUS_SQLITE3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("geopackage"), Some(crate::Name::new_unchecked("sqlite3")), &[]);
+    /// `application/geoxacml+xml`
+    pub const GEOXACML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("geoxacml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/gltf-buffer`
+    pub const GLTF_BUFFER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("gltf-buffer"), None, &[]);
+    /// `application/gml+xml`
+    pub const GML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("gml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/gzip`
+    pub const GZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("gzip"), None, &[]);
+    /// `application/H224`
+    pub const H224: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("H224"), None, &[]);
+    /// `application/held+xml`
+    pub const HELD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("held"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/hl7v2+xml`
+    pub const HL7V2_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("hl7v2"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/hta`
+    pub const HTA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("hta"), None, &[]);
+    /// `application/http`
+    pub const HTTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("http"), None, &[]);
+    /// `application/hyperstudio`
+    pub const HYPERSTUDIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("hyperstudio"), None, &[]);
+    /// `application/ibe-key-request+xml`
+    pub const IBE_KEY_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ibe-key-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ibe-pkg-reply+xml`
+    pub const IBE_PKG_REPLY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ibe-pkg-reply"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ibe-pp-data`
+    pub const IBE_PP_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ibe-pp-data"), None, &[]);
+    /// `application/iges`
+    pub const IGES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("iges"), None, &[]);
+    /// `application/im-iscomposing+xml`
+    pub const IM_ISCOMPOSING_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("im-iscomposing"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/index`
+    pub const INDEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("index"), None, &[]);
+    /// `application/index.cmd`
+    pub const INDEX_CMD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("index.cmd"), None, &[]);
+    /// `application/index.obj`
+    pub const INDEX_OBJ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("index.obj"), None, &[]);
+    /// `application/index.response`
+    pub const INDEX_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("index.response"), None, &[]);
+    /// `application/index.vnd`
+    pub const INDEX_VND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("index.vnd"), None, &[]);
+    /// `application/inkml+xml`
+    pub const INKML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("inkml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/IOTP`
+    pub const IOTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("IOTP"), None, &[]);
+    /// `application/ipfix`
+    pub const IPFIX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ipfix"), None, &[]);
+    /// `application/ipp`
+    pub const IPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ipp"), None, &[]);
+    /// `application/ISUP`
+    pub const ISUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ISUP"), None, &[]);
+    /// `application/its+xml`
+    pub const ITS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("its"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/java-archive`
+    pub const JAVA_ARCHIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("java-archive"), None, &[]);
+    /// `application/java-serialized-object`
+    pub const JAVA_SERIALIZED_OBJECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("java-serialized-object"), None, &[]);
+    /// `application/java-vm`
+    pub const JAVA_VM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("java-vm"), None, &[]);
+    /// `application/jf2feed+json`
+    pub const JF2FEED_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jf2feed"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/jose`
+    pub const JOSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jose"), None, &[]);
+    /// `application/jose+json`
+    pub const JOSE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jose"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/jrd+json`
+    pub const JRD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jrd"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/jscalendar+json`
+    pub const JSCALENDAR_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jscalendar"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/json`
+    pub const JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("json"), None, &[]);
+    /// `application/json-patch+json`
+    pub const JSON_PATCH_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("json-patch"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/json-seq`
+    pub const JSON_SEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("json-seq"), None, &[]);
+    /// `application/jwk+json`
+    pub const JWK_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jwk"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/jwk-set+json`
+    pub const JWK_SET_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jwk-set"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/jwt`
+    pub const JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("jwt"), None, &[]);
+    /// `application/kpml-request+xml`
+    pub const KPML_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("kpml-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/kpml-response+xml`
+    pub const KPML_RESPONSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("kpml-response"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ld+json`
+    pub const LD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ld"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/lgr+xml`
+    pub const LGR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("lgr"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/link-format`
+    pub const LINK_FORMAT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("link-format"), None, &[]);
+    /// `application/linkset`
+    pub const LINKSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("linkset"), None, &[]);
+    /// `application/linkset+json`
+    pub const LINKSET_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("linkset"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/load-control+xml`
+    pub const LOAD_CONTROL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("load-control"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/logout+jwt`
+    pub const LOGOUT_PLUS_JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("logout"), Some(crate::Name::new_unchecked("jwt")), &[]);
+    /// `application/lost+xml`
+    pub const LOST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("lost"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/lostsync+xml`
+    pub const LOSTSYNC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("lostsync"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/lpf+zip`
+    pub const LPF_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("lpf"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/LXF`
+    pub const LXF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("LXF"), None, &[]);
+    /// `application/m3g`
+    pub const M3G: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("m3g"), None, &[]);
+    /// `application/mac-binhex40`
+    pub const MAC_BINHEX40: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mac-binhex40"), None, &[]);
+    /// `application/mac-compactpro`
+    pub const MAC_COMPACTPRO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mac-compactpro"), None, &[]);
+    /// `application/macwriteii`
+    pub const MACWRITEII: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("macwriteii"), None, &[]);
+    /// `application/mads+xml`
+    pub const MADS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mads"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/manifest+json`
+    pub const MANIFEST_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("manifest"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/marc`
+    pub const MARC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("marc"), None, &[]);
+    /// `application/marcxml+xml`
+    pub const MARCXML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("marcxml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mathematica`
+    pub const MATHEMATICA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mathematica"), None, &[]);
+    /// `application/mathml+xml`
+    pub const MATHML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mathml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mathml-content+xml`
+    pub const MATHML_CONTENT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mathml-content"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mathml-presentation+xml`
+    pub const MATHML_PRESENTATION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mathml-presentation"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-associated-procedure-description+xml`
+    pub const MBMS_ASSOCIATED_PROCEDURE_DESCRIPTION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-associated-procedure-description"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-deregister+xml`
+    pub const MBMS_DEREGISTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-deregister"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-envelope+xml`
+    pub const MBMS_ENVELOPE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-envelope"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-msk+xml`
+    pub const MBMS_MSK_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-msk"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-msk-response+xml`
+    pub const MBMS_MSK_RESPONSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-msk-response"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-protection-description+xml`
+    pub const MBMS_PROTECTION_DESCRIPTION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-protection-description"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-reception-report+xml`
+    pub const MBMS_RECEPTION_REPORT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-reception-report"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-register+xml`
+    pub const MBMS_REGISTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-register"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-register-response+xml`
+    pub const MBMS_REGISTER_RESPONSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-register-response"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-schedule+xml`
+    pub const MBMS_SCHEDULE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-schedule"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbms-user-service-description+xml`
+    pub const MBMS_USER_SERVICE_DESCRIPTION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbms-user-service-description"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mbox`
+    pub const MBOX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mbox"), None, &[]);
+    /// `application/media-policy-dataset+xml`
+    pub const MEDIA_POLICY_DATASET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("media-policy-dataset"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mediaservercontrol+xml`
+    pub const MEDIASERVERCONTROL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mediaservercontrol"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/media_control+xml`
+    pub const MEDIA_CONTROL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("media_control"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/merge-patch+json`
+    pub const MERGE_PATCH_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("merge-patch"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/metalink4+xml`
+    pub const METALINK4_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("metalink4"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mets+xml`
+    pub const METS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mets"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/MF4`
+    pub const MF4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("MF4"), None, &[]);
+    /// `application/mikey`
+    pub const MIKEY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mikey"), None, &[]);
+    /// `application/mipc`
+    pub const MIPC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mipc"), None, &[]);
+    /// `application/missing-blocks+cbor-seq`
+    pub const MISSING_BLOCKS_PLUS_CBOR_SEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("missing-blocks"), Some(crate::Name::new_unchecked("cbor-seq")), &[]);
+    /// `application/mmt-aei+xml`
+    pub const MMT_AEI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mmt-aei"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mmt-usd+xml`
+    pub const MMT_USD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mmt-usd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mods+xml`
+    pub const MODS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mods"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/moss-keys`
+    pub const MOSS_KEYS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("moss-keys"), None, &[]);
+    /// `application/moss-signature`
+    pub const MOSS_SIGNATURE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("moss-signature"), None, &[]);
+    /// `application/mosskey-data`
+    pub const MOSSKEY_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mosskey-data"), None, &[]);
+    /// `application/mosskey-request`
+    pub const MOSSKEY_REQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mosskey-request"), None, &[]);
+    /// `application/mp21`
+    pub const MP21: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mp21"), None, &[]);
+    /// `application/mp4`
+    pub const MP4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mp4"), None, &[]);
+    /// `application/mpeg4-generic`
+    pub const MPEG4_GENERIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mpeg4-generic"), None, &[]);
+    /// `application/mpeg4-iod`
+    pub const MPEG4_IOD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mpeg4-iod"), None, &[]);
+    /// `application/mpeg4-iod-xmt`
+    pub const MPEG4_IOD_XMT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mpeg4-iod-xmt"), None, &[]);
+    /// `application/mrb-consumer+xml`
+    pub const MRB_CONSUMER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mrb-consumer"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/mrb-publish+xml`
+    pub const MRB_PUBLISH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mrb-publish"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/msaccess`
+    pub const MSACCESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("msaccess"), None, &[]);
+    /// `application/msc-ivr+xml`
+    pub const MSC_IVR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("msc-ivr"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/msc-mixer+xml`
+    pub const MSC_MIXER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("msc-mixer"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/msword`
+    pub const MSWORD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("msword"), None, &[]);
+    /// `application/mud+json`
+    pub const MUD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mud"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/multipart-core`
+    pub const MULTIPART_CORE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("multipart-core"), None, &[]);
+    /// `application/mxf`
+    pub const MXF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("mxf"), None, &[]);
+    /// `application/n-quads`
+    pub const N_QUADS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("n-quads"), None, &[]);
+    /// `application/n-triples`
+    pub const N_TRIPLES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("n-triples"), None, &[]);
+    /// `application/nasdata`
+    pub const NASDATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("nasdata"), None, &[]);
+    /// `application/news-checkgroups`
+    pub const NEWS_CHECKGROUPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("news-checkgroups"), None, &[]);
+    /// `application/news-groupinfo`
+    pub const NEWS_GROUPINFO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("news-groupinfo"), None, &[]);
+    /// `application/news-transmission`
+    pub const NEWS_TRANSMISSION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("news-transmission"), None, &[]);
+    /// `application/nlsml+xml`
+    pub const NLSML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("nlsml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/node`
+    pub const NODE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("node"), None, &[]);
+    /// `application/nss`
+    pub const NSS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("nss"), None, &[]);
+    /// `application/oauth-authz-req+jwt`
+    pub const OAUTH_AUTHZ_REQ_PLUS_JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("oauth-authz-req"), Some(crate::Name::new_unchecked("jwt")), &[]);
+    /// `application/oblivious-dns-message`
+    pub const OBLIVIOUS_DNS_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("oblivious-dns-message"), None, &[]);
+    /// `application/ocsp-request`
+    pub const OCSP_REQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ocsp-request"), None, &[]);
+    /// `application/ocsp-response`
+    pub const OCSP_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ocsp-response"), None, &[]);
+    /// `application/octet-stream`
+    pub const OCTET_STREAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("octet-stream"), None, &[]);
+    /// `application/ODA`
+    pub const ODA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ODA"), None, &[]);
+    /// `application/odm+xml`
+    pub const ODM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("odm"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ODX`
+    pub const ODX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ODX"), None, &[]);
+    /// `application/oebps-package+xml`
+    pub const OEBPS_PACKAGE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("oebps-package"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ogg`
+    pub const OGG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ogg"), None, &[]);
+    /// `application/onenote`
+    pub const ONENOTE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("onenote"), None, &[]);
+    /// `application/opc-nodeset+xml`
+    pub const OPC_NODESET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("opc-nodeset"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/oscore`
+    pub const OSCORE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("oscore"), None, &[]);
+    /// `application/oxps`
+    pub const OXPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("oxps"), None, &[]);
+    /// `application/p21`
+    pub const P21: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("p21"), None, &[]);
+    /// `application/p21+zip`
+    pub const P21_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("p21"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/p2p-overlay+xml`
+    pub const P2P_OVERLAY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("p2p-overlay"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/parityfec`
+    pub const PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("parityfec"), None, &[]);
+    /// `application/passport`
+    pub const PASSPORT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("passport"), None, &[]);
+    /// `application/patch-ops-error+xml`
+    pub const PATCH_OPS_ERROR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("patch-ops-error"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/pdf`
+    pub const PDF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pdf"), None, &[]);
+    /// `application/PDX`
+    pub const PDX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("PDX"), None, &[]);
+    /// `application/pem-certificate-chain`
+    pub const PEM_CERTIFICATE_CHAIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pem-certificate-chain"), None, &[]);
+    /// `application/pgp-encrypted`
+    pub const PGP_ENCRYPTED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pgp-encrypted"), None, &[]);
+    /// `application/pgp-keys`
+    pub const PGP_KEYS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pgp-keys"), None, &[]);
+    /// `application/pgp-signature`
+    pub const PGP_SIGNATURE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pgp-signature"), None, &[]);
+    /// `application/pics-rules`
+    pub const PICS_RULES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pics-rules"), None, &[]);
+    /// `application/pidf+xml`
+    pub const PIDF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pidf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/pidf-diff+xml`
+    pub const PIDF_DIFF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pidf-diff"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/pkcs10`
+    pub const PKCS10: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs10"), None, &[]);
+    /// `application/pkcs12`
+    pub const PKCS12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs12"), None, &[]);
+    /// `application/pkcs7-mime`
+    pub const PKCS7_MIME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs7-mime"), None, &[]);
+    /// `application/pkcs7-signature`
+    pub const PKCS7_SIGNATURE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs7-signature"), None, &[]);
+    /// `application/pkcs8`
+    pub const PKCS8: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs8"), None, &[]);
+    /// `application/pkcs8-encrypted`
+    pub const PKCS8_ENCRYPTED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkcs8-encrypted"), None, &[]);
+    /// `application/pkix-attr-cert`
+    pub const PKIX_ATTR_CERT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkix-attr-cert"), None, &[]);
+    /// `application/pkix-cert`
+    pub const PKIX_CERT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkix-cert"), None, &[]);
+    /// `application/pkix-crl`
+    pub const PKIX_CRL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkix-crl"), None, &[]);
+    /// `application/pkix-pkipath`
+    pub const PKIX_PKIPATH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkix-pkipath"), None, &[]);
+    /// `application/pkixcmp`
+    pub const PKIXCMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pkixcmp"), None, &[]);
+    /// `application/pls+xml`
+    pub const PLS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pls"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/poc-settings+xml`
+    pub const POC_SETTINGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("poc-settings"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/postscript`
+    pub const POSTSCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("postscript"), None, &[]);
+    /// `application/ppsp-tracker+json`
+    pub const PPSP_TRACKER_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ppsp-tracker"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/problem+json`
+    pub const PROBLEM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("problem"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/problem+xml`
+    pub const PROBLEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("problem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/provenance+xml`
+    pub const PROVENANCE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("provenance"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/prs.alvestrand.titrax-sheet`
+    pub const PRS_ALVESTRAND_TITRAX_SHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.alvestrand.titrax-sheet"), None, &[]);
+    /// `application/prs.cww`
+    pub const PRS_CWW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.cww"), None, &[]);
+    /// `application/prs.cyn`
+    pub const PRS_CYN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.cyn"), None, &[]);
+    /// `application/prs.hpub+zip`
+    pub const PRS_HPUB_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.hpub"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/prs.nprend`
+    pub const PRS_NPREND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.nprend"), None, &[]);
+    /// `application/prs.plucker`
+    pub const PRS_PLUCKER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.plucker"), None, &[]);
+    /// `application/prs.rdf-xml-crypt`
+    pub const PRS_RDF_XML_CRYPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.rdf-xml-crypt"), None, &[]);
+    /// `application/prs.xsf+xml`
+    pub const PRS_XSF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("prs.xsf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/pskc+xml`
+    pub const PSKC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pskc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/pvd+json`
+    pub const PVD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("pvd"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/QSIG`
+    pub const QSIG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("QSIG"), None, &[]);
+    /// `application/raptorfec`
+    pub const RAPTORFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("raptorfec"), None, &[]);
+    /// `application/rdap+json`
+    pub const RDAP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rdap"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/rdf+xml`
+    pub const RDF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rdf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/reginfo+xml`
+    pub const REGINFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("reginfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/relax-ng-compact-syntax`
+    pub const RELAX_NG_COMPACT_SYNTAX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("relax-ng-compact-syntax"), None, &[]);
+    /// `application/reputon+json`
+    pub const REPUTON_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("reputon"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/resource-lists+xml`
+    pub const RESOURCE_LISTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("resource-lists"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/resource-lists-diff+xml`
+    pub const RESOURCE_LISTS_DIFF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("resource-lists-diff"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/rfc+xml`
+    pub const RFC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rfc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/riscos`
+    pub const RISCOS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("riscos"), None, &[]);
+    /// `application/rlmi+xml`
+    pub const RLMI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rlmi"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/rls-services+xml`
+    pub const RLS_SERVICES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rls-services"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/route-apd+xml`
+    pub const ROUTE_APD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("route-apd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/route-s-tsid+xml`
+    pub const ROUTE_S_TSID_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("route-s-tsid"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/route-usd+xml`
+    pub const ROUTE_USD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("route-usd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/rpki-checklist`
+    pub const RPKI_CHECKLIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-checklist"), None, &[]);
+    /// `application/rpki-ghostbusters`
+    pub const RPKI_GHOSTBUSTERS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-ghostbusters"), None, &[]);
+    /// `application/rpki-manifest`
+    pub const RPKI_MANIFEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-manifest"), None, &[]);
+    /// `application/rpki-publication`
+    pub const RPKI_PUBLICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-publication"), None, &[]);
+    /// `application/rpki-roa`
+    pub const RPKI_ROA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-roa"), None, &[]);
+    /// `application/rpki-updown`
+    pub const RPKI_UPDOWN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rpki-updown"), None, &[]);
+    /// `application/rtf`
+    pub const RTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rtf"), None, &[]);
+    /// `application/rtploopback`
+    pub const RTPLOOPBACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rtploopback"), None, &[]);
+    /// `application/rtx`
+    pub const RTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("rtx"), None, &[]);
+    /// `application/samlassertion+xml`
+    pub const SAMLASSERTION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("samlassertion"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/samlmetadata+xml`
+    pub const SAMLMETADATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("samlmetadata"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sarif+json`
+    pub const SARIF_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sarif"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/sarif-external-properties+json`
+    pub const SARIF_EXTERNAL_PROPERTIES_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sarif-external-properties"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/sbe`
+    pub const SBE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sbe"), None, &[]);
+    /// `application/sbml+xml`
+    pub const SBML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sbml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/scaip+xml`
+    pub const SCAIP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scaip"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/scim+json`
+    pub const SCIM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scim"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/scvp-cv-request`
+    pub const SCVP_CV_REQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scvp-cv-request"), None, &[]);
+    /// `application/scvp-cv-response`
+    pub const SCVP_CV_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scvp-cv-response"), None, &[]);
+    /// `application/scvp-vp-request`
+    pub const SCVP_VP_REQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scvp-vp-request"), None, &[]);
+    /// `application/scvp-vp-response`
+    pub const SCVP_VP_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("scvp-vp-response"), None, &[]);
+    /// `application/sdp`
+    pub const SDP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sdp"), None, &[]);
+    /// `application/secevent+jwt`
+    pub const SECEVENT_PLUS_JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("secevent"), Some(crate::Name::new_unchecked("jwt")), &[]);
+    /// `application/senml+cbor`
+    pub const SENML_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/senml+json`
+    pub const SENML_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/senml+xml`
+    pub const SENML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/senml-etch+cbor`
+    pub const SENML_ETCH_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml-etch"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/senml-etch+json`
+    pub const SENML_ETCH_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml-etch"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/senml-exi`
+    pub const SENML_EXI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("senml-exi"), None, &[]);
+    /// `application/sensml+cbor`
+    pub const SENSML_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sensml"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/sensml+json`
+    pub const SENSML_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sensml"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/sensml+xml`
+    pub const SENSML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sensml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sensml-exi`
+    pub const SENSML_EXI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sensml-exi"), None, &[]);
+    /// `application/sep+xml`
+    pub const SEP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sep"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sep-exi`
+    pub const SEP_EXI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sep-exi"), None, &[]);
+    /// `application/session-info`
+    pub const SESSION_INFO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("session-info"), None, &[]);
+    /// `application/set-payment`
+    pub const SET_PAYMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("set-payment"), None, &[]);
+    /// `application/set-payment-initiation`
+    pub const SET_PAYMENT_INITIATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("set-payment-initiation"), None, &[]);
+    /// `application/set-registration`
+    pub const SET_REGISTRATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("set-registration"), None, &[]);
+    /// `application/set-registration-initiation`
+    pub const SET_REGISTRATION_INITIATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("set-registration-initiation"), None, &[]);
+    /// `application/SGML`
+    pub const SGML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("SGML"), None, &[]);
+    /// `application/sgml-open-catalog`
+    pub const SGML_OPEN_CATALOG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sgml-open-catalog"), None, &[]);
+    /// `application/shf+xml`
+    pub const SHF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("shf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sieve`
+    pub const SIEVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sieve"), None, &[]);
+    /// `application/simple-filter+xml`
+    pub const SIMPLE_FILTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("simple-filter"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/simple-message-summary`
+    pub const SIMPLE_MESSAGE_SUMMARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("simple-message-summary"), None, &[]);
+    /// `application/simpleSymbolContainer`
+    pub const SIMPLE_SYMBOL_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("simpleSymbolContainer"), None, &[]);
+    /// `application/sipc`
+    pub const SIPC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sipc"), None, &[]);
+    /// `application/slate`
+    pub const SLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("slate"), None, &[]);
+    /// `application/smil+xml`
+    pub const SMIL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("smil"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/smpte336m`
+    pub const SMPTE336M: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("smpte336m"), None, &[]);
+    /// `application/soap+fastinfoset`
+    pub const SOAP_PLUS_FASTINFOSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("soap"), Some(crate::Name::new_unchecked("fastinfoset")), &[]);
+    /// `application/soap+xml`
+    pub const SOAP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("soap"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sparql-query`
+    pub const SPARQL_QUERY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sparql-query"), None, &[]);
+    /// `application/sparql-results+xml`
+    pub const SPARQL_RESULTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sparql-results"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/spdx+json`
+    pub const SPDX_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("spdx"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/spirits-event+xml`
+    pub const SPIRITS_EVENT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("spirits-event"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sql`
+    pub const SQL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sql"), None, &[]);
+    /// `application/srgs`
+    pub const SRGS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("srgs"), None, &[]);
+    /// `application/srgs+xml`
+    pub const SRGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("srgs"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/sru+xml`
+    pub const SRU_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("sru"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/ssml+xml`
+    pub const SSML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ssml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/stix+json`
+    pub const STIX_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("stix"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/swid+cbor`
+    pub const SWID_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("swid"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/swid+xml`
+    pub const SWID_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("swid"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/tamp-apex-update`
+    pub const TAMP_APEX_UPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-apex-update"), None, &[]);
+    /// `application/tamp-apex-update-confirm`
+    pub const TAMP_APEX_UPDATE_CONFIRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-apex-update-confirm"), None, &[]);
+    /// `application/tamp-community-update`
+    pub const TAMP_COMMUNITY_UPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-community-update"), None, &[]);
+    /// `application/tamp-community-update-confirm`
+    pub const TAMP_COMMUNITY_UPDATE_CONFIRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-community-update-confirm"), None, &[]);
+    /// `application/tamp-error`
+    pub const TAMP_ERROR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-error"), None, &[]);
+    /// `application/tamp-sequence-adjust`
+    pub const TAMP_SEQUENCE_ADJUST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-sequence-adjust"), None, &[]);
+    /// `application/tamp-sequence-adjust-confirm`
+    pub const TAMP_SEQUENCE_ADJUST_CONFIRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-sequence-adjust-confirm"), None, &[]);
+    /// `application/tamp-status-query`
+    pub const TAMP_STATUS_QUERY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-status-query"), None, &[]);
+    /// `application/tamp-status-response`
+    pub const TAMP_STATUS_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-status-response"), None, &[]);
+    /// `application/tamp-update`
+    pub const TAMP_UPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-update"), None, &[]);
+    /// `application/tamp-update-confirm`
+    pub const TAMP_UPDATE_CONFIRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tamp-update-confirm"), None, &[]);
+    /// `application/taxii+json`
+    pub const TAXII_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("taxii"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/td+json`
+    pub const TD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("td"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/tei+xml`
+    pub const TEI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tei"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/TETRA_ISI`
+    pub const TETRA_ISI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("TETRA_ISI"), None, &[]);
+    /// `application/thraud+xml`
+    pub const THRAUD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("thraud"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/timestamp-query`
+    pub const TIMESTAMP_QUERY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("timestamp-query"), None, &[]);
+    /// `application/timestamp-reply`
+    pub const TIMESTAMP_REPLY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("timestamp-reply"), None, &[]);
+    /// `application/timestamped-data`
+    pub const TIMESTAMPED_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("timestamped-data"), None, &[]);
+    /// `application/tlsrpt+gzip`
+    pub const TLSRPT_PLUS_GZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tlsrpt"), Some(crate::Name::new_unchecked("gzip")), &[]);
+    /// `application/tlsrpt+json`
+    pub const TLSRPT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tlsrpt"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/tm+json`
+    pub const TM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tm"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/tnauthlist`
+    pub const TNAUTHLIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tnauthlist"), None, &[]);
+    /// `application/token-introspection+jwt`
+    pub const TOKEN_INTROSPECTION_PLUS_JWT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("token-introspection"), Some(crate::Name::new_unchecked("jwt")), &[]);
+    /// `application/trickle-ice-sdpfrag`
+    pub const TRICKLE_ICE_SDPFRAG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("trickle-ice-sdpfrag"), None, &[]);
+    /// `application/trig`
+    pub const TRIG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("trig"), None, &[]);
+    /// `application/ttml+xml`
+    pub const TTML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ttml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/tve-trigger`
+    pub const TVE_TRIGGER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tve-trigger"), None, &[]);
+    /// `application/tzif`
+    pub const TZIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tzif"), None, &[]);
+    /// `application/tzif-leap`
+    pub const TZIF_LEAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("tzif-leap"), None, &[]);
+    /// `application/ulpfec`
+    pub const ULPFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("ulpfec"), None, &[]);
+    /// `application/urc-grpsheet+xml`
+    pub const URC_GRPSHEET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("urc-grpsheet"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/urc-ressheet+xml`
+    pub const URC_RESSHEET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("urc-ressheet"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/urc-targetdesc+xml`
+    pub const URC_TARGETDESC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("urc-targetdesc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/urc-uisocketdesc+xml`
+    pub const URC_UISOCKETDESC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("urc-uisocketdesc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vcard+json`
+    pub const VCARD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vcard"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vcard+xml`
+    pub const VCARD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vcard"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vemmi`
+    pub const VEMMI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vemmi"), None, &[]);
+    /// `application/vnd.1000minds.decision-model+xml`
+    pub const VND_1000MINDS_DECISION_MODEL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.1000minds.decision-model"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-prose+xml`
+    pub const VND_3GPP_PROSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-prose"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-prose-pc3a+xml`
+    pub const VND_3GPP_PROSE_PC3A_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-prose-pc3a"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-prose-pc3ach+xml`
+    pub const VND_3GPP_PROSE_PC3ACH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-prose-pc3ach"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-prose-pc3ch+xml`
+    pub const VND_3GPP_PROSE_PC3CH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-prose-pc3ch"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-prose-pc8+xml`
+    pub const VND_3GPP_PROSE_PC8_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-prose-pc8"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp-v2x-local-service-information`
+    pub const VND_3GPP_V2X_LOCAL_SERVICE_INFORMATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp-v2x-local-service-information"), None, &[]);
+    /// `application/vnd.3gpp.5gnas`
+    pub const VND_3GPP_5GNAS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.5gnas"), None, &[]);
+    /// `application/vnd.3gpp.access-transfer-events+xml`
+    pub const VND_3GPP_ACCESS_TRANSFER_EVENTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.access-transfer-events"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.bsf+xml`
+    pub const VND_3GPP_BSF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.bsf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.GMOP+xml`
+    pub const VND_3GPP_GMOP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.GMOP"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.gtpc`
+    pub const VND_3GPP_GTPC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.gtpc"), None, &[]);
+    /// `application/vnd.3gpp.interworking-data`
+    pub const VND_3GPP_INTERWORKING_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.interworking-data"), None, &[]);
+    /// `application/vnd.3gpp.lpp`
+    pub const VND_3GPP_LPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.lpp"), None, &[]);
+    /// `application/vnd.3gpp.mc-signalling-ear`
+    pub const VND_3GPP_MC_SIGNALLING_EAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mc-signalling-ear"), None, &[]);
+    /// `application/vnd.3gpp.mcdata-affiliation-command+xml`
+    pub const VND_3GPP_MCDATA_AFFILIATION_COMMAND_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-affiliation-command"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-info+xml`
+    pub const VND_3GPP_MCDATA_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-msgstore-ctrl-request+xml`
+    pub const VND_3GPP_MCDATA_MSGSTORE_CTRL_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-msgstore-ctrl-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-payload`
+    pub const VND_3GPP_MCDATA_PAYLOAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-payload"), None, &[]);
+    /// `application/vnd.3gpp.mcdata-regroup+xml`
+    pub const VND_3GPP_MCDATA_REGROUP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-regroup"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-service-config+xml`
+    pub const VND_3GPP_MCDATA_SERVICE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-service-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-signalling`
+    pub const VND_3GPP_MCDATA_SIGNALLING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-signalling"), None, &[]);
+    /// `application/vnd.3gpp.mcdata-ue-config+xml`
+    pub const VND_3GPP_MCDATA_UE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-ue-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcdata-user-profile+xml`
+    pub const VND_3GPP_MCDATA_USER_PROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcdata-user-profile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-affiliation-command+xml`
+    pub const VND_3GPP_MCPTT_AFFILIATION_COMMAND_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-affiliation-command"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-floor-request+xml`
+    pub const VND_3GPP_MCPTT_FLOOR_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-floor-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-info+xml`
+    pub const VND_3GPP_MCPTT_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-location-info+xml`
+    pub const VND_3GPP_MCPTT_LOCATION_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-location-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-mbms-usage-info+xml`
+    pub const VND_3GPP_MCPTT_MBMS_USAGE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-mbms-usage-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-service-config+xml`
+    pub const VND_3GPP_MCPTT_SERVICE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-service-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-signed+xml`
+    pub const VND_3GPP_MCPTT_SIGNED_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-signed"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-ue-config+xml`
+    pub const VND_3GPP_MCPTT_UE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-ue-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-ue-init-config+xml`
+    pub const VND_3GPP_MCPTT_UE_INIT_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-ue-init-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcptt-user-profile+xml`
+    pub const VND_3GPP_MCPTT_USER_PROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcptt-user-profile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-affiliation-command+xml`
+    pub const VND_3GPP_MCVIDEO_AFFILIATION_COMMAND_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-affiliation-command"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-info+xml`
+    pub const VND_3GPP_MCVIDEO_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-location-info+xml`
+    pub const VND_3GPP_MCVIDEO_LOCATION_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-location-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-mbms-usage-info+xml`
+    pub const VND_3GPP_MCVIDEO_MBMS_USAGE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-mbms-usage-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-service-config+xml`
+    pub const VND_3GPP_MCVIDEO_SERVICE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-service-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-transmission-request+xml`
+    pub const VND_3GPP_MCVIDEO_TRANSMISSION_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-transmission-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-ue-config+xml`
+    pub const VND_3GPP_MCVIDEO_UE_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-ue-config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mcvideo-user-profile+xml`
+    pub const VND_3GPP_MCVIDEO_USER_PROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mcvideo-user-profile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.mid-call+xml`
+    pub const VND_3GPP_MID_CALL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.mid-call"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.ngap`
+    pub const VND_3GPP_NGAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.ngap"), None, &[]);
+    /// `application/vnd.3gpp.pfcp`
+    pub const VND_3GPP_PFCP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.pfcp"), None, &[]);
+    /// `application/vnd.3gpp.pic-bw-large`
+    pub const VND_3GPP_PIC_BW_LARGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.pic-bw-large"), None, &[]);
+    /// `application/vnd.3gpp.pic-bw-small`
+    pub const VND_3GPP_PIC_BW_SMALL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.pic-bw-small"), None, &[]);
+    /// `application/vnd.3gpp.pic-bw-var`
+    pub const VND_3GPP_PIC_BW_VAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.pic-bw-var"), None, &[]);
+    /// `application/vnd.3gpp.s1ap`
+    pub const VND_3GPP_S1AP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.s1ap"), None, &[]);
+    /// `application/vnd.3gpp.sms`
+    pub const VND_3GPP_SMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.sms"), None, &[]);
+    /// `application/vnd.3gpp.sms+xml`
+    pub const VND_3GPP_SMS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.sms"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.srvcc-ext+xml`
+    pub const VND_3GPP_SRVCC_EXT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.srvcc-ext"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.SRVCC-info+xml`
+    pub const VND_3GPP_SRVCC_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.SRVCC-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.state-and-event-info+xml`
+    pub const VND_3GPP_STATE_AND_EVENT_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.state-and-event-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp.ussd+xml`
+    pub const VND_3GPP_USSD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp.ussd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp2.bcmcsinfo+xml`
+    pub const VND_3GPP2_BCMCSINFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp2.bcmcsinfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.3gpp2.sms`
+    pub const VND_3GPP2_SMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp2.sms"), None, &[]);
+    /// `application/vnd.3gpp2.tcap`
+    pub const VND_3GPP2_TCAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3gpp2.tcap"), None, &[]);
+    /// `application/vnd.3lightssoftware.imagescal`
+    pub const VND_3LIGHTSSOFTWARE_IMAGESCAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3lightssoftware.imagescal"), None, &[]);
+    /// `application/vnd.3M.Post-it-Notes`
+    pub const VND_3M_POST_IT_NOTES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.3M.Post-it-Notes"), None, &[]);
+    /// `application/vnd.accpac.simply.aso`
+    pub const VND_ACCPAC_SIMPLY_ASO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.accpac.simply.aso"), None, &[]);
+    /// `application/vnd.accpac.simply.imp`
+    pub const VND_ACCPAC_SIMPLY_IMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.accpac.simply.imp"), None, &[]);
+    /// `application/vnd.acucobol`
+    pub const VND_ACUCOBOL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.acucobol"), None, &[]);
+    /// `application/vnd.acucorp`
+    pub const VND_ACUCORP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.acucorp"), None, &[]);
+    /// `application/vnd.adobe.flash.movie`
+    pub const VND_ADOBE_FLASH_MOVIE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.adobe.flash.movie"), None, &[]);
+    /// `application/vnd.adobe.formscentral.fcdt`
+    pub const VND_ADOBE_FORMSCENTRAL_FCDT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.adobe.formscentral.fcdt"), None, &[]);
+    /// `application/vnd.adobe.fxp`
+    pub const VND_ADOBE_FXP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.adobe.fxp"), None, &[]);
+    /// `application/vnd.adobe.partial-upload`
+    pub const VND_ADOBE_PARTIAL_UPLOAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.adobe.partial-upload"), None, &[]);
+    /// `application/vnd.adobe.xdp+xml`
+    pub const VND_ADOBE_XDP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.adobe.xdp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.aether.imp`
+    pub const VND_AETHER_IMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.aether.imp"), None, &[]);
+    /// `application/vnd.afpc.afplinedata`
+    pub const VND_AFPC_AFPLINEDATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.afplinedata"), None, &[]);
+    /// `application/vnd.afpc.afplinedata-pagedef`
+    pub const VND_AFPC_AFPLINEDATA_PAGEDEF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.afplinedata-pagedef"), None, &[]);
+    /// `application/vnd.afpc.cmoca-cmresource`
+    pub const VND_AFPC_CMOCA_CMRESOURCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.cmoca-cmresource"), None, &[]);
+    /// `application/vnd.afpc.foca-charset`
+    pub const VND_AFPC_FOCA_CHARSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.foca-charset"), None, &[]);
+    /// `application/vnd.afpc.foca-codedfont`
+    pub const VND_AFPC_FOCA_CODEDFONT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.foca-codedfont"), None, &[]);
+    /// `application/vnd.afpc.foca-codepage`
+    pub const VND_AFPC_FOCA_CODEPAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.foca-codepage"), None, &[]);
+    /// `application/vnd.afpc.modca`
+    pub const VND_AFPC_MODCA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca"), None, &[]);
+    /// `application/vnd.afpc.modca-formdef`
+    pub const VND_AFPC_MODCA_FORMDEF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca-formdef"), None, &[]);
+    /// `application/vnd.afpc.modca-mediummap`
+    pub const VND_AFPC_MODCA_MEDIUMMAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca-mediummap"), None, &[]);
+    /// `application/vnd.afpc.modca-objectcontainer`
+    pub const VND_AFPC_MODCA_OBJECTCONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca-objectcontainer"), None, &[]);
+    /// `application/vnd.afpc.modca-overlay`
+    pub const VND_AFPC_MODCA_OVERLAY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca-overlay"), None, &[]);
+    /// `application/vnd.afpc.modca-pagesegment`
+    pub const VND_AFPC_MODCA_PAGESEGMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.afpc.modca-pagesegment"), None, &[]);
+    /// `application/vnd.age`
+    pub const VND_AGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.age"), None, &[]);
+    /// `application/vnd.ah-barcode`
+    pub const VND_AH_BARCODE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ah-barcode"), None, &[]);
+    /// `application/vnd.ahead.space`
+    pub const VND_AHEAD_SPACE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ahead.space"), None, &[]);
+    /// `application/vnd.airzip.filesecure.azf`
+    pub const VND_AIRZIP_FILESECURE_AZF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.airzip.filesecure.azf"), None, &[]);
+    /// `application/vnd.airzip.filesecure.azs`
+    pub const VND_AIRZIP_FILESECURE_AZS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.airzip.filesecure.azs"), None, &[]);
+    /// `application/vnd.amadeus+json`
+    pub const VND_AMADEUS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.amadeus"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.amazon.mobi8-ebook`
+    pub const VND_AMAZON_MOBI8_EBOOK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.amazon.mobi8-ebook"), None, &[]);
+    /// `application/vnd.americandynamics.acc`
+    pub const VND_AMERICANDYNAMICS_ACC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.americandynamics.acc"), None, &[]);
+    /// `application/vnd.amiga.ami`
+    pub const VND_AMIGA_AMI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.amiga.ami"), None, &[]);
+    /// `application/vnd.amundsen.maze+xml`
+    pub const VND_AMUNDSEN_MAZE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.amundsen.maze"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.android.ota`
+    pub const VND_ANDROID_OTA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.android.ota"), None, &[]);
+    /// `application/vnd.android.package-archive`
+    pub const VND_ANDROID_PACKAGE_ARCHIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.android.package-archive"), None, &[]);
+    /// `application/vnd.anki`
+    pub const VND_ANKI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.anki"), None, &[]);
+    /// `application/vnd.anser-web-certificate-issue-initiation`
+    pub const VND_ANSER_WEB_CERTIFICATE_ISSUE_INITIATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.anser-web-certificate-issue-initiation"), None, &[]);
+    /// `application/vnd.anser-web-funds-transfer-initiation`
+    pub const VND_ANSER_WEB_FUNDS_TRANSFER_INITIATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.anser-web-funds-transfer-initiation"), None, &[]);
+    /// `application/vnd.antix.game-component`
+    pub const VND_ANTIX_GAME_COMPONENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.antix.game-component"), None, &[]);
+    /// `application/vnd.apache.arrow.file`
+    pub const VND_APACHE_ARROW_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apache.arrow.file"), None, &[]);
+    /// `application/vnd.apache.arrow.stream`
+    pub const VND_APACHE_ARROW_STREAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apache.arrow.stream"), None, &[]);
+    /// `application/vnd.apache.thrift.binary`
+    pub const VND_APACHE_THRIFT_BINARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apache.thrift.binary"), None, &[]);
+    /// `application/vnd.apache.thrift.compact`
+    pub const VND_APACHE_THRIFT_COMPACT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apache.thrift.compact"), None, &[]);
+    /// `application/vnd.apache.thrift.json`
+    pub const VND_APACHE_THRIFT_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apache.thrift.json"), None, &[]);
+    /// `application/vnd.apexlang`
+    pub const VND_APEXLANG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apexlang"), None, &[]);
+    /// `application/vnd.api+json`
+    pub const VND_API_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.api"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.aplextor.warrp+json`
+    pub const VND_APLEXTOR_WARRP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.aplextor.warrp"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.apothekende.reservation+json`
+    pub const VND_APOTHEKENDE_RESERVATION_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apothekende.reservation"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.apple.installer+xml`
+    pub const VND_APPLE_INSTALLER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apple.installer"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.apple.keynote`
+    pub const VND_APPLE_KEYNOTE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apple.keynote"), None, &[]);
+    /// `application/vnd.apple.mpegurl`
+    pub const VND_APPLE_MPEGURL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apple.mpegurl"), None, &[]);
+    /// `application/vnd.apple.numbers`
+    pub const VND_APPLE_NUMBERS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apple.numbers"), None, &[]);
+    /// `application/vnd.apple.pages`
+    pub const VND_APPLE_PAGES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.apple.pages"), None, &[]);
+    /// `application/vnd.aristanetworks.swi`
+    pub const VND_ARISTANETWORKS_SWI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.aristanetworks.swi"), None, &[]);
+    /// `application/vnd.artisan+json`
+    pub const VND_ARTISAN_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.artisan"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.artsquare`
+    pub const VND_ARTSQUARE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.artsquare"), None, &[]);
+    /// `application/vnd.astraea-software.iota`
+    pub const VND_ASTRAEA_SOFTWARE_IOTA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.astraea-software.iota"), None, &[]);
+    /// `application/vnd.audiograph`
+    pub const VND_AUDIOGRAPH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.audiograph"), None, &[]);
+    /// `application/vnd.autopackage`
+    pub const VND_AUTOPACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.autopackage"), None, &[]);
+    /// `application/vnd.avalon+json`
+    pub const VND_AVALON_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.avalon"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.avistar+xml`
+    pub const VND_AVISTAR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.avistar"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.balsamiq.bmml+xml`
+    pub const VND_BALSAMIQ_BMML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.balsamiq.bmml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.balsamiq.bmpr`
+    pub const VND_BALSAMIQ_BMPR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.balsamiq.bmpr"), None, &[]);
+    /// `application/vnd.banana-accounting`
+    pub const VND_BANANA_ACCOUNTING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.banana-accounting"), None, &[]);
+    /// `application/vnd.bbf.usp.error`
+    pub const VND_BBF_USP_ERROR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bbf.usp.error"), None, &[]);
+    /// `application/vnd.bbf.usp.msg`
+    pub const VND_BBF_USP_MSG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bbf.usp.msg"), None, &[]);
+    /// `application/vnd.bbf.usp.msg+json`
+    pub const VND_BBF_USP_MSG_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bbf.usp.msg"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.bekitzur-stech+json`
+    pub const VND_BEKITZUR_STECH_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bekitzur-stech"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.belightsoft.lhzd+zip`
+    pub const VND_BELIGHTSOFT_LHZD_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.belightsoft.lhzd"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.belightsoft.lhzl+zip`
+    pub const VND_BELIGHTSOFT_LHZL_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.belightsoft.lhzl"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.bint.med-content`
+    pub const VND_BINT_MED_CONTENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bint.med-content"), None, &[]);
+    /// `application/vnd.biopax.rdf+xml`
+    pub const VND_BIOPAX_RDF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.biopax.rdf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.blink-idb-value-wrapper`
+    pub const VND_BLINK_IDB_VALUE_WRAPPER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.blink-idb-value-wrapper"), None, &[]);
+    /// `application/vnd.blueice.multipass`
+    pub const VND_BLUEICE_MULTIPASS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.blueice.multipass"), None, &[]);
+    /// `application/vnd.bluetooth.ep.oob`
+    pub const VND_BLUETOOTH_EP_OOB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bluetooth.ep.oob"), None, &[]);
+    /// `application/vnd.bluetooth.le.oob`
+    pub const VND_BLUETOOTH_LE_OOB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bluetooth.le.oob"), None, &[]);
+    /// `application/vnd.bmi`
+    pub const VND_BMI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bmi"), None, &[]);
+    /// `application/vnd.bpf`
+    pub const VND_BPF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bpf"), None, &[]);
+    /// `application/vnd.bpf3`
+    pub const VND_BPF3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.bpf3"), None, &[]);
+    /// `application/vnd.businessobjects`
+    pub const VND_BUSINESSOBJECTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.businessobjects"), None, &[]);
+    /// `application/vnd.byu.uapi+json`
+    pub const VND_BYU_UAPI_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.byu.uapi"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.cab-jscript`
+    pub const VND_CAB_JSCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cab-jscript"), None, &[]);
+    /// `application/vnd.canon-cpdl`
+    pub const VND_CANON_CPDL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.canon-cpdl"), None, &[]);
+    /// `application/vnd.canon-lips`
+    pub const VND_CANON_LIPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.canon-lips"), None, &[]);
+    /// `application/vnd.capasystems-pg+json`
+    pub const VND_CAPASYSTEMS_PG_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.capasystems-pg"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.cendio.thinlinc.clientconf`
+    pub const VND_CENDIO_THINLINC_CLIENTCONF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cendio.thinlinc.clientconf"), None, &[]);
+    /// `application/vnd.century-systems.tcp_stream`
+    pub const VND_CENTURY_SYSTEMS_TCP_STREAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.century-systems.tcp_stream"), None, &[]);
+    /// `application/vnd.chemdraw+xml`
+    pub const VND_CHEMDRAW_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.chemdraw"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.chess-pgn`
+    pub const VND_CHESS_PGN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.chess-pgn"), None, &[]);
+    /// `application/vnd.chipnuts.karaoke-mmd`
+    pub const VND_CHIPNUTS_KARAOKE_MMD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.chipnuts.karaoke-mmd"), None, &[]);
+    /// `application/vnd.ciedi`
+    pub const VND_CIEDI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ciedi"), None, &[]);
+    /// `application/vnd.cinderella`
+    pub const VND_CINDERELLA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cinderella"), None, &[]);
+    /// `application/vnd.cirpack.isdn-ext`
+    pub const VND_CIRPACK_ISDN_EXT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cirpack.isdn-ext"), None, &[]);
+    /// `application/vnd.citationstyles.style+xml`
+    pub const VND_CITATIONSTYLES_STYLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.citationstyles.style"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.claymore`
+    pub const VND_CLAYMORE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.claymore"), None, &[]);
+    /// `application/vnd.cloanto.rp9`
+    pub const VND_CLOANTO_RP9: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cloanto.rp9"), None, &[]);
+    /// `application/vnd.clonk.c4group`
+    pub const VND_CLONK_C4GROUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.clonk.c4group"), None, &[]);
+    /// `application/vnd.cluetrust.cartomobile-config`
+    pub const VND_CLUETRUST_CARTOMOBILE_CONFIG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cluetrust.cartomobile-config"), None, &[]);
+    /// `application/vnd.cluetrust.cartomobile-config-pkg`
+    pub const VND_CLUETRUST_CARTOMOBILE_CONFIG_PKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cluetrust.cartomobile-config-pkg"), None, &[]);
+    /// `application/vnd.cncf.helm.chart.content.v1.tar+gzip`
+    pub const VND_CNCF_HELM_CHART_CONTENT_V1_TAR_PLUS_GZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cncf.helm.chart.content.v1.tar"), Some(crate::Name::new_unchecked("gzip")), &[]);
+    /// `application/vnd.cncf.helm.chart.provenance.v1.prov`
+    pub const VND_CNCF_HELM_CHART_PROVENANCE_V1_PROV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cncf.helm.chart.provenance.v1.prov"), None, &[]);
+    /// `application/vnd.coffeescript`
+    pub const VND_COFFEESCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.coffeescript"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.document`
+    pub const VND_COLLABIO_XODOCUMENTS_DOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.document"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.document-template`
+    pub const VND_COLLABIO_XODOCUMENTS_DOCUMENT_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.document-template"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.presentation`
+    pub const VND_COLLABIO_XODOCUMENTS_PRESENTATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.presentation"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.presentation-template`
+    pub const VND_COLLABIO_XODOCUMENTS_PRESENTATION_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.presentation-template"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.spreadsheet`
+    pub const VND_COLLABIO_XODOCUMENTS_SPREADSHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.spreadsheet"), None, &[]);
+    /// `application/vnd.collabio.xodocuments.spreadsheet-template`
+    pub const VND_COLLABIO_XODOCUMENTS_SPREADSHEET_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collabio.xodocuments.spreadsheet-template"), None, &[]);
+    /// `application/vnd.collection+json`
+    pub const VND_COLLECTION_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collection"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.collection.doc+json`
+    pub const VND_COLLECTION_DOC_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collection.doc"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.collection.next+json`
+    pub const VND_COLLECTION_NEXT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.collection.next"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.comicbook+zip`
+    pub const VND_COMICBOOK_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.comicbook"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.comicbook-rar`
+    pub const VND_COMICBOOK_RAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.comicbook-rar"), None, &[]);
+    /// `application/vnd.commerce-battelle`
+    pub const VND_COMMERCE_BATTELLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.commerce-battelle"), None, &[]);
+    /// `application/vnd.commonspace`
+    pub const VND_COMMONSPACE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.commonspace"), None, &[]);
+    /// `application/vnd.contact.cmsg`
+    pub const VND_CONTACT_CMSG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.contact.cmsg"), None, &[]);
+    /// `application/vnd.coreos.ignition+json`
+    pub const VND_COREOS_IGNITION_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.coreos.ignition"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.cosmocaller`
+    pub const VND_COSMOCALLER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cosmocaller"), None, &[]);
+    /// `application/vnd.crick.clicker`
+    pub const VND_CRICK_CLICKER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crick.clicker"), None, &[]);
+    /// `application/vnd.crick.clicker.keyboard`
+    pub const VND_CRICK_CLICKER_KEYBOARD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crick.clicker.keyboard"), None, &[]);
+    /// `application/vnd.crick.clicker.palette`
+    pub const VND_CRICK_CLICKER_PALETTE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crick.clicker.palette"), None, &[]);
+    /// `application/vnd.crick.clicker.template`
+    pub const VND_CRICK_CLICKER_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crick.clicker.template"), None, &[]);
+    /// `application/vnd.crick.clicker.wordbank`
+    pub const VND_CRICK_CLICKER_WORDBANK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crick.clicker.wordbank"), None, &[]);
+    /// `application/vnd.criticaltools.wbs+xml`
+    pub const VND_CRITICALTOOLS_WBS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.criticaltools.wbs"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.cryptii.pipe+json`
+    pub const VND_CRYPTII_PIPE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cryptii.pipe"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.crypto-shade-file`
+    pub const VND_CRYPTO_SHADE_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.crypto-shade-file"), None, &[]);
+    /// `application/vnd.cryptomator.encrypted`
+    pub const VND_CRYPTOMATOR_ENCRYPTED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cryptomator.encrypted"), None, &[]);
+    /// `application/vnd.cryptomator.vault`
+    pub const VND_CRYPTOMATOR_VAULT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cryptomator.vault"), None, &[]);
+    /// `application/vnd.ctc-posml`
+    pub const VND_CTC_POSML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ctc-posml"), None, &[]);
+    /// `application/vnd.ctct.ws+xml`
+    pub const VND_CTCT_WS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ctct.ws"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.cups-pdf`
+    pub const VND_CUPS_PDF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cups-pdf"), None, &[]);
+    /// `application/vnd.cups-postscript`
+    pub const VND_CUPS_POSTSCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cups-postscript"), None, &[]);
+    /// `application/vnd.cups-ppd`
+    pub const VND_CUPS_PPD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cups-ppd"), None, &[]);
+    /// `application/vnd.cups-raster`
+    pub const VND_CUPS_RASTER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cups-raster"), None, &[]);
+    /// `application/vnd.cups-raw`
+    pub const VND_CUPS_RAW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cups-raw"), None, &[]);
+    /// `application/vnd.curl`
+    pub const VND_CURL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.curl"), None, &[]);
+    /// `application/vnd.cyan.dean.root+xml`
+    pub const VND_CYAN_DEAN_ROOT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cyan.dean.root"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.cybank`
+    pub const VND_CYBANK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cybank"), None, &[]);
+    /// `application/vnd.cyclonedx+json`
+    pub const VND_CYCLONEDX_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cyclonedx"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.cyclonedx+xml`
+    pub const VND_CYCLONEDX_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.cyclonedx"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.d2l.coursepackage1p0+zip`
+    pub const VND_D2L_COURSEPACKAGE1P0_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.d2l.coursepackage1p0"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.d3m-dataset`
+    pub const VND_D3M_DATASET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.d3m-dataset"), None, &[]);
+    /// `application/vnd.d3m-problem`
+    pub const VND_D3M_PROBLEM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.d3m-problem"), None, &[]);
+    /// `application/vnd.dart`
+    pub const VND_DART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dart"), None, &[]);
+    /// `application/vnd.data-vision.rdz`
+    pub const VND_DATA_VISION_RDZ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.data-vision.rdz"), None, &[]);
+    /// `application/vnd.datalog`
+    pub const VND_DATALOG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.datalog"), None, &[]);
+    /// `application/vnd.datapackage+json`
+    pub const VND_DATAPACKAGE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.datapackage"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.dataresource+json`
+    pub const VND_DATARESOURCE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dataresource"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.dbf`
+    pub const VND_DBF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dbf"), None, &[]);
+    /// `application/vnd.debian.binary-package`
+    pub const VND_DEBIAN_BINARY_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.debian.binary-package"), None, &[]);
+    /// `application/vnd.dece.data`
+    pub const VND_DECE_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dece.data"), None, &[]);
+    /// `application/vnd.dece.ttml+xml`
+    pub const VND_DECE_TTML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dece.ttml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dece.unspecified`
+    pub const VND_DECE_UNSPECIFIED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dece.unspecified"), None, &[]);
+    /// `application/vnd.dece.zip`
+    pub const VND_DECE_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dece.zip"), None, &[]);
+    /// `application/vnd.denovo.fcselayout-link`
+    pub const VND_DENOVO_FCSELAYOUT_LINK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.denovo.fcselayout-link"), None, &[]);
+    /// `application/vnd.desmume.movie`
+    pub const VND_DESMUME_MOVIE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.desmume.movie"), None, &[]);
+    /// `application/vnd.dir-bi.plate-dl-nosuffix`
+    pub const VND_DIR_BI_PLATE_DL_NOSUFFIX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dir-bi.plate-dl-nosuffix"), None, &[]);
+    /// `application/vnd.dm.delegation+xml`
+    pub const VND_DM_DELEGATION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dm.delegation"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dna`
+    pub const VND_DNA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dna"), None, &[]);
+    /// `application/vnd.document+json`
+    pub const VND_DOCUMENT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.document"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.dolby.mobile.1`
+    pub const VND_DOLBY_MOBILE_1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dolby.mobile.1"), None, &[]);
+    /// `application/vnd.dolby.mobile.2`
+    pub const VND_DOLBY_MOBILE_2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dolby.mobile.2"), None, &[]);
+    /// `application/vnd.doremir.scorecloud-binary-document`
+    pub const VND_DOREMIR_SCORECLOUD_BINARY_DOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.doremir.scorecloud-binary-document"), None, &[]);
+    /// `application/vnd.dpgraph`
+    pub const VND_DPGRAPH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dpgraph"), None, &[]);
+    /// `application/vnd.dreamfactory`
+    pub const VND_DREAMFACTORY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dreamfactory"), None, &[]);
+    /// `application/vnd.drive+json`
+    pub const VND_DRIVE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.drive"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.dtg.local`
+    pub const VND_DTG_LOCAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dtg.local"), None, &[]);
+    /// `application/vnd.dtg.local.flash`
+    pub const VND_DTG_LOCAL_FLASH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dtg.local.flash"), None, &[]);
+    /// `application/vnd.dtg.local.html`
+    pub const VND_DTG_LOCAL_HTML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dtg.local.html"), None, &[]);
+    /// `application/vnd.dvb.ait`
+    pub const VND_DVB_AIT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ait"), None, &[]);
+    /// `application/vnd.dvb.dvbisl+xml`
+    pub const VND_DVB_DVBISL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.dvbisl"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.dvbj`
+    pub const VND_DVB_DVBJ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.dvbj"), None, &[]);
+    /// `application/vnd.dvb.esgcontainer`
+    pub const VND_DVB_ESGCONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.esgcontainer"), None, &[]);
+    /// `application/vnd.dvb.ipdcdftnotifaccess`
+    pub const VND_DVB_IPDCDFTNOTIFACCESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ipdcdftnotifaccess"), None, &[]);
+    /// `application/vnd.dvb.ipdcesgaccess`
+    pub const VND_DVB_IPDCESGACCESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ipdcesgaccess"), None, &[]);
+    /// `application/vnd.dvb.ipdcesgaccess2`
+    pub const VND_DVB_IPDCESGACCESS2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ipdcesgaccess2"), None, &[]);
+    /// `application/vnd.dvb.ipdcesgpdd`
+    pub const VND_DVB_IPDCESGPDD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ipdcesgpdd"), None, &[]);
+    /// `application/vnd.dvb.ipdcroaming`
+    pub const VND_DVB_IPDCROAMING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.ipdcroaming"), None, &[]);
+    /// `application/vnd.dvb.iptv.alfec-base`
+    pub const VND_DVB_IPTV_ALFEC_BASE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.iptv.alfec-base"), None, &[]);
+    /// `application/vnd.dvb.iptv.alfec-enhancement`
+    pub const VND_DVB_IPTV_ALFEC_ENHANCEMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.iptv.alfec-enhancement"), None, &[]);
+    /// `application/vnd.dvb.notif-aggregate-root+xml`
+    pub const VND_DVB_NOTIF_AGGREGATE_ROOT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-aggregate-root"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-container+xml`
+    pub const VND_DVB_NOTIF_CONTAINER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-container"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-generic+xml`
+    pub const VND_DVB_NOTIF_GENERIC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-generic"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-ia-msglist+xml`
+    pub const VND_DVB_NOTIF_IA_MSGLIST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-ia-msglist"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-ia-registration-request+xml`
+    pub const VND_DVB_NOTIF_IA_REGISTRATION_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-ia-registration-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-ia-registration-response+xml`
+    pub const VND_DVB_NOTIF_IA_REGISTRATION_RESPONSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-ia-registration-response"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.notif-init+xml`
+    pub const VND_DVB_NOTIF_INIT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.notif-init"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.dvb.pfr`
+    pub const VND_DVB_PFR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.pfr"), None, &[]);
+    /// `application/vnd.dvb.service`
+    pub const VND_DVB_SERVICE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dvb.service"), None, &[]);
+    /// `application/vnd.dxr`
+    pub const VND_DXR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dxr"), None, &[]);
+    /// `application/vnd.dynageo`
+    pub const VND_DYNAGEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dynageo"), None, &[]);
+    /// `application/vnd.dzr`
+    pub const VND_DZR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.dzr"), None, &[]);
+    /// `application/vnd.easykaraoke.cdgdownload`
+    pub const VND_EASYKARAOKE_CDGDOWNLOAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.easykaraoke.cdgdownload"), None, &[]);
+    /// `application/vnd.ecdis-update`
+    pub const VND_ECDIS_UPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecdis-update"), None, &[]);
+    /// `application/vnd.ecip.rlp`
+    pub const VND_ECIP_RLP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecip.rlp"), None, &[]);
+    /// `application/vnd.eclipse.ditto+json`
+    pub const VND_ECLIPSE_DITTO_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eclipse.ditto"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ecowin.chart`
+    pub const VND_ECOWIN_CHART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.chart"), None, &[]);
+    /// `application/vnd.ecowin.filerequest`
+    pub const VND_ECOWIN_FILEREQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.filerequest"), None, &[]);
+    /// `application/vnd.ecowin.fileupdate`
+    pub const VND_ECOWIN_FILEUPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.fileupdate"), None, &[]);
+    /// `application/vnd.ecowin.series`
+    pub const VND_ECOWIN_SERIES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.series"), None, &[]);
+    /// `application/vnd.ecowin.seriesrequest`
+    pub const VND_ECOWIN_SERIESREQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.seriesrequest"), None, &[]);
+    /// `application/vnd.ecowin.seriesupdate`
+    pub const VND_ECOWIN_SERIESUPDATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ecowin.seriesupdate"), None, &[]);
+    /// `application/vnd.efi.img`
+    pub const VND_EFI_IMG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.efi.img"), None, &[]);
+    /// `application/vnd.efi.iso`
+    pub const VND_EFI_ISO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.efi.iso"), None, &[]);
+    /// `application/vnd.eln+zip`
+    pub const VND_ELN_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eln"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.emclient.accessrequest+xml`
+    pub const VND_EMCLIENT_ACCESSREQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.emclient.accessrequest"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.enliven`
+    pub const VND_ENLIVEN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.enliven"), None, &[]);
+    /// `application/vnd.enphase.envoy`
+    pub const VND_ENPHASE_ENVOY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.enphase.envoy"), None, &[]);
+    /// `application/vnd.eprints.data+xml`
+    pub const VND_EPRINTS_DATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eprints.data"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.epson.esf`
+    pub const VND_EPSON_ESF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.epson.esf"), None, &[]);
+    /// `application/vnd.epson.msf`
+    pub const VND_EPSON_MSF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.epson.msf"), None, &[]);
+    /// `application/vnd.epson.quickanime`
+    pub const VND_EPSON_QUICKANIME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.epson.quickanime"), None, &[]);
+    /// `application/vnd.epson.salt`
+    pub const VND_EPSON_SALT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.epson.salt"), None, &[]);
+    /// `application/vnd.epson.ssf`
+    pub const VND_EPSON_SSF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.epson.ssf"), None, &[]);
+    /// `application/vnd.ericsson.quickcall`
+    pub const VND_ERICSSON_QUICKCALL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ericsson.quickcall"), None, &[]);
+    /// `application/vnd.espass-espass+zip`
+    pub const VND_ESPASS_ESPASS_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.espass-espass"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.eszigno3+xml`
+    pub const VND_ESZIGNO3_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eszigno3"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.aoc+xml`
+    pub const VND_ETSI_AOC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.aoc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.asic-e+zip`
+    pub const VND_ETSI_ASIC_E_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.asic-e"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.etsi.asic-s+zip`
+    pub const VND_ETSI_ASIC_S_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.asic-s"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.etsi.cug+xml`
+    pub const VND_ETSI_CUG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.cug"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvcommand+xml`
+    pub const VND_ETSI_IPTVCOMMAND_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvcommand"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvdiscovery+xml`
+    pub const VND_ETSI_IPTVDISCOVERY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvdiscovery"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvprofile+xml`
+    pub const VND_ETSI_IPTVPROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvprofile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvsad-bc+xml`
+    pub const VND_ETSI_IPTVSAD_BC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvsad-bc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvsad-cod+xml`
+    pub const VND_ETSI_IPTVSAD_COD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvsad-cod"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvsad-npvr+xml`
+    pub const VND_ETSI_IPTVSAD_NPVR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvsad-npvr"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvservice+xml`
+    pub const VND_ETSI_IPTVSERVICE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvservice"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvsync+xml`
+    pub const VND_ETSI_IPTVSYNC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvsync"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.iptvueprofile+xml`
+    pub const VND_ETSI_IPTVUEPROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.iptvueprofile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.mcid+xml`
+    pub const VND_ETSI_MCID_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.mcid"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.mheg5`
+    pub const VND_ETSI_MHEG5: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.mheg5"), None, &[]);
+    /// `application/vnd.etsi.overload-control-policy-dataset+xml`
+    pub const VND_ETSI_OVERLOAD_CONTROL_POLICY_DATASET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.overload-control-policy-dataset"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.pstn+xml`
+    pub const VND_ETSI_PSTN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.pstn"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.sci+xml`
+    pub const VND_ETSI_SCI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.sci"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.simservs+xml`
+    pub const VND_ETSI_SIMSERVS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.simservs"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.timestamp-token`
+    pub const VND_ETSI_TIMESTAMP_TOKEN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.timestamp-token"), None, &[]);
+    /// `application/vnd.etsi.tsl+xml`
+    pub const VND_ETSI_TSL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.tsl"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.etsi.tsl.der`
+    pub const VND_ETSI_TSL_DER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.etsi.tsl.der"), None, &[]);
+    /// `application/vnd.eu.kasparian.car+json`
+    pub const VND_EU_KASPARIAN_CAR_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eu.kasparian.car"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.eudora.data`
+    pub const VND_EUDORA_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.eudora.data"), None, &[]);
+    /// `application/vnd.evolv.ecig.profile`
+    pub const VND_EVOLV_ECIG_PROFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.evolv.ecig.profile"), None, &[]);
+    /// `application/vnd.evolv.ecig.settings`
+    pub const VND_EVOLV_ECIG_SETTINGS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.evolv.ecig.settings"), None, &[]);
+    /// `application/vnd.evolv.ecig.theme`
+    pub const VND_EVOLV_ECIG_THEME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.evolv.ecig.theme"), None, &[]);
+    /// `application/vnd.exstream-empower+zip`
+    pub const VND_EXSTREAM_EMPOWER_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.exstream-empower"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.exstream-package`
+    pub const VND_EXSTREAM_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.exstream-package"), None, &[]);
+    /// `application/vnd.ezpix-album`
+    pub const VND_EZPIX_ALBUM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ezpix-album"), None, &[]);
+    /// `application/vnd.ezpix-package`
+    pub const VND_EZPIX_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ezpix-package"), None, &[]);
+    /// `application/vnd.f-secure.mobile`
+    pub const VND_F_SECURE_MOBILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.f-secure.mobile"), None, &[]);
+    /// `application/vnd.familysearch.gedcom+zip`
+    pub const VND_FAMILYSEARCH_GEDCOM_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.familysearch.gedcom"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.fastcopy-disk-image`
+    pub const VND_FASTCOPY_DISK_IMAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fastcopy-disk-image"), None, &[]);
+    /// `application/vnd.fdsn.mseed`
+    pub const VND_FDSN_MSEED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fdsn.mseed"), None, &[]);
+    /// `application/vnd.fdsn.seed`
+    pub const VND_FDSN_SEED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fdsn.seed"), None, &[]);
+    /// `application/vnd.ffsns`
+    pub const VND_FFSNS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ffsns"), None, &[]);
+    /// `application/vnd.ficlab.flb+zip`
+    pub const VND_FICLAB_FLB_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ficlab.flb"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.filmit.zfc`
+    pub const VND_FILMIT_ZFC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.filmit.zfc"), None, &[]);
+    /// `application/vnd.fints`
+    pub const VND_FINTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fints"), None, &[]);
+    /// `application/vnd.firemonkeys.cloudcell`
+    pub const VND_FIREMONKEYS_CLOUDCELL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.firemonkeys.cloudcell"), None, &[]);
+    /// `application/vnd.FloGraphIt`
+    pub const VND_FLO_GRAPH_IT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.FloGraphIt"), None, &[]);
+    /// `application/vnd.fluxtime.clip`
+    pub const VND_FLUXTIME_CLIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fluxtime.clip"), None, &[]);
+    /// `application/vnd.font-fontforge-sfd`
+    pub const VND_FONT_FONTFORGE_SFD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.font-fontforge-sfd"), None, &[]);
+    /// `application/vnd.framemaker`
+    pub const VND_FRAMEMAKER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.framemaker"), None, &[]);
+    /// `application/vnd.fsc.weblaunch`
+    pub const VND_FSC_WEBLAUNCH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fsc.weblaunch"), None, &[]);
+    /// `application/vnd.fujifilm.fb.docuworks`
+    pub const VND_FUJIFILM_FB_DOCUWORKS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujifilm.fb.docuworks"), None, &[]);
+    /// `application/vnd.fujifilm.fb.docuworks.binder`
+    pub const VND_FUJIFILM_FB_DOCUWORKS_BINDER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujifilm.fb.docuworks.binder"), None, &[]);
+    /// `application/vnd.fujifilm.fb.docuworks.container`
+    pub const VND_FUJIFILM_FB_DOCUWORKS_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujifilm.fb.docuworks.container"), None, &[]);
+    /// `application/vnd.fujifilm.fb.jfi+xml`
+    pub const VND_FUJIFILM_FB_JFI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujifilm.fb.jfi"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.fujitsu.oasys`
+    pub const VND_FUJITSU_OASYS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujitsu.oasys"), None, &[]);
+    /// `application/vnd.fujitsu.oasys2`
+    pub const VND_FUJITSU_OASYS2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujitsu.oasys2"), None, &[]);
+    /// `application/vnd.fujitsu.oasys3`
+    pub const VND_FUJITSU_OASYS3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujitsu.oasys3"), None, &[]);
+    /// `application/vnd.fujitsu.oasysgp`
+    pub const VND_FUJITSU_OASYSGP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujitsu.oasysgp"), None, &[]);
+    /// `application/vnd.fujitsu.oasysprs`
+    pub const VND_FUJITSU_OASYSPRS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujitsu.oasysprs"), None, &[]);
+    /// `application/vnd.fujixerox.ART-EX`
+    pub const VND_FUJIXEROX_ART_EX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.ART-EX"), None, &[]);
+    /// `application/vnd.fujixerox.ART4`
+    pub const VND_FUJIXEROX_ART4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.ART4"), None, &[]);
+    /// `application/vnd.fujixerox.ddd`
+    pub const VND_FUJIXEROX_DDD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.ddd"), None, &[]);
+    /// `application/vnd.fujixerox.docuworks`
+    pub const VND_FUJIXEROX_DOCUWORKS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.docuworks"), None, &[]);
+    /// `application/vnd.fujixerox.docuworks.binder`
+    pub const VND_FUJIXEROX_DOCUWORKS_BINDER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.docuworks.binder"), None, &[]);
+    /// `application/vnd.fujixerox.docuworks.container`
+    pub const VND_FUJIXEROX_DOCUWORKS_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.docuworks.container"), None, &[]);
+    /// `application/vnd.fujixerox.HBPL`
+    pub const VND_FUJIXEROX_HBPL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fujixerox.HBPL"), None, &[]);
+    /// `application/vnd.fut-misnet`
+    pub const VND_FUT_MISNET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fut-misnet"), None, &[]);
+    /// `application/vnd.futoin+cbor`
+    pub const VND_FUTOIN_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.futoin"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/vnd.futoin+json`
+    pub const VND_FUTOIN_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.futoin"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.fuzzysheet`
+    pub const VND_FUZZYSHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.fuzzysheet"), None, &[]);
+    /// `application/vnd.genomatix.tuxedo`
+    pub const VND_GENOMATIX_TUXEDO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.genomatix.tuxedo"), None, &[]);
+    /// `application/vnd.genozip`
+    pub const VND_GENOZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.genozip"), None, &[]);
+    /// `application/vnd.gentics.grd+json`
+    pub const VND_GENTICS_GRD_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentics.grd"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.gentoo.catmetadata+xml`
+    pub const VND_GENTOO_CATMETADATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.catmetadata"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.gentoo.ebuild`
+    pub const VND_GENTOO_EBUILD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.ebuild"), None, &[]);
+    /// `application/vnd.gentoo.eclass`
+    pub const VND_GENTOO_ECLASS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.eclass"), None, &[]);
+    /// `application/vnd.gentoo.gpkg`
+    pub const VND_GENTOO_GPKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.gpkg"), None, &[]);
+    /// `application/vnd.gentoo.manifest`
+    pub const VND_GENTOO_MANIFEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.manifest"), None, &[]);
+    /// `application/vnd.gentoo.pkgmetadata+xml`
+    pub const VND_GENTOO_PKGMETADATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.pkgmetadata"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.gentoo.xpak`
+    pub const VND_GENTOO_XPAK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gentoo.xpak"), None, &[]);
+    /// `application/vnd.geogebra.file`
+    pub const VND_GEOGEBRA_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geogebra.file"), None, &[]);
+    /// `application/vnd.geogebra.slides`
+    pub const VND_GEOGEBRA_SLIDES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geogebra.slides"), None, &[]);
+    /// `application/vnd.geogebra.tool`
+    pub const VND_GEOGEBRA_TOOL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geogebra.tool"), None, &[]);
+    /// `application/vnd.geometry-explorer`
+    pub const VND_GEOMETRY_EXPLORER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geometry-explorer"), None, &[]);
+    /// `application/vnd.geonext`
+    pub const VND_GEONEXT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geonext"), None, &[]);
+    /// `application/vnd.geoplan`
+    pub const VND_GEOPLAN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geoplan"), None, &[]);
+    /// `application/vnd.geospace`
+    pub const VND_GEOSPACE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.geospace"), None, &[]);
+    /// `application/vnd.gerber`
+    pub const VND_GERBER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gerber"), None, &[]);
+    /// `application/vnd.globalplatform.card-content-mgt`
+    pub const VND_GLOBALPLATFORM_CARD_CONTENT_MGT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.globalplatform.card-content-mgt"), None, &[]);
+    /// `application/vnd.globalplatform.card-content-mgt-response`
+    pub const VND_GLOBALPLATFORM_CARD_CONTENT_MGT_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.globalplatform.card-content-mgt-response"), None, &[]);
+    /// `application/vnd.gnu.taler.exchange+json`
+    pub const VND_GNU_TALER_EXCHANGE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gnu.taler.exchange"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.gnu.taler.merchant+json`
+    pub const VND_GNU_TALER_MERCHANT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gnu.taler.merchant"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.google-earth.kml+xml`
+    pub const VND_GOOGLE_EARTH_KML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.google-earth.kml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.google-earth.kmz`
+    pub const VND_GOOGLE_EARTH_KMZ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.google-earth.kmz"), None, &[]);
+    /// `application/vnd.gov.sk.e-form+xml`
+    pub const VND_GOV_SK_E_FORM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gov.sk.e-form"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.gov.sk.e-form+zip`
+    pub const VND_GOV_SK_E_FORM_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gov.sk.e-form"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.gov.sk.xmldatacontainer+xml`
+    pub const VND_GOV_SK_XMLDATACONTAINER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gov.sk.xmldatacontainer"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.gpxsee.map+xml`
+    pub const VND_GPXSEE_MAP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gpxsee.map"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.grafeq`
+    pub const VND_GRAFEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.grafeq"), None, &[]);
+    /// `application/vnd.gridmp`
+    pub const VND_GRIDMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.gridmp"), None, &[]);
+    /// `application/vnd.groove-account`
+    pub const VND_GROOVE_ACCOUNT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-account"), None, &[]);
+    /// `application/vnd.groove-help`
+    pub const VND_GROOVE_HELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-help"), None, &[]);
+    /// `application/vnd.groove-identity-message`
+    pub const VND_GROOVE_IDENTITY_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-identity-message"), None, &[]);
+    /// `application/vnd.groove-injector`
+    pub const VND_GROOVE_INJECTOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-injector"), None, &[]);
+    /// `application/vnd.groove-tool-message`
+    pub const VND_GROOVE_TOOL_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-tool-message"), None, &[]);
+    /// `application/vnd.groove-tool-template`
+    pub const VND_GROOVE_TOOL_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-tool-template"), None, &[]);
+    /// `application/vnd.groove-vcard`
+    pub const VND_GROOVE_VCARD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.groove-vcard"), None, &[]);
+    /// `application/vnd.hal+json`
+    pub const VND_HAL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hal"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hal+xml`
+    pub const VND_HAL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hal"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.HandHeld-Entertainment+xml`
+    pub const VND_HAND_HELD_ENTERTAINMENT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.HandHeld-Entertainment"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.hbci`
+    pub const VND_HBCI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hbci"), None, &[]);
+    /// `application/vnd.hc+json`
+    pub const VND_HC_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hc"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hcl-bireports`
+    pub const VND_HCL_BIREPORTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hcl-bireports"), None, &[]);
+    /// `application/vnd.hdt`
+    pub const VND_HDT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hdt"), None, &[]);
+    /// `application/vnd.heroku+json`
+    pub const VND_HEROKU_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.heroku"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hhe.lesson-player`
+    pub const VND_HHE_LESSON_PLAYER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hhe.lesson-player"), None, &[]);
+    /// `application/vnd.hp-HPGL`
+    pub const VND_HP_HPGL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-HPGL"), None, &[]);
+    /// `application/vnd.hp-hpid`
+    pub const VND_HP_HPID: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-hpid"), None, &[]);
+    /// `application/vnd.hp-hps`
+    pub const VND_HP_HPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-hps"), None, &[]);
+    /// `application/vnd.hp-jlyt`
+    pub const VND_HP_JLYT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-jlyt"), None, &[]);
+    /// `application/vnd.hp-PCL`
+    pub const VND_HP_PCL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-PCL"), None, &[]);
+    /// `application/vnd.hp-PCLXL`
+    pub const VND_HP_PCLXL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hp-PCLXL"), None, &[]);
+    /// `application/vnd.httphone`
+    pub const VND_HTTPHONE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.httphone"), None, &[]);
+    /// `application/vnd.hydrostatix.sof-data`
+    pub const VND_HYDROSTATIX_SOF_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hydrostatix.sof-data"), None, &[]);
+    /// `application/vnd.hyper+json`
+    pub const VND_HYPER_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hyper"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hyper-item+json`
+    pub const VND_HYPER_ITEM_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hyper-item"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hyperdrive+json`
+    pub const VND_HYPERDRIVE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hyperdrive"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.hzn-3d-crossword`
+    pub const VND_HZN_3D_CROSSWORD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.hzn-3d-crossword"), None, &[]);
+    /// `application/vnd.ibm.electronic-media`
+    pub const VND_IBM_ELECTRONIC_MEDIA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ibm.electronic-media"), None, &[]);
+    /// `application/vnd.ibm.MiniPay`
+    pub const VND_IBM_MINI_PAY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ibm.MiniPay"), None, &[]);
+    /// `application/vnd.ibm.rights-management`
+    pub const VND_IBM_RIGHTS_MANAGEMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ibm.rights-management"), None, &[]);
+    /// `application/vnd.ibm.secure-container`
+    pub const VND_IBM_SECURE_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ibm.secure-container"), None, &[]);
+    /// `application/vnd.iccprofile`
+    pub const VND_ICCPROFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iccprofile"), None, &[]);
+    /// `application/vnd.ieee.1905`
+    pub const VND_IEEE_1905: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ieee.1905"), None, &[]);
+    /// `application/vnd.igloader`
+    pub const VND_IGLOADER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.igloader"), None, &[]);
+    /// `application/vnd.imagemeter.folder+zip`
+    pub const VND_IMAGEMETER_FOLDER_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.imagemeter.folder"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.imagemeter.image+zip`
+    pub const VND_IMAGEMETER_IMAGE_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.imagemeter.image"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.immervision-ivp`
+    pub const VND_IMMERVISION_IVP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.immervision-ivp"), None, &[]);
+    /// `application/vnd.immervision-ivu`
+    pub const VND_IMMERVISION_IVU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.immervision-ivu"), None, &[]);
+    /// `application/vnd.ims.imsccv1p1`
+    pub const VND_IMS_IMSCCV1P1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.imsccv1p1"), None, &[]);
+    /// `application/vnd.ims.imsccv1p2`
+    pub const VND_IMS_IMSCCV1P2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.imsccv1p2"), None, &[]);
+    /// `application/vnd.ims.imsccv1p3`
+    pub const VND_IMS_IMSCCV1P3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.imsccv1p3"), None, &[]);
+    /// `application/vnd.ims.lis.v2.result+json`
+    pub const VND_IMS_LIS_V2_RESULT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lis.v2.result"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ims.lti.v2.toolconsumerprofile+json`
+    pub const VND_IMS_LTI_V2_TOOLCONSUMERPROFILE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lti.v2.toolconsumerprofile"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ims.lti.v2.toolproxy+json`
+    pub const VND_IMS_LTI_V2_TOOLPROXY_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lti.v2.toolproxy"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ims.lti.v2.toolproxy.id+json`
+    pub const VND_IMS_LTI_V2_TOOLPROXY_ID_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lti.v2.toolproxy.id"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ims.lti.v2.toolsettings+json`
+    pub const VND_IMS_LTI_V2_TOOLSETTINGS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lti.v2.toolsettings"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ims.lti.v2.toolsettings.simple+json`
+    pub const VND_IMS_LTI_V2_TOOLSETTINGS_SIMPLE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ims.lti.v2.toolsettings.simple"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.informedcontrol.rms+xml`
+    pub const VND_INFORMEDCONTROL_RMS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.informedcontrol.rms"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.infotech.project`
+    pub const VND_INFOTECH_PROJECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.infotech.project"), None, &[]);
+    /// `application/vnd.infotech.project+xml`
+    pub const VND_INFOTECH_PROJECT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.infotech.project"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.innopath.wamp.notification`
+    pub const VND_INNOPATH_WAMP_NOTIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.innopath.wamp.notification"), None, &[]);
+    /// `application/vnd.insors.igm`
+    pub const VND_INSORS_IGM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.insors.igm"), None, &[]);
+    /// `application/vnd.intercon.formnet`
+    pub const VND_INTERCON_FORMNET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intercon.formnet"), None, &[]);
+    /// `application/vnd.intergeo`
+    pub const VND_INTERGEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intergeo"), None, &[]);
+    /// `application/vnd.intertrust.digibox`
+    pub const VND_INTERTRUST_DIGIBOX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intertrust.digibox"), None, &[]);
+    /// `application/vnd.intertrust.nncp`
+    pub const VND_INTERTRUST_NNCP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intertrust.nncp"), None, &[]);
+    /// `application/vnd.intu.qbo`
+    pub const VND_INTU_QBO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intu.qbo"), None, &[]);
+    /// `application/vnd.intu.qfx`
+    pub const VND_INTU_QFX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.intu.qfx"), None, &[]);
+    /// `application/vnd.ipld.car`
+    pub const VND_IPLD_CAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ipld.car"), None, &[]);
+    /// `application/vnd.ipld.dag-cbor`
+    pub const VND_IPLD_DAG_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ipld.dag-cbor"), None, &[]);
+    /// `application/vnd.ipld.dag-json`
+    pub const VND_IPLD_DAG_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ipld.dag-json"), None, &[]);
+    /// `application/vnd.ipld.raw`
+    pub const VND_IPLD_RAW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ipld.raw"), None, &[]);
+    /// `application/vnd.iptc.g2.catalogitem+xml`
+    pub const VND_IPTC_G2_CATALOGITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.catalogitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.conceptitem+xml`
+    pub const VND_IPTC_G2_CONCEPTITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.conceptitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.knowledgeitem+xml`
+    pub const VND_IPTC_G2_KNOWLEDGEITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.knowledgeitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.newsitem+xml`
+    pub const VND_IPTC_G2_NEWSITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.newsitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.newsmessage+xml`
+    pub const VND_IPTC_G2_NEWSMESSAGE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.newsmessage"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.packageitem+xml`
+    pub const VND_IPTC_G2_PACKAGEITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.packageitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.iptc.g2.planningitem+xml`
+    pub const VND_IPTC_G2_PLANNINGITEM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iptc.g2.planningitem"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ipunplugged.rcprofile`
+    pub const VND_IPUNPLUGGED_RCPROFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ipunplugged.rcprofile"), None, &[]);
+    /// `application/vnd.irepository.package+xml`
+    pub const VND_IREPOSITORY_PACKAGE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.irepository.package"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.is-xpr`
+    pub const VND_IS_XPR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.is-xpr"), None, &[]);
+    /// `application/vnd.isac.fcs`
+    pub const VND_ISAC_FCS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.isac.fcs"), None, &[]);
+    /// `application/vnd.iso11783-10+zip`
+    pub const VND_ISO11783_10_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.iso11783-10"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.jam`
+    pub const VND_JAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.jam"), None, &[]);
+    /// `application/vnd.japannet-directory-service`
+    pub const VND_JAPANNET_DIRECTORY_SERVICE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-directory-service"), None, &[]);
+    /// `application/vnd.japannet-jpnstore-wakeup`
+    pub const VND_JAPANNET_JPNSTORE_WAKEUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-jpnstore-wakeup"), None, &[]);
+    /// `application/vnd.japannet-payment-wakeup`
+    pub const VND_JAPANNET_PAYMENT_WAKEUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-payment-wakeup"), None, &[]);
+    /// `application/vnd.japannet-registration`
+    pub const VND_JAPANNET_REGISTRATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-registration"), None, &[]);
+    /// `application/vnd.japannet-registration-wakeup`
+    pub const VND_JAPANNET_REGISTRATION_WAKEUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-registration-wakeup"), None, &[]);
+    /// `application/vnd.japannet-setstore-wakeup`
+    pub const VND_JAPANNET_SETSTORE_WAKEUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-setstore-wakeup"), None, &[]);
+    /// `application/vnd.japannet-verification`
+    pub const VND_JAPANNET_VERIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-verification"), None, &[]);
+    /// `application/vnd.japannet-verification-wakeup`
+    pub const VND_JAPANNET_VERIFICATION_WAKEUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.japannet-verification-wakeup"), None, &[]);
+    /// `application/vnd.jcp.javame.midlet-rms`
+    pub const VND_JCP_JAVAME_MIDLET_RMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.jcp.javame.midlet-rms"), None, &[]);
+    /// `application/vnd.jisp`
+    pub const VND_JISP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.jisp"), None, &[]);
+    /// `application/vnd.joost.joda-archive`
+    pub const VND_JOOST_JODA_ARCHIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.joost.joda-archive"), None, &[]);
+    /// `application/vnd.jsk.isdn-ngn`
+    pub const VND_JSK_ISDN_NGN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.jsk.isdn-ngn"), None, &[]);
+    /// `application/vnd.kahootz`
+    pub const VND_KAHOOTZ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kahootz"), None, &[]);
+    /// `application/vnd.kde.karbon`
+    pub const VND_KDE_KARBON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.karbon"), None, &[]);
+    /// `application/vnd.kde.kchart`
+    pub const VND_KDE_KCHART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kchart"), None, &[]);
+    /// `application/vnd.kde.kformula`
+    pub const VND_KDE_KFORMULA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kformula"), None, &[]);
+    /// `application/vnd.kde.kivio`
+    pub const VND_KDE_KIVIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kivio"), None, &[]);
+    /// `application/vnd.kde.kontour`
+    pub const VND_KDE_KONTOUR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kontour"), None, &[]);
+    /// `application/vnd.kde.kpresenter`
+    pub const VND_KDE_KPRESENTER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kpresenter"), None, &[]);
+    /// `application/vnd.kde.kspread`
+    pub const VND_KDE_KSPREAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kspread"), None, &[]);
+    /// `application/vnd.kde.kword`
+    pub const VND_KDE_KWORD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kde.kword"), None, &[]);
+    /// `application/vnd.kenameaapp`
+    pub const VND_KENAMEAAPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kenameaapp"), None, &[]);
+    /// `application/vnd.kidspiration`
+    pub const VND_KIDSPIRATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kidspiration"), None, &[]);
+    /// `application/vnd.Kinar`
+    pub const VND_KINAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Kinar"), None, &[]);
+    /// `application/vnd.koan`
+    pub const VND_KOAN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.koan"), None, &[]);
+    /// `application/vnd.kodak-descriptor`
+    pub const VND_KODAK_DESCRIPTOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.kodak-descriptor"), None, &[]);
+    /// `application/vnd.las`
+    pub const VND_LAS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.las"), None, &[]);
+    /// `application/vnd.las.las+json`
+    pub const VND_LAS_LAS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.las.las"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.las.las+xml`
+    pub const VND_LAS_LAS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.las.las"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.laszip`
+    pub const VND_LASZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.laszip"), None, &[]);
+    /// `application/vnd.leap+json`
+    pub const VND_LEAP_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.leap"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.liberty-request+xml`
+    pub const VND_LIBERTY_REQUEST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.liberty-request"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.llamagraphics.life-balance.desktop`
+    pub const VND_LLAMAGRAPHICS_LIFE_BALANCE_DESKTOP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.llamagraphics.life-balance.desktop"), None, &[]);
+    /// `application/vnd.llamagraphics.life-balance.exchange+xml`
+    pub const VND_LLAMAGRAPHICS_LIFE_BALANCE_EXCHANGE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.llamagraphics.life-balance.exchange"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.logipipe.circuit+zip`
+    pub const VND_LOGIPIPE_CIRCUIT_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.logipipe.circuit"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.loom`
+    pub const VND_LOOM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.loom"), None, &[]);
+    /// `application/vnd.lotus-1-2-3`
+    pub const VND_LOTUS_1_2_3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-1-2-3"), None, &[]);
+    /// `application/vnd.lotus-approach`
+    pub const VND_LOTUS_APPROACH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-approach"), None, &[]);
+    /// `application/vnd.lotus-freelance`
+    pub const VND_LOTUS_FREELANCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-freelance"), None, &[]);
+    /// `application/vnd.lotus-notes`
+    pub const VND_LOTUS_NOTES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-notes"), None, &[]);
+    /// `application/vnd.lotus-organizer`
+    pub const VND_LOTUS_ORGANIZER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-organizer"), None, &[]);
+    /// `application/vnd.lotus-screencam`
+    pub const VND_LOTUS_SCREENCAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-screencam"), None, &[]);
+    /// `application/vnd.lotus-wordpro`
+    pub const VND_LOTUS_WORDPRO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.lotus-wordpro"), None, &[]);
+    /// `application/vnd.macports.portpkg`
+    pub const VND_MACPORTS_PORTPKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.macports.portpkg"), None, &[]);
+    /// `application/vnd.mapbox-vector-tile`
+    pub const VND_MAPBOX_VECTOR_TILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mapbox-vector-tile"), None, &[]);
+    /// `application/vnd.marlin.drm.actiontoken+xml`
+    pub const VND_MARLIN_DRM_ACTIONTOKEN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.marlin.drm.actiontoken"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.marlin.drm.conftoken+xml`
+    pub const VND_MARLIN_DRM_CONFTOKEN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.marlin.drm.conftoken"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.marlin.drm.license+xml`
+    pub const VND_MARLIN_DRM_LICENSE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.marlin.drm.license"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.marlin.drm.mdcf`
+    pub const VND_MARLIN_DRM_MDCF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.marlin.drm.mdcf"), None, &[]);
+    /// `application/vnd.mason+json`
+    pub const VND_MASON_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mason"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.maxar.archive.3tz+zip`
+    pub const VND_MAXAR_ARCHIVE_3TZ_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.maxar.archive.3tz"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `application/vnd.maxmind.maxmind-db`
+    pub const VND_MAXMIND_MAXMIND_DB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.maxmind.maxmind-db"), None, &[]);
+    /// `application/vnd.mcd`
+    pub const VND_MCD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mcd"), None, &[]);
+    /// `application/vnd.medcalcdata`
+    pub const VND_MEDCALCDATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.medcalcdata"), None, &[]);
+    /// `application/vnd.mediastation.cdkey`
+    pub const VND_MEDIASTATION_CDKEY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mediastation.cdkey"), None, &[]);
+    /// `application/vnd.medicalholodeck.recordxr`
+    pub const VND_MEDICALHOLODECK_RECORDXR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.medicalholodeck.recordxr"), None, &[]);
+    /// `application/vnd.meridian-slingshot`
+    pub const VND_MERIDIAN_SLINGSHOT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.meridian-slingshot"), None, &[]);
+    /// `application/vnd.MFER`
+    pub const VND_MFER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.MFER"), None, &[]);
+    /// `application/vnd.mfmp`
+    pub const VND_MFMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mfmp"), None, &[]);
+    /// `application/vnd.micro+json`
+    pub const VND_MICRO_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.micro"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.micrografx.flo`
+    pub const VND_MICROGRAFX_FLO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.micrografx.flo"), None, &[]);
+    /// `application/vnd.micrografx.igx`
+    pub const VND_MICROGRAFX_IGX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.micrografx.igx"), None, &[]);
+    /// `application/vnd.microsoft.portable-executable`
+    pub const VND_MICROSOFT_PORTABLE_EXECUTABLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.microsoft.portable-executable"), None, &[]);
+    /// `application/vnd.microsoft.windows.thumbnail-cache`
+    pub const VND_MICROSOFT_WINDOWS_THUMBNAIL_CACHE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.microsoft.windows.thumbnail-cache"), None, &[]);
+    /// `application/vnd.miele+json`
+    pub const VND_MIELE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.miele"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.mif`
+    pub const VND_MIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mif"), None, &[]);
+    /// `application/vnd.minisoft-hp3000-save`
+    pub const VND_MINISOFT_HP3000_SAVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.minisoft-hp3000-save"), None, &[]);
+    /// `application/vnd.mitsubishi.misty-guard.trustweb`
+    pub const VND_MITSUBISHI_MISTY_GUARD_TRUSTWEB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mitsubishi.misty-guard.trustweb"), None, &[]);
+    /// `application/vnd.Mobius.DAF`
+    pub const VND_MOBIUS_DAF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.DAF"), None, &[]);
+    /// `application/vnd.Mobius.DIS`
+    pub const VND_MOBIUS_DIS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.DIS"), None, &[]);
+    /// `application/vnd.Mobius.MBK`
+    pub const VND_MOBIUS_MBK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.MBK"), None, &[]);
+    /// `application/vnd.Mobius.MQY`
+    pub const VND_MOBIUS_MQY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.MQY"), None, &[]);
+    /// `application/vnd.Mobius.MSL`
+    pub const VND_MOBIUS_MSL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.MSL"), None, &[]);
+    /// `application/vnd.Mobius.PLC`
+    pub const VND_MOBIUS_PLC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.PLC"), None, &[]);
+    /// `application/vnd.Mobius.TXF`
+    pub const VND_MOBIUS_TXF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Mobius.TXF"), None, &[]);
+    /// `application/vnd.mophun.application`
+    pub const VND_MOPHUN_APPLICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mophun.application"), None, &[]);
+    /// `application/vnd.mophun.certificate`
+    pub const VND_MOPHUN_CERTIFICATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mophun.certificate"), None, &[]);
+    /// `application/vnd.motorola.flexsuite`
+    pub const VND_MOTOROLA_FLEXSUITE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.adsi`
+    pub const VND_MOTOROLA_FLEXSUITE_ADSI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.adsi"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.fis`
+    pub const VND_MOTOROLA_FLEXSUITE_FIS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.fis"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.gotap`
+    pub const VND_MOTOROLA_FLEXSUITE_GOTAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.gotap"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.kmr`
+    pub const VND_MOTOROLA_FLEXSUITE_KMR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.kmr"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.ttc`
+    pub const VND_MOTOROLA_FLEXSUITE_TTC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.ttc"), None, &[]);
+    /// `application/vnd.motorola.flexsuite.wem`
+    pub const VND_MOTOROLA_FLEXSUITE_WEM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.flexsuite.wem"), None, &[]);
+    /// `application/vnd.motorola.iprm`
+    pub const VND_MOTOROLA_IPRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.motorola.iprm"), None, &[]);
+    /// `application/vnd.mozilla.xul+xml`
+    pub const VND_MOZILLA_XUL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mozilla.xul"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ms-3mfdocument`
+    pub const VND_MS_3MFDOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-3mfdocument"), None, &[]);
+    /// `application/vnd.ms-artgalry`
+    pub const VND_MS_ARTGALRY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-artgalry"), None, &[]);
+    /// `application/vnd.ms-asf`
+    pub const VND_MS_ASF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-asf"), None, &[]);
+    /// `application/vnd.ms-cab-compressed`
+    pub const VND_MS_CAB_COMPRESSED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-cab-compressed"), None, &[]);
+    /// `application/vnd.ms-excel`
+    pub const VND_MS_EXCEL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-excel"), None, &[]);
+    /// `application/vnd.ms-excel.addin.macroEnabled.12`
+    pub const VND_MS_EXCEL_ADDIN_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-excel.addin.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-excel.sheet.binary.macroEnabled.12`
+    pub const VND_MS_EXCEL_SHEET_BINARY_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-excel.sheet.binary.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-excel.sheet.macroEnabled.12`
+    pub const VND_MS_EXCEL_SHEET_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-excel.sheet.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-excel.template.macroEnabled.12`
+    pub const VND_MS_EXCEL_TEMPLATE_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-excel.template.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-fontobject`
+    pub const VND_MS_FONTOBJECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-fontobject"), None, &[]);
+    /// `application/vnd.ms-htmlhelp`
+    pub const VND_MS_HTMLHELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-htmlhelp"), None, &[]);
+    /// `application/vnd.ms-ims`
+    pub const VND_MS_IMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-ims"), None, &[]);
+    /// `application/vnd.ms-lrm`
+    pub const VND_MS_LRM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-lrm"), None, &[]);
+    /// `application/vnd.ms-office.activeX+xml`
+    pub const VND_MS_OFFICE_ACTIVE_X_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-office.activeX"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ms-officetheme`
+    pub const VND_MS_OFFICETHEME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-officetheme"), None, &[]);
+    /// `application/vnd.ms-pki.seccat`
+    pub const VND_MS_PKI_SECCAT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-pki.seccat"), None, &[]);
+    /// `application/vnd.ms-playready.initiator+xml`
+    pub const VND_MS_PLAYREADY_INITIATOR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-playready.initiator"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ms-powerpoint`
+    pub const VND_MS_POWERPOINT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint"), None, &[]);
+    /// `application/vnd.ms-powerpoint.addin.macroEnabled.12`
+    pub const VND_MS_POWERPOINT_ADDIN_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint.addin.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-powerpoint.presentation.macroEnabled.12`
+    pub const VND_MS_POWERPOINT_PRESENTATION_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint.presentation.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-powerpoint.slide.macroEnabled.12`
+    pub const VND_MS_POWERPOINT_SLIDE_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint.slide.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-powerpoint.slideshow.macroEnabled.12`
+    pub const VND_MS_POWERPOINT_SLIDESHOW_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint.slideshow.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-powerpoint.template.macroEnabled.12`
+    pub const VND_MS_POWERPOINT_TEMPLATE_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-powerpoint.template.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-PrintDeviceCapabilities+xml`
+    pub const VND_MS_PRINT_DEVICE_CAPABILITIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-PrintDeviceCapabilities"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ms-PrintSchemaTicket+xml`
+    pub const VND_MS_PRINT_SCHEMA_TICKET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-PrintSchemaTicket"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.ms-project`
+    pub const VND_MS_PROJECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-project"), None, &[]);
+    /// `application/vnd.ms-tnef`
+    pub const VND_MS_TNEF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-tnef"), None, &[]);
+    /// `application/vnd.ms-windows.devicepairing`
+    pub const VND_MS_WINDOWS_DEVICEPAIRING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-windows.devicepairing"), None, &[]);
+    /// `application/vnd.ms-windows.nwprinting.oob`
+    pub const VND_MS_WINDOWS_NWPRINTING_OOB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-windows.nwprinting.oob"), None, &[]);
+    /// `application/vnd.ms-windows.printerpairing`
+    pub const VND_MS_WINDOWS_PRINTERPAIRING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-windows.printerpairing"), None, &[]);
+    /// `application/vnd.ms-windows.wsd.oob`
+    pub const VND_MS_WINDOWS_WSD_OOB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-windows.wsd.oob"), None, &[]);
+    /// `application/vnd.ms-wmdrm.lic-chlg-req`
+    pub const VND_MS_WMDRM_LIC_CHLG_REQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-wmdrm.lic-chlg-req"), None, &[]);
+    /// `application/vnd.ms-wmdrm.lic-resp`
+    pub const VND_MS_WMDRM_LIC_RESP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-wmdrm.lic-resp"), None, &[]);
+    /// `application/vnd.ms-wmdrm.meter-chlg-req`
+    pub const VND_MS_WMDRM_METER_CHLG_REQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-wmdrm.meter-chlg-req"), None, &[]);
+    /// `application/vnd.ms-wmdrm.meter-resp`
+    pub const VND_MS_WMDRM_METER_RESP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-wmdrm.meter-resp"), None, &[]);
+    /// `application/vnd.ms-word.document.macroEnabled.12`
+    pub const VND_MS_WORD_DOCUMENT_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-word.document.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-word.template.macroEnabled.12`
+    pub const VND_MS_WORD_TEMPLATE_MACRO_ENABLED_12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-word.template.macroEnabled.12"), None, &[]);
+    /// `application/vnd.ms-works`
+    pub const VND_MS_WORKS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-works"), None, &[]);
+    /// `application/vnd.ms-wpl`
+    pub const VND_MS_WPL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-wpl"), None, &[]);
+    /// `application/vnd.ms-xpsdocument`
+    pub const VND_MS_XPSDOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ms-xpsdocument"), None, &[]);
+    /// `application/vnd.msa-disk-image`
+    pub const VND_MSA_DISK_IMAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.msa-disk-image"), None, &[]);
+    /// `application/vnd.mseq`
+    pub const VND_MSEQ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mseq"), None, &[]);
+    /// `application/vnd.msign`
+    pub const VND_MSIGN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.msign"), None, &[]);
+    /// `application/vnd.multiad.creator`
+    pub const VND_MULTIAD_CREATOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.multiad.creator"), None, &[]);
+    /// `application/vnd.multiad.creator.cif`
+    pub const VND_MULTIAD_CREATOR_CIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.multiad.creator.cif"), None, &[]);
+    /// `application/vnd.music-niff`
+    pub const VND_MUSIC_NIFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.music-niff"), None, &[]);
+    /// `application/vnd.musician`
+    pub const VND_MUSICIAN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.musician"), None, &[]);
+    /// `application/vnd.muvee.style`
+    pub const VND_MUVEE_STYLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.muvee.style"), None, &[]);
+    /// `application/vnd.mynfc`
+    pub const VND_MYNFC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.mynfc"), None, &[]);
+    /// `application/vnd.nacamar.ybrid+json`
+    pub const VND_NACAMAR_YBRID_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nacamar.ybrid"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.ncd.control`
+    pub const VND_NCD_CONTROL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ncd.control"), None, &[]);
+    /// `application/vnd.ncd.reference`
+    pub const VND_NCD_REFERENCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ncd.reference"), None, &[]);
+    /// `application/vnd.nearst.inv+json`
+    pub const VND_NEARST_INV_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nearst.inv"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.nebumind.line`
+    pub const VND_NEBUMIND_LINE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nebumind.line"), None, &[]);
+    /// `application/vnd.nervana`
+    pub const VND_NERVANA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nervana"), None, &[]);
+    /// `application/vnd.netfpx`
+    pub const VND_NETFPX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.netfpx"), None, &[]);
+    /// `application/vnd.neurolanguage.nlu`
+    pub const VND_NEUROLANGUAGE_NLU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.neurolanguage.nlu"), None, &[]);
+    /// `application/vnd.nimn`
+    pub const VND_NIMN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nimn"), None, &[]);
+    /// `application/vnd.nintendo.nitro.rom`
+    pub const VND_NINTENDO_NITRO_ROM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nintendo.nitro.rom"), None, &[]);
+    /// `application/vnd.nintendo.snes.rom`
+    pub const VND_NINTENDO_SNES_ROM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nintendo.snes.rom"), None, &[]);
+    /// `application/vnd.nitf`
+    pub const VND_NITF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nitf"), None, &[]);
+    /// `application/vnd.noblenet-directory`
+    pub const VND_NOBLENET_DIRECTORY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.noblenet-directory"), None, &[]);
+    /// `application/vnd.noblenet-sealer`
+    pub const VND_NOBLENET_SEALER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.noblenet-sealer"), None, &[]);
+    /// `application/vnd.noblenet-web`
+    pub const VND_NOBLENET_WEB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.noblenet-web"), None, &[]);
+    /// `application/vnd.nokia.catalogs`
+    pub const VND_NOKIA_CATALOGS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.catalogs"), None, &[]);
+    /// `application/vnd.nokia.conml+wbxml`
+    pub const VND_NOKIA_CONML_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.conml"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.nokia.conml+xml`
+    pub const VND_NOKIA_CONML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.conml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.iptv.config+xml`
+    pub const VND_NOKIA_IPTV_CONFIG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.iptv.config"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.iSDS-radio-presets`
+    pub const VND_NOKIA_I_SDS_RADIO_PRESETS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.iSDS-radio-presets"), None, &[]);
+    /// `application/vnd.nokia.landmark+wbxml`
+    pub const VND_NOKIA_LANDMARK_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.landmark"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.nokia.landmark+xml`
+    pub const VND_NOKIA_LANDMARK_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.landmark"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.landmarkcollection+xml`
+    pub const VND_NOKIA_LANDMARKCOLLECTION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.landmarkcollection"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.n-gage.ac+xml`
+    pub const VND_NOKIA_N_GAGE_AC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.n-gage.ac"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.n-gage.data`
+    pub const VND_NOKIA_N_GAGE_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.n-gage.data"), None, &[]);
+    /// `application/vnd.nokia.ncd`
+    pub const VND_NOKIA_NCD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.ncd"), None, &[]);
+    /// `application/vnd.nokia.pcd+wbxml`
+    pub const VND_NOKIA_PCD_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.pcd"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.nokia.pcd+xml`
+    pub const VND_NOKIA_PCD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.pcd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.nokia.radio-preset`
+    pub const VND_NOKIA_RADIO_PRESET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.radio-preset"), None, &[]);
+    /// `application/vnd.nokia.radio-presets`
+    pub const VND_NOKIA_RADIO_PRESETS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.nokia.radio-presets"), None, &[]);
+    /// `application/vnd.novadigm.EDM`
+    pub const VND_NOVADIGM_EDM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.novadigm.EDM"), None, &[]);
+    /// `application/vnd.novadigm.EDX`
+    pub const VND_NOVADIGM_EDX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.novadigm.EDX"), None, &[]);
+    /// `application/vnd.novadigm.EXT`
+    pub const VND_NOVADIGM_EXT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.novadigm.EXT"), None, &[]);
+    /// `application/vnd.ntt-local.content-share`
+    pub const VND_NTT_LOCAL_CONTENT_SHARE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ntt-local.content-share"), None, &[]);
+    /// `application/vnd.ntt-local.file-transfer`
+    pub const VND_NTT_LOCAL_FILE_TRANSFER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ntt-local.file-transfer"), None, &[]);
+    /// `application/vnd.ntt-local.ogw_remote-access`
+    pub const VND_NTT_LOCAL_OGW_REMOTE_ACCESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ntt-local.ogw_remote-access"), None, &[]);
+    /// `application/vnd.ntt-local.sip-ta_remote`
+    pub const VND_NTT_LOCAL_SIP_TA_REMOTE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ntt-local.sip-ta_remote"), None, &[]);
+    /// `application/vnd.ntt-local.sip-ta_tcp_stream`
+    pub const VND_NTT_LOCAL_SIP_TA_TCP_STREAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ntt-local.sip-ta_tcp_stream"), None, &[]);
+    /// `application/vnd.oasis.opendocument.base`
+    pub const VND_OASIS_OPENDOCUMENT_BASE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.base"), None, &[]);
+    /// `application/vnd.oasis.opendocument.chart`
+    pub const VND_OASIS_OPENDOCUMENT_CHART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.chart"), None, &[]);
+    /// `application/vnd.oasis.opendocument.chart-template`
+    pub const VND_OASIS_OPENDOCUMENT_CHART_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.chart-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.formula`
+    pub const VND_OASIS_OPENDOCUMENT_FORMULA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.formula"), None, &[]);
+    /// `application/vnd.oasis.opendocument.formula-template`
+    pub const VND_OASIS_OPENDOCUMENT_FORMULA_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.formula-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.graphics`
+    pub const VND_OASIS_OPENDOCUMENT_GRAPHICS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.graphics"), None, &[]);
+    /// `application/vnd.oasis.opendocument.graphics-template`
+    pub const VND_OASIS_OPENDOCUMENT_GRAPHICS_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.graphics-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.image`
+    pub const VND_OASIS_OPENDOCUMENT_IMAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.image"), None, &[]);
+    /// `application/vnd.oasis.opendocument.image-template`
+    pub const VND_OASIS_OPENDOCUMENT_IMAGE_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.image-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.presentation`
+    pub const VND_OASIS_OPENDOCUMENT_PRESENTATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.presentation"), None, &[]);
+    /// `application/vnd.oasis.opendocument.presentation-template`
+    pub const VND_OASIS_OPENDOCUMENT_PRESENTATION_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.presentation-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.spreadsheet`
+    pub const VND_OASIS_OPENDOCUMENT_SPREADSHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.spreadsheet"), None, &[]);
+    /// `application/vnd.oasis.opendocument.spreadsheet-template`
+    pub const VND_OASIS_OPENDOCUMENT_SPREADSHEET_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.spreadsheet-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.text`
+    pub const VND_OASIS_OPENDOCUMENT_TEXT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.text"), None, &[]);
+    /// `application/vnd.oasis.opendocument.text-master`
+    pub const VND_OASIS_OPENDOCUMENT_TEXT_MASTER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.text-master"), None, &[]);
+    /// `application/vnd.oasis.opendocument.text-template`
+    pub const VND_OASIS_OPENDOCUMENT_TEXT_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.text-template"), None, &[]);
+    /// `application/vnd.oasis.opendocument.text-web`
+    pub const VND_OASIS_OPENDOCUMENT_TEXT_WEB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oasis.opendocument.text-web"), None, &[]);
+    /// `application/vnd.obn`
+    pub const VND_OBN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.obn"), None, &[]);
+    /// `application/vnd.ocf+cbor`
+    pub const VND_OCF_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ocf"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/vnd.oci.image.manifest.v1+json`
+    pub const VND_OCI_IMAGE_MANIFEST_V1_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oci.image.manifest.v1"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.oftn.l10n+json`
+    pub const VND_OFTN_L10N_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oftn.l10n"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.oipf.contentaccessdownload+xml`
+    pub const VND_OIPF_CONTENTACCESSDOWNLOAD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.contentaccessdownload"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.contentaccessstreaming+xml`
+    pub const VND_OIPF_CONTENTACCESSSTREAMING_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.contentaccessstreaming"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.cspg-hexbinary`
+    pub const VND_OIPF_CSPG_HEXBINARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.cspg-hexbinary"), None, &[]);
+    /// `application/vnd.oipf.dae.svg+xml`
+    pub const VND_OIPF_DAE_SVG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.dae.svg"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.dae.xhtml+xml`
+    pub const VND_OIPF_DAE_XHTML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.dae.xhtml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.mippvcontrolmessage+xml`
+    pub const VND_OIPF_MIPPVCONTROLMESSAGE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.mippvcontrolmessage"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.pae.gem`
+    pub const VND_OIPF_PAE_GEM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.pae.gem"), None, &[]);
+    /// `application/vnd.oipf.spdiscovery+xml`
+    pub const VND_OIPF_SPDISCOVERY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.spdiscovery"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.spdlist+xml`
+    pub const VND_OIPF_SPDLIST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.spdlist"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.ueprofile+xml`
+    pub const VND_OIPF_UEPROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.ueprofile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oipf.userprofile+xml`
+    pub const VND_OIPF_USERPROFILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oipf.userprofile"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.olpc-sugar`
+    pub const VND_OLPC_SUGAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.olpc-sugar"), None, &[]);
+    /// `application/vnd.oma-scws-config`
+    pub const VND_OMA_SCWS_CONFIG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma-scws-config"), None, &[]);
+    /// `application/vnd.oma-scws-http-request`
+    pub const VND_OMA_SCWS_HTTP_REQUEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma-scws-http-request"), None, &[]);
+    /// `application/vnd.oma-scws-http-response`
+    pub const VND_OMA_SCWS_HTTP_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma-scws-http-response"), None, &[]);
+    /// `application/vnd.oma.bcast.associated-procedure-parameter+xml`
+    pub const VND_OMA_BCAST_ASSOCIATED_PROCEDURE_PARAMETER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.associated-procedure-parameter"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.drm-trigger+xml`
+    pub const VND_OMA_BCAST_DRM_TRIGGER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.drm-trigger"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.imd+xml`
+    pub const VND_OMA_BCAST_IMD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.imd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.ltkm`
+    pub const VND_OMA_BCAST_LTKM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.ltkm"), None, &[]);
+    /// `application/vnd.oma.bcast.notification+xml`
+    pub const VND_OMA_BCAST_NOTIFICATION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.notification"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.provisioningtrigger`
+    pub const VND_OMA_BCAST_PROVISIONINGTRIGGER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.provisioningtrigger"), None, &[]);
+    /// `application/vnd.oma.bcast.sgboot`
+    pub const VND_OMA_BCAST_SGBOOT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.sgboot"), None, &[]);
+    /// `application/vnd.oma.bcast.sgdd+xml`
+    pub const VND_OMA_BCAST_SGDD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.sgdd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.sgdu`
+    pub const VND_OMA_BCAST_SGDU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.sgdu"), None, &[]);
+    /// `application/vnd.oma.bcast.simple-symbol-container`
+    pub const VND_OMA_BCAST_SIMPLE_SYMBOL_CONTAINER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.simple-symbol-container"), None, &[]);
+    /// `application/vnd.oma.bcast.smartcard-trigger+xml`
+    pub const VND_OMA_BCAST_SMARTCARD_TRIGGER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.smartcard-trigger"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.sprov+xml`
+    pub const VND_OMA_BCAST_SPROV_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.sprov"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.bcast.stkm`
+    pub const VND_OMA_BCAST_STKM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.bcast.stkm"), None, &[]);
+    /// `application/vnd.oma.cab-address-book+xml`
+    pub const VND_OMA_CAB_ADDRESS_BOOK_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.cab-address-book"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.cab-feature-handler+xml`
+    pub const VND_OMA_CAB_FEATURE_HANDLER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.cab-feature-handler"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.cab-pcc+xml`
+    pub const VND_OMA_CAB_PCC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.cab-pcc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.cab-subs-invite+xml`
+    pub const VND_OMA_CAB_SUBS_INVITE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.cab-subs-invite"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.cab-user-prefs+xml`
+    pub const VND_OMA_CAB_USER_PREFS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.cab-user-prefs"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.dcd`
+    pub const VND_OMA_DCD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.dcd"), None, &[]);
+    /// `application/vnd.oma.dcdc`
+    pub const VND_OMA_DCDC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.dcdc"), None, &[]);
+    /// `application/vnd.oma.dd2+xml`
+    pub const VND_OMA_DD2_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.dd2"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.drm.risd+xml`
+    pub const VND_OMA_DRM_RISD_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.drm.risd"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.group-usage-list+xml`
+    pub const VND_OMA_GROUP_USAGE_LIST_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.group-usage-list"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.lwm2m+cbor`
+    pub const VND_OMA_LWM2M_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.lwm2m"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/vnd.oma.lwm2m+json`
+    pub const VND_OMA_LWM2M_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.lwm2m"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.oma.lwm2m+tlv`
+    pub const VND_OMA_LWM2M_PLUS_TLV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.lwm2m"), Some(crate::Name::new_unchecked("tlv")), &[]);
+    /// `application/vnd.oma.pal+xml`
+    pub const VND_OMA_PAL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.pal"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.poc.detailed-progress-report+xml`
+    pub const VND_OMA_POC_DETAILED_PROGRESS_REPORT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.poc.detailed-progress-report"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.poc.final-report+xml`
+    pub const VND_OMA_POC_FINAL_REPORT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.poc.final-report"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.poc.groups+xml`
+    pub const VND_OMA_POC_GROUPS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.poc.groups"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.poc.invocation-descriptor+xml`
+    pub const VND_OMA_POC_INVOCATION_DESCRIPTOR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.poc.invocation-descriptor"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.poc.optimized-progress-report+xml`
+    pub const VND_OMA_POC_OPTIMIZED_PROGRESS_REPORT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.poc.optimized-progress-report"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.push`
+    pub const VND_OMA_PUSH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.push"), None, &[]);
+    /// `application/vnd.oma.scidm.messages+xml`
+    pub const VND_OMA_SCIDM_MESSAGES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.scidm.messages"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oma.xcap-directory+xml`
+    pub const VND_OMA_XCAP_DIRECTORY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oma.xcap-directory"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.omads-email+xml`
+    pub const VND_OMADS_EMAIL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.omads-email"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.omads-file+xml`
+    pub const VND_OMADS_FILE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.omads-file"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.omads-folder+xml`
+    pub const VND_OMADS_FOLDER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.omads-folder"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.omaloc-supl-init`
+    pub const VND_OMALOC_SUPL_INIT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.omaloc-supl-init"), None, &[]);
+    /// `application/vnd.onepager`
+    pub const VND_ONEPAGER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepager"), None, &[]);
+    /// `application/vnd.onepagertamp`
+    pub const VND_ONEPAGERTAMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepagertamp"), None, &[]);
+    /// `application/vnd.onepagertamx`
+    pub const VND_ONEPAGERTAMX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepagertamx"), None, &[]);
+    /// `application/vnd.onepagertat`
+    pub const VND_ONEPAGERTAT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepagertat"), None, &[]);
+    /// `application/vnd.onepagertatp`
+    pub const VND_ONEPAGERTATP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepagertatp"), None, &[]);
+    /// `application/vnd.onepagertatx`
+    pub const VND_ONEPAGERTATX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onepagertatx"), None, &[]);
+    /// `application/vnd.onvif.metadata`
+    pub const VND_ONVIF_METADATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.onvif.metadata"), None, &[]);
+    /// `application/vnd.openblox.game+xml`
+    pub const VND_OPENBLOX_GAME_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openblox.game"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openblox.game-binary`
+    pub const VND_OPENBLOX_GAME_BINARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openblox.game-binary"), None, &[]);
+    /// `application/vnd.openeye.oeb`
+    pub const VND_OPENEYE_OEB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openeye.oeb"), None, &[]);
+    /// `application/vnd.openofficeorg.extension`
+    pub const VND_OPENOFFICEORG_EXTENSION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openofficeorg.extension"), None, &[]);
+    /// `application/vnd.openstreetmap.data+xml`
+    pub const VND_OPENSTREETMAP_DATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openstreetmap.data"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.opentimestamps.ots`
+    pub const VND_OPENTIMESTAMPS_OTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.opentimestamps.ots"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.custom-properties+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_CUSTOM_PROPERTIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.custom-properties"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.customXmlProperties+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_CUSTOM_XML_PROPERTIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.customXmlProperties"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawing+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWING_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawing"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.chart+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_CHART_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.chart"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_CHARTSHAPES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.chartshapes"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.diagramColors+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_DIAGRAM_COLORS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.diagramColors"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.diagramData+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_DIAGRAM_DATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.diagramData"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.diagramLayout+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_DIAGRAM_LAYOUT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.diagramLayout"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.drawingml.diagramStyle+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_DRAWINGML_DIAGRAM_STYLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.drawingml.diagramStyle"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.extended-properties+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_EXTENDED_PROPERTIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.extended-properties"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.commentAuthors+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_COMMENT_AUTHORS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.commentAuthors"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.comments+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_COMMENTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.comments"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.handoutMaster+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_HANDOUT_MASTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.handoutMaster"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.notesMaster+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_NOTES_MASTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.notesMaster"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_NOTES_SLIDE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.notesSlide"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.presentation`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_PRESENTATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.presentation"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_PRESENTATION_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.presentation.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.presProps+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_PRES_PROPS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.presProps"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slide`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slide"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slide+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slide"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slideLayout+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDE_LAYOUT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slideLayout"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slideMaster+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDE_MASTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slideMaster"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slideshow`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDESHOW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slideshow"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slideshow.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDESHOW_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slideshow.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.slideUpdateInfo+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_SLIDE_UPDATE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.slideUpdateInfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.tableStyles+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_TABLE_STYLES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.tableStyles"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.tags+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_TAGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.tags"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.template`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.template"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.template.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_TEMPLATE_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.template.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.presentationml.viewProps+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_PRESENTATIONML_VIEW_PROPS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.presentationml.viewProps"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.calcChain+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_CALC_CHAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.calcChain"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.chartsheet+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_CHARTSHEET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.chartsheet"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.comments+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_COMMENTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.comments"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.connections+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_CONNECTIONS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.connections"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_DIALOGSHEET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.dialogsheet"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.externalLink+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_EXTERNAL_LINK_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.externalLink"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_PIVOT_CACHE_DEFINITION_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheDefinition"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheRecords+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_PIVOT_CACHE_RECORDS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.pivotCacheRecords"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.pivotTable+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_PIVOT_TABLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.pivotTable"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.queryTable+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_QUERY_TABLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.queryTable"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.revisionHeaders+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_REVISION_HEADERS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.revisionHeaders"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.revisionLog+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_REVISION_LOG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.revisionLog"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_SHARED_STRINGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.sheet`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_SHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.sheet"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_SHEET_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.sheet.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_SHEET_METADATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.sheetMetadata"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_STYLES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.styles"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_TABLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.table"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.tableSingleCells+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_TABLE_SINGLE_CELLS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.tableSingleCells"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.template`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.template"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.template.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_TEMPLATE_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.template.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.userNames+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_USER_NAMES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.userNames"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.volatileDependencies+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_VOLATILE_DEPENDENCIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.volatileDependencies"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_SPREADSHEETML_WORKSHEET_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.spreadsheetml.worksheet"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.theme+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_THEME_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.theme"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.themeOverride+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_THEME_OVERRIDE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.themeOverride"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.vmlDrawing`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_VML_DRAWING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.vmlDrawing"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.comments+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_COMMENTS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.comments"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.document`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_DOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.document"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.document.glossary+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_DOCUMENT_GLOSSARY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.document.glossary"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_DOCUMENT_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.document.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.endnotes+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_ENDNOTES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.endnotes"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.fontTable+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_FONT_TABLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.fontTable"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_FOOTER_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.footer"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.footnotes+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_FOOTNOTES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.footnotes"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.numbering+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_NUMBERING_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.numbering"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.settings+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_SETTINGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.settings"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_STYLES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.styles"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.template`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.template"), None, &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.template.main+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_TEMPLATE_MAIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.template.main"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-officedocument.wordprocessingml.webSettings+xml`
+    pub const VND_OPENXMLFORMATS_OFFICEDOCUMENT_WORDPROCESSINGML_WEB_SETTINGS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-officedocument.wordprocessingml.webSettings"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-package.core-properties+xml`
+    pub const VND_OPENXMLFORMATS_PACKAGE_CORE_PROPERTIES_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-package.core-properties"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml`
+    pub const VND_OPENXMLFORMATS_PACKAGE_DIGITAL_SIGNATURE_XMLSIGNATURE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-package.digital-signature-xmlsignature"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.openxmlformats-package.relationships+xml`
+    pub const VND_OPENXMLFORMATS_PACKAGE_RELATIONSHIPS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.openxmlformats-package.relationships"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oracle.resource+json`
+    pub const VND_ORACLE_RESOURCE_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oracle.resource"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.orange.indata`
+    pub const VND_ORANGE_INDATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.orange.indata"), None, &[]);
+    /// `application/vnd.osa.netdeploy`
+    pub const VND_OSA_NETDEPLOY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.osa.netdeploy"), None, &[]);
+    /// `application/vnd.osgeo.mapguide.package`
+    pub const VND_OSGEO_MAPGUIDE_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.osgeo.mapguide.package"), None, &[]);
+    /// `application/vnd.osgi.bundle`
+    pub const VND_OSGI_BUNDLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.osgi.bundle"), None, &[]);
+    /// `application/vnd.osgi.dp`
+    pub const VND_OSGI_DP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.osgi.dp"), None, &[]);
+    /// `application/vnd.osgi.subsystem`
+    pub const VND_OSGI_SUBSYSTEM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.osgi.subsystem"), None, &[]);
+    /// `application/vnd.otps.ct-kip+xml`
+    pub const VND_OTPS_CT_KIP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.otps.ct-kip"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.oxli.countgraph`
+    pub const VND_OXLI_COUNTGRAPH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.oxli.countgraph"), None, &[]);
+    /// `application/vnd.pagerduty+json`
+    pub const VND_PAGERDUTY_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pagerduty"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.palm`
+    pub const VND_PALM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.palm"), None, &[]);
+    /// `application/vnd.panoply`
+    pub const VND_PANOPLY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.panoply"), None, &[]);
+    /// `application/vnd.paos.xml`
+    pub const VND_PAOS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.paos.xml"), None, &[]);
+    /// `application/vnd.patentdive`
+    pub const VND_PATENTDIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.patentdive"), None, &[]);
+    /// `application/vnd.patientecommsdoc`
+    pub const VND_PATIENTECOMMSDOC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.patientecommsdoc"), None, &[]);
+    /// `application/vnd.pawaafile`
+    pub const VND_PAWAAFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pawaafile"), None, &[]);
+    /// `application/vnd.pcos`
+    pub const VND_PCOS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pcos"), None, &[]);
+    /// `application/vnd.pg.format`
+    pub const VND_PG_FORMAT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pg.format"), None, &[]);
+    /// `application/vnd.pg.osasli`
+    pub const VND_PG_OSASLI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pg.osasli"), None, &[]);
+    /// `application/vnd.piaccess.application-licence`
+    pub const VND_PIACCESS_APPLICATION_LICENCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.piaccess.application-licence"), None, &[]);
+    /// `application/vnd.picsel`
+    pub const VND_PICSEL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.picsel"), None, &[]);
+    /// `application/vnd.pmi.widget`
+    pub const VND_PMI_WIDGET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pmi.widget"), None, &[]);
+    /// `application/vnd.poc.group-advertisement+xml`
+    pub const VND_POC_GROUP_ADVERTISEMENT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.poc.group-advertisement"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.pocketlearn`
+    pub const VND_POCKETLEARN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pocketlearn"), None, &[]);
+    /// `application/vnd.powerbuilder6`
+    pub const VND_POWERBUILDER6: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder6"), None, &[]);
+    /// `application/vnd.powerbuilder6-s`
+    pub const VND_POWERBUILDER6_S: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder6-s"), None, &[]);
+    /// `application/vnd.powerbuilder7`
+    pub const VND_POWERBUILDER7: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder7"), None, &[]);
+    /// `application/vnd.powerbuilder7-s`
+    pub const VND_POWERBUILDER7_S: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder7-s"), None, &[]);
+    /// `application/vnd.powerbuilder75`
+    pub const VND_POWERBUILDER75: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder75"), None, &[]);
+    /// `application/vnd.powerbuilder75-s`
+    pub const VND_POWERBUILDER75_S: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.powerbuilder75-s"), None, &[]);
+    /// `application/vnd.preminet`
+    pub const VND_PREMINET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.preminet"), None, &[]);
+    /// `application/vnd.previewsystems.box`
+    pub const VND_PREVIEWSYSTEMS_BOX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.previewsystems.box"), None, &[]);
+    /// `application/vnd.proteus.magazine`
+    pub const VND_PROTEUS_MAGAZINE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.proteus.magazine"), None, &[]);
+    /// `application/vnd.psfs`
+    pub const VND_PSFS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.psfs"), None, &[]);
+    /// `application/vnd.publishare-delta-tree`
+    pub const VND_PUBLISHARE_DELTA_TREE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.publishare-delta-tree"), None, &[]);
+    /// `application/vnd.pvi.ptid1`
+    pub const VND_PVI_PTID1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pvi.ptid1"), None, &[]);
+    /// `application/vnd.pwg-multiplexed`
+    pub const VND_PWG_MULTIPLEXED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pwg-multiplexed"), None, &[]);
+    /// `application/vnd.pwg-xhtml-print+xml`
+    pub const VND_PWG_XHTML_PRINT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.pwg-xhtml-print"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.qualcomm.brew-app-res`
+    pub const VND_QUALCOMM_BREW_APP_RES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.qualcomm.brew-app-res"), None, &[]);
+    /// `application/vnd.quarantainenet`
+    pub const VND_QUARANTAINENET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.quarantainenet"), None, &[]);
+    /// `application/vnd.Quark.QuarkXPress`
+    pub const VND_QUARK_QUARK_XPRESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.Quark.QuarkXPress"), None, &[]);
+    /// `application/vnd.quobject-quoxdocument`
+    pub const VND_QUOBJECT_QUOXDOCUMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.quobject-quoxdocument"), None, &[]);
+    /// `application/vnd.radisys.moml+xml`
+    pub const VND_RADISYS_MOML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.moml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml+xml`
+    pub const VND_RADISYS_MSML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-audit+xml`
+    pub const VND_RADISYS_MSML_AUDIT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-audit"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-audit-conf+xml`
+    pub const VND_RADISYS_MSML_AUDIT_CONF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-audit-conf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-audit-conn+xml`
+    pub const VND_RADISYS_MSML_AUDIT_CONN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-audit-conn"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-audit-dialog+xml`
+    pub const VND_RADISYS_MSML_AUDIT_DIALOG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-audit-dialog"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-audit-stream+xml`
+    pub const VND_RADISYS_MSML_AUDIT_STREAM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-audit-stream"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-conf+xml`
+    pub const VND_RADISYS_MSML_CONF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-conf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog+xml`
+    pub const VND_RADISYS_MSML_DIALOG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-base+xml`
+    pub const VND_RADISYS_MSML_DIALOG_BASE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-base"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-fax-detect+xml`
+    pub const VND_RADISYS_MSML_DIALOG_FAX_DETECT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-fax-detect"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-fax-sendrecv+xml`
+    pub const VND_RADISYS_MSML_DIALOG_FAX_SENDRECV_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-fax-sendrecv"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-group+xml`
+    pub const VND_RADISYS_MSML_DIALOG_GROUP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-group"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-speech+xml`
+    pub const VND_RADISYS_MSML_DIALOG_SPEECH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-speech"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.radisys.msml-dialog-transform+xml`
+    pub const VND_RADISYS_MSML_DIALOG_TRANSFORM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.radisys.msml-dialog-transform"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.rainstor.data`
+    pub const VND_RAINSTOR_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rainstor.data"), None, &[]);
+    /// `application/vnd.rapid`
+    pub const VND_RAPID: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rapid"), None, &[]);
+    /// `application/vnd.rar`
+    pub const VND_RAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rar"), None, &[]);
+    /// `application/vnd.realvnc.bed`
+    pub const VND_REALVNC_BED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.realvnc.bed"), None, &[]);
+    /// `application/vnd.recordare.musicxml`
+    pub const VND_RECORDARE_MUSICXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.recordare.musicxml"), None, &[]);
+    /// `application/vnd.recordare.musicxml+xml`
+    pub const VND_RECORDARE_MUSICXML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.recordare.musicxml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.RenLearn.rlprint`
+    pub const VND_REN_LEARN_RLPRINT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.RenLearn.rlprint"), None, &[]);
+    /// `application/vnd.resilient.logic`
+    pub const VND_RESILIENT_LOGIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.resilient.logic"), None, &[]);
+    /// `application/vnd.restful+json`
+    pub const VND_RESTFUL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.restful"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.rig.cryptonote`
+    pub const VND_RIG_CRYPTONOTE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rig.cryptonote"), None, &[]);
+    /// `application/vnd.rim.cod`
+    pub const VND_RIM_COD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rim.cod"), None, &[]);
+    /// `application/vnd.route66.link66+xml`
+    pub const VND_ROUTE66_LINK66_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.route66.link66"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.rs-274x`
+    pub const VND_RS_274X: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.rs-274x"), None, &[]);
+    /// `application/vnd.ruckus.download`
+    pub const VND_RUCKUS_DOWNLOAD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ruckus.download"), None, &[]);
+    /// `application/vnd.s3sms`
+    pub const VND_S3SMS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.s3sms"), None, &[]);
+    /// `application/vnd.sailingtracker.track`
+    pub const VND_SAILINGTRACKER_TRACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sailingtracker.track"), None, &[]);
+    /// `application/vnd.sar`
+    pub const VND_SAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sar"), None, &[]);
+    /// `application/vnd.sbm.cid`
+    pub const VND_SBM_CID: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sbm.cid"), None, &[]);
+    /// `application/vnd.sbm.mid2`
+    pub const VND_SBM_MID2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sbm.mid2"), None, &[]);
+    /// `application/vnd.scribus`
+    pub const VND_SCRIBUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.scribus"), None, &[]);
+    /// `application/vnd.sealed.3df`
+    pub const VND_SEALED_3DF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.3df"), None, &[]);
+    /// `application/vnd.sealed.csf`
+    pub const VND_SEALED_CSF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.csf"), None, &[]);
+    /// `application/vnd.sealed.doc`
+    pub const VND_SEALED_DOC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.doc"), None, &[]);
+    /// `application/vnd.sealed.eml`
+    pub const VND_SEALED_EML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.eml"), None, &[]);
+    /// `application/vnd.sealed.mht`
+    pub const VND_SEALED_MHT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.mht"), None, &[]);
+    /// `application/vnd.sealed.net`
+    pub const VND_SEALED_NET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.net"), None, &[]);
+    /// `application/vnd.sealed.ppt`
+    pub const VND_SEALED_PPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.ppt"), None, &[]);
+    /// `application/vnd.sealed.tiff`
+    pub const VND_SEALED_TIFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.tiff"), None, &[]);
+    /// `application/vnd.sealed.xls`
+    pub const VND_SEALED_XLS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealed.xls"), None, &[]);
+    /// `application/vnd.sealedmedia.softseal.html`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_HTML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.html"), None, &[]);
+    /// `application/vnd.sealedmedia.softseal.pdf`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_PDF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.pdf"), None, &[]);
+    /// `application/vnd.seemail`
+    pub const VND_SEEMAIL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.seemail"), None, &[]);
+    /// `application/vnd.seis+json`
+    pub const VND_SEIS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.seis"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.sema`
+    pub const VND_SEMA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sema"), None, &[]);
+    /// `application/vnd.semd`
+    pub const VND_SEMD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.semd"), None, &[]);
+    /// `application/vnd.semf`
+    pub const VND_SEMF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.semf"), None, &[]);
+    /// `application/vnd.shade-save-file`
+    pub const VND_SHADE_SAVE_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shade-save-file"), None, &[]);
+    /// `application/vnd.shana.informed.formdata`
+    pub const VND_SHANA_INFORMED_FORMDATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shana.informed.formdata"), None, &[]);
+    /// `application/vnd.shana.informed.formtemplate`
+    pub const VND_SHANA_INFORMED_FORMTEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shana.informed.formtemplate"), None, &[]);
+    /// `application/vnd.shana.informed.interchange`
+    pub const VND_SHANA_INFORMED_INTERCHANGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shana.informed.interchange"), None, &[]);
+    /// `application/vnd.shana.informed.package`
+    pub const VND_SHANA_INFORMED_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shana.informed.package"), None, &[]);
+    /// `application/vnd.shootproof+json`
+    pub const VND_SHOOTPROOF_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shootproof"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.shopkick+json`
+    pub const VND_SHOPKICK_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shopkick"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.shp`
+    pub const VND_SHP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shp"), None, &[]);
+    /// `application/vnd.shx`
+    pub const VND_SHX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.shx"), None, &[]);
+    /// `application/vnd.sigrok.session`
+    pub const VND_SIGROK_SESSION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sigrok.session"), None, &[]);
+    /// `application/vnd.SimTech-MindMapper`
+    pub const VND_SIM_TECH_MIND_MAPPER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.SimTech-MindMapper"), None, &[]);
+    /// `application/vnd.siren+json`
+    pub const VND_SIREN_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.siren"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.smaf`
+    pub const VND_SMAF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.smaf"), None, &[]);
+    /// `application/vnd.smart.notebook`
+    pub const VND_SMART_NOTEBOOK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.smart.notebook"), None, &[]);
+    /// `application/vnd.smart.teacher`
+    pub const VND_SMART_TEACHER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.smart.teacher"), None, &[]);
+    /// `application/vnd.snesdev-page-table`
+    pub const VND_SNESDEV_PAGE_TABLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.snesdev-page-table"), None, &[]);
+    /// `application/vnd.software602.filler.form+xml`
+    pub const VND_SOFTWARE602_FILLER_FORM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.software602.filler.form"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.software602.filler.form-xml-zip`
+    pub const VND_SOFTWARE602_FILLER_FORM_XML_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.software602.filler.form-xml-zip"), None, &[]);
+    /// `application/vnd.solent.sdkm+xml`
+    pub const VND_SOLENT_SDKM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.solent.sdkm"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.spotfire.dxp`
+    pub const VND_SPOTFIRE_DXP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.spotfire.dxp"), None, &[]);
+    /// `application/vnd.spotfire.sfs`
+    pub const VND_SPOTFIRE_SFS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.spotfire.sfs"), None, &[]);
+    /// `application/vnd.sqlite3`
+    pub const VND_SQLITE3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sqlite3"), None, &[]);
+    /// `application/vnd.sss-cod`
+    pub const VND_SSS_COD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sss-cod"), None, &[]);
+    /// `application/vnd.sss-dtf`
+    pub const VND_SSS_DTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sss-dtf"), None, &[]);
+    /// `application/vnd.sss-ntf`
+    pub const VND_SSS_NTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sss-ntf"), None, &[]);
+    /// `application/vnd.stardivision.calc`
+    pub const VND_STARDIVISION_CALC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.calc"), None, &[]);
+    /// `application/vnd.stardivision.chart`
+    pub const VND_STARDIVISION_CHART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.chart"), None, &[]);
+    /// `application/vnd.stardivision.draw`
+    pub const VND_STARDIVISION_DRAW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.draw"), None, &[]);
+    /// `application/vnd.stardivision.impress`
+    pub const VND_STARDIVISION_IMPRESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.impress"), None, &[]);
+    /// `application/vnd.stardivision.math`
+    pub const VND_STARDIVISION_MATH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.math"), None, &[]);
+    /// `application/vnd.stardivision.writer`
+    pub const VND_STARDIVISION_WRITER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.writer"), None, &[]);
+    /// `application/vnd.stardivision.writer-global`
+    pub const VND_STARDIVISION_WRITER_GLOBAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stardivision.writer-global"), None, &[]);
+    /// `application/vnd.stepmania.package`
+    pub const VND_STEPMANIA_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stepmania.package"), None, &[]);
+    /// `application/vnd.stepmania.stepchart`
+    pub const VND_STEPMANIA_STEPCHART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.stepmania.stepchart"), None, &[]);
+    /// `application/vnd.street-stream`
+    pub const VND_STREET_STREAM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.street-stream"), None, &[]);
+    /// `application/vnd.sun.wadl+xml`
+    pub const VND_SUN_WADL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.wadl"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.sun.xml.calc`
+    pub const VND_SUN_XML_CALC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.calc"), None, &[]);
+    /// `application/vnd.sun.xml.calc.template`
+    pub const VND_SUN_XML_CALC_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.calc.template"), None, &[]);
+    /// `application/vnd.sun.xml.draw`
+    pub const VND_SUN_XML_DRAW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.draw"), None, &[]);
+    /// `application/vnd.sun.xml.draw.template`
+    pub const VND_SUN_XML_DRAW_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.draw.template"), None, &[]);
+    /// `application/vnd.sun.xml.impress`
+    pub const VND_SUN_XML_IMPRESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.impress"), None, &[]);
+    /// `application/vnd.sun.xml.impress.template`
+    pub const VND_SUN_XML_IMPRESS_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.impress.template"), None, &[]);
+    /// `application/vnd.sun.xml.math`
+    pub const VND_SUN_XML_MATH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.math"), None, &[]);
+    /// `application/vnd.sun.xml.writer`
+    pub const VND_SUN_XML_WRITER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.writer"), None, &[]);
+    /// `application/vnd.sun.xml.writer.global`
+    pub const VND_SUN_XML_WRITER_GLOBAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.writer.global"), None, &[]);
+    /// `application/vnd.sun.xml.writer.template`
+    pub const VND_SUN_XML_WRITER_TEMPLATE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sun.xml.writer.template"), None, &[]);
+    /// `application/vnd.sus-calendar`
+    pub const VND_SUS_CALENDAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sus-calendar"), None, &[]);
+    /// `application/vnd.svd`
+    pub const VND_SVD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.svd"), None, &[]);
+    /// `application/vnd.swiftview-ics`
+    pub const VND_SWIFTVIEW_ICS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.swiftview-ics"), None, &[]);
+    /// `application/vnd.sybyl.mol2`
+    pub const VND_SYBYL_MOL2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sybyl.mol2"), None, &[]);
+    /// `application/vnd.sycle+xml`
+    pub const VND_SYCLE_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.sycle"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.syft+json`
+    pub const VND_SYFT_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syft"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.symbian.install`
+    pub const VND_SYMBIAN_INSTALL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.symbian.install"), None, &[]);
+    /// `application/vnd.syncml+xml`
+    pub const VND_SYNCML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.syncml.dm+wbxml`
+    pub const VND_SYNCML_DM_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dm"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.syncml.dm+xml`
+    pub const VND_SYNCML_DM_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dm"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.syncml.dm.notification`
+    pub const VND_SYNCML_DM_NOTIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dm.notification"), None, &[]);
+    /// `application/vnd.syncml.dmddf+wbxml`
+    pub const VND_SYNCML_DMDDF_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dmddf"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.syncml.dmddf+xml`
+    pub const VND_SYNCML_DMDDF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dmddf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.syncml.dmtnds+wbxml`
+    pub const VND_SYNCML_DMTNDS_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dmtnds"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.syncml.dmtnds+xml`
+    pub const VND_SYNCML_DMTNDS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.dmtnds"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.syncml.ds.notification`
+    pub const VND_SYNCML_DS_NOTIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.syncml.ds.notification"), None, &[]);
+    /// `application/vnd.tableschema+json`
+    pub const VND_TABLESCHEMA_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tableschema"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.tao.intent-module-archive`
+    pub const VND_TAO_INTENT_MODULE_ARCHIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tao.intent-module-archive"), None, &[]);
+    /// `application/vnd.tcpdump.pcap`
+    pub const VND_TCPDUMP_PCAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tcpdump.pcap"), None, &[]);
+    /// `application/vnd.theqvd`
+    pub const VND_THEQVD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.theqvd"), None, &[]);
+    /// `application/vnd.think-cell.ppttc+json`
+    pub const VND_THINK_CELL_PPTTC_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.think-cell.ppttc"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.tmd.mediaflex.api+xml`
+    pub const VND_TMD_MEDIAFLEX_API_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tmd.mediaflex.api"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.tml`
+    pub const VND_TML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tml"), None, &[]);
+    /// `application/vnd.tmobile-livetv`
+    pub const VND_TMOBILE_LIVETV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tmobile-livetv"), None, &[]);
+    /// `application/vnd.tri.onesource`
+    pub const VND_TRI_ONESOURCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.tri.onesource"), None, &[]);
+    /// `application/vnd.trid.tpt`
+    pub const VND_TRID_TPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.trid.tpt"), None, &[]);
+    /// `application/vnd.triscape.mxs`
+    pub const VND_TRISCAPE_MXS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.triscape.mxs"), None, &[]);
+    /// `application/vnd.trueapp`
+    pub const VND_TRUEAPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.trueapp"), None, &[]);
+    /// `application/vnd.truedoc`
+    pub const VND_TRUEDOC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.truedoc"), None, &[]);
+    /// `application/vnd.ubisoft.webplayer`
+    pub const VND_UBISOFT_WEBPLAYER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ubisoft.webplayer"), None, &[]);
+    /// `application/vnd.ufdl`
+    pub const VND_UFDL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ufdl"), None, &[]);
+    /// `application/vnd.uiq.theme`
+    pub const VND_UIQ_THEME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uiq.theme"), None, &[]);
+    /// `application/vnd.umajin`
+    pub const VND_UMAJIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.umajin"), None, &[]);
+    /// `application/vnd.unity`
+    pub const VND_UNITY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.unity"), None, &[]);
+    /// `application/vnd.uoml+xml`
+    pub const VND_UOML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uoml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.uplanet.alert`
+    pub const VND_UPLANET_ALERT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.alert"), None, &[]);
+    /// `application/vnd.uplanet.alert-wbxml`
+    pub const VND_UPLANET_ALERT_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.alert-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.bearer-choice`
+    pub const VND_UPLANET_BEARER_CHOICE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.bearer-choice"), None, &[]);
+    /// `application/vnd.uplanet.bearer-choice-wbxml`
+    pub const VND_UPLANET_BEARER_CHOICE_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.bearer-choice-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.cacheop`
+    pub const VND_UPLANET_CACHEOP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.cacheop"), None, &[]);
+    /// `application/vnd.uplanet.cacheop-wbxml`
+    pub const VND_UPLANET_CACHEOP_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.cacheop-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.channel`
+    pub const VND_UPLANET_CHANNEL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.channel"), None, &[]);
+    /// `application/vnd.uplanet.channel-wbxml`
+    pub const VND_UPLANET_CHANNEL_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.channel-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.list`
+    pub const VND_UPLANET_LIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.list"), None, &[]);
+    /// `application/vnd.uplanet.list-wbxml`
+    pub const VND_UPLANET_LIST_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.list-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.listcmd`
+    pub const VND_UPLANET_LISTCMD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.listcmd"), None, &[]);
+    /// `application/vnd.uplanet.listcmd-wbxml`
+    pub const VND_UPLANET_LISTCMD_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.listcmd-wbxml"), None, &[]);
+    /// `application/vnd.uplanet.signal`
+    pub const VND_UPLANET_SIGNAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uplanet.signal"), None, &[]);
+    /// `application/vnd.uri-map`
+    pub const VND_URI_MAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.uri-map"), None, &[]);
+    /// `application/vnd.valve.source.material`
+    pub const VND_VALVE_SOURCE_MATERIAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.valve.source.material"), None, &[]);
+    /// `application/vnd.vcx`
+    pub const VND_VCX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vcx"), None, &[]);
+    /// `application/vnd.vd-study`
+    pub const VND_VD_STUDY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vd-study"), None, &[]);
+    /// `application/vnd.vectorworks`
+    pub const VND_VECTORWORKS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vectorworks"), None, &[]);
+    /// `application/vnd.vel+json`
+    pub const VND_VEL_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vel"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.verimatrix.vcas`
+    pub const VND_VERIMATRIX_VCAS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.verimatrix.vcas"), None, &[]);
+    /// `application/vnd.veritone.aion+json`
+    pub const VND_VERITONE_AION_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.veritone.aion"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.veryant.thin`
+    pub const VND_VERYANT_THIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.veryant.thin"), None, &[]);
+    /// `application/vnd.ves.encrypted`
+    pub const VND_VES_ENCRYPTED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.ves.encrypted"), None, &[]);
+    /// `application/vnd.vidsoft.vidconference`
+    pub const VND_VIDSOFT_VIDCONFERENCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vidsoft.vidconference"), None, &[]);
+    /// `application/vnd.visio`
+    pub const VND_VISIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.visio"), None, &[]);
+    /// `application/vnd.visionary`
+    pub const VND_VISIONARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.visionary"), None, &[]);
+    /// `application/vnd.vividence.scriptfile`
+    pub const VND_VIVIDENCE_SCRIPTFILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vividence.scriptfile"), None, &[]);
+    /// `application/vnd.vsf`
+    pub const VND_VSF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.vsf"), None, &[]);
+    /// `application/vnd.wap.sic`
+    pub const VND_WAP_SIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wap.sic"), None, &[]);
+    /// `application/vnd.wap.slc`
+    pub const VND_WAP_SLC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wap.slc"), None, &[]);
+    /// `application/vnd.wap.wbxml`
+    pub const VND_WAP_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wap.wbxml"), None, &[]);
+    /// `application/vnd.wap.wmlc`
+    pub const VND_WAP_WMLC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wap.wmlc"), None, &[]);
+    /// `application/vnd.wap.wmlscriptc`
+    pub const VND_WAP_WMLSCRIPTC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wap.wmlscriptc"), None, &[]);
+    /// `application/vnd.wasmflow.wafl`
+    pub const VND_WASMFLOW_WAFL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wasmflow.wafl"), None, &[]);
+    /// `application/vnd.webturbo`
+    pub const VND_WEBTURBO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.webturbo"), None, &[]);
+    /// `application/vnd.wfa.dpp`
+    pub const VND_WFA_DPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wfa.dpp"), None, &[]);
+    /// `application/vnd.wfa.p2p`
+    pub const VND_WFA_P2P: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wfa.p2p"), None, &[]);
+    /// `application/vnd.wfa.wsc`
+    pub const VND_WFA_WSC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wfa.wsc"), None, &[]);
+    /// `application/vnd.windows.devicepairing`
+    pub const VND_WINDOWS_DEVICEPAIRING: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.windows.devicepairing"), None, &[]);
+    /// `application/vnd.wmc`
+    pub const VND_WMC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wmc"), None, &[]);
+    /// `application/vnd.wmf.bootstrap`
+    pub const VND_WMF_BOOTSTRAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wmf.bootstrap"), None, &[]);
+    /// `application/vnd.wolfram.mathematica`
+    pub const VND_WOLFRAM_MATHEMATICA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wolfram.mathematica"), None, &[]);
+    /// `application/vnd.wolfram.mathematica.package`
+    pub const VND_WOLFRAM_MATHEMATICA_PACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wolfram.mathematica.package"), None, &[]);
+    /// `application/vnd.wolfram.player`
+    pub const VND_WOLFRAM_PLAYER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wolfram.player"), None, &[]);
+    /// `application/vnd.wordlift`
+    pub const VND_WORDLIFT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wordlift"), None, &[]);
+    /// `application/vnd.wordperfect`
+    pub const VND_WORDPERFECT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wordperfect"), None, &[]);
+    /// `application/vnd.wqd`
+    pub const VND_WQD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wqd"), None, &[]);
+    /// `application/vnd.wrq-hp3000-labelled`
+    pub const VND_WRQ_HP3000_LABELLED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wrq-hp3000-labelled"), None, &[]);
+    /// `application/vnd.wt.stf`
+    pub const VND_WT_STF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wt.stf"), None, &[]);
+    /// `application/vnd.wv.csp+wbxml`
+    pub const VND_WV_CSP_PLUS_WBXML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wv.csp"), Some(crate::Name::new_unchecked("wbxml")), &[]);
+    /// `application/vnd.wv.csp+xml`
+    pub const VND_WV_CSP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wv.csp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.wv.ssp+xml`
+    pub const VND_WV_SSP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.wv.ssp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.xacml+json`
+    pub const VND_XACML_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xacml"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vnd.xara`
+    pub const VND_XARA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xara"), None, &[]);
+    /// `application/vnd.xfdl`
+    pub const VND_XFDL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xfdl"), None, &[]);
+    /// `application/vnd.xfdl.webform`
+    pub const VND_XFDL_WEBFORM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xfdl.webform"), None, &[]);
+    /// `application/vnd.xmi+xml`
+    pub const VND_XMI_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmi"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.xmpie.cpkg`
+    pub const VND_XMPIE_CPKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmpie.cpkg"), None, &[]);
+    /// `application/vnd.xmpie.dpkg`
+    pub const VND_XMPIE_DPKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmpie.dpkg"), None, &[]);
+    /// `application/vnd.xmpie.plan`
+    pub const VND_XMPIE_PLAN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmpie.plan"), None, &[]);
+    /// `application/vnd.xmpie.ppkg`
+    pub const VND_XMPIE_PPKG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmpie.ppkg"), None, &[]);
+    /// `application/vnd.xmpie.xlim`
+    pub const VND_XMPIE_XLIM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.xmpie.xlim"), None, &[]);
+    /// `application/vnd.yamaha.hv-dic`
+    pub const VND_YAMAHA_HV_DIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.hv-dic"), None, &[]);
+    /// `application/vnd.yamaha.hv-script`
+    pub const VND_YAMAHA_HV_SCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.hv-script"), None, &[]);
+    /// `application/vnd.yamaha.hv-voice`
+    pub const VND_YAMAHA_HV_VOICE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.hv-voice"), None, &[]);
+    /// `application/vnd.yamaha.openscoreformat`
+    pub const VND_YAMAHA_OPENSCOREFORMAT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.openscoreformat"), None, &[]);
+    /// `application/vnd.yamaha.openscoreformat.osfpvg+xml`
+    pub const VND_YAMAHA_OPENSCOREFORMAT_OSFPVG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.openscoreformat.osfpvg"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/vnd.yamaha.remote-setup`
+    pub const VND_YAMAHA_REMOTE_SETUP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.remote-setup"), None, &[]);
+    /// `application/vnd.yamaha.smaf-audio`
+    pub const VND_YAMAHA_SMAF_AUDIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.smaf-audio"), None, &[]);
+    /// `application/vnd.yamaha.smaf-phrase`
+    pub const VND_YAMAHA_SMAF_PHRASE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.smaf-phrase"), None, &[]);
+    /// `application/vnd.yamaha.through-ngn`
+    pub const VND_YAMAHA_THROUGH_NGN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.through-ngn"), None, &[]);
+    /// `application/vnd.yamaha.tunnel-udpencap`
+    pub const VND_YAMAHA_TUNNEL_UDPENCAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yamaha.tunnel-udpencap"), None, &[]);
+    /// `application/vnd.yaoweme`
+    pub const VND_YAOWEME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yaoweme"), None, &[]);
+    /// `application/vnd.yellowriver-custom-menu`
+    pub const VND_YELLOWRIVER_CUSTOM_MENU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.yellowriver-custom-menu"), None, &[]);
+    /// `application/vnd.zul`
+    pub const VND_ZUL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.zul"), None, &[]);
+    /// `application/vnd.zzazz.deck+xml`
+    pub const VND_ZZAZZ_DECK_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vnd.zzazz.deck"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/voicexml+xml`
+    pub const VOICEXML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("voicexml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/voucher-cms+json`
+    pub const VOUCHER_CMS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("voucher-cms"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/vq-rtcpxr`
+    pub const VQ_RTCPXR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("vq-rtcpxr"), None, &[]);
+    /// `application/wasm`
+    pub const WASM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("wasm"), None, &[]);
+    /// `application/watcherinfo+xml`
+    pub const WATCHERINFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("watcherinfo"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/webpush-options+json`
+    pub const WEBPUSH_OPTIONS_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("webpush-options"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/whoispp-query`
+    pub const WHOISPP_QUERY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("whoispp-query"), None, &[]);
+    /// `application/whoispp-response`
+    pub const WHOISPP_RESPONSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("whoispp-response"), None, &[]);
+    /// `application/widget`
+    pub const WIDGET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("widget"), None, &[]);
+    /// `application/wita`
+    pub const WITA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("wita"), None, &[]);
+    /// `application/wordperfect5.1`
+    pub const WORDPERFECT5_1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("wordperfect5.1"), None, &[]);
+    /// `application/wsdl+xml`
+    pub const WSDL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("wsdl"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/wspolicy+xml`
+    pub const WSPOLICY_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("wspolicy"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/x400-bp`
+    pub const X400_BP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("x400-bp"), None, &[]);
+    /// `application/xacml+xml`
+    pub const XACML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xacml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-att+xml`
+    pub const XCAP_ATT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-att"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-caps+xml`
+    pub const XCAP_CAPS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-caps"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-diff+xml`
+    pub const XCAP_DIFF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-diff"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-el+xml`
+    pub const XCAP_EL_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-el"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-error+xml`
+    pub const XCAP_ERROR_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-error"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcap-ns+xml`
+    pub const XCAP_NS_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcap-ns"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcon-conference-info+xml`
+    pub const XCON_CONFERENCE_INFO_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcon-conference-info"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xcon-conference-info-diff+xml`
+    pub const XCON_CONFERENCE_INFO_DIFF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xcon-conference-info-diff"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xenc+xml`
+    pub const XENC_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xenc"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xfdf`
+    pub const XFDF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xfdf"), None, &[]);
+    /// `application/xhtml+xml`
+    pub const XHTML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xhtml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xliff+xml`
+    pub const XLIFF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xliff"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xml`
+    pub const XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xml"), None, &[]);
+    /// `application/xml-dtd`
+    pub const XML_DTD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xml-dtd"), None, &[]);
+    /// `application/xml-external-parsed-entity`
+    pub const XML_EXTERNAL_PARSED_ENTITY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xml-external-parsed-entity"), None, &[]);
+    /// `application/xml-patch+xml`
+    pub const XML_PATCH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xml-patch"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xmpp+xml`
+    pub const XMPP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xmpp"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xop+xml`
+    pub const XOP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xop"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xslt+xml`
+    pub const XSLT_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xslt"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xspf+xml`
+    pub const XSPF_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xspf"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/xv+xml`
+    pub const XV_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("xv"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/yang`
+    pub const YANG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang"), None, &[]);
+    /// `application/yang-data+cbor`
+    pub const YANG_DATA_PLUS_CBOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang-data"), Some(crate::Name::new_unchecked("cbor")), &[]);
+    /// `application/yang-data+json`
+    pub const YANG_DATA_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang-data"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/yang-data+xml`
+    pub const YANG_DATA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang-data"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/yang-patch+json`
+    pub const YANG_PATCH_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang-patch"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `application/yang-patch+xml`
+    pub const YANG_PATCH_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yang-patch"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/yin+xml`
+    pub const YIN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("yin"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `application/zip`
+    pub const ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("zip"), None, &[]);
+    /// `application/zlib`
+    pub const ZLIB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("zlib"), None, &[]);
+    /// `application/zstd`
+    pub const ZSTD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("application"), crate::Name::new_unchecked("zstd"), None, &[]);
+}
+
+/// Media types with the `audio` top-level type.
+pub mod audio {
+    /// `audio/1d-interleaved-parityfec`
+    pub const _1D_INTERLEAVED_PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("1d-interleaved-parityfec"), None, &[]);
+    /// `audio/32kadpcm`
+    pub const _32KADPCM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("32kadpcm"), None, &[]);
+    /// `audio/3gpp`
+    pub const _3GPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("3gpp"), None, &[]);
+    /// `audio/3gpp2`
+    pub const _3GPP2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("3gpp2"), None, &[]);
+    /// `audio/aac`
+    pub const AAC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("aac"), None, &[]);
+    /// `audio/ac3`
+    pub const AC3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ac3"), None, &[]);
+    /// `audio/AMR`
+    pub const AMR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("AMR"), None, &[]);
+    /// `audio/AMR-WB`
+    pub const AMR_WB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("AMR-WB"), None, &[]);
+    /// `audio/amr-wb+`
+    pub const AMR_WB_PLUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("amr-wb+"), None, &[]);
+    /// `audio/annodex`
+    pub const ANNODEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("annodex"), None, &[]);
+    /// `audio/aptx`
+    pub const APTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("aptx"), None, &[]);
+    /// `audio/asc`
+    pub const ASC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("asc"), None, &[]);
+    /// `audio/ATRAC-ADVANCED-LOSSLESS`
+    pub const ATRAC_ADVANCED_LOSSLESS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ATRAC-ADVANCED-LOSSLESS"), None, &[]);
+    /// `audio/ATRAC-X`
+    pub const ATRAC_X: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ATRAC-X"), None, &[]);
+    /// `audio/ATRAC3`
+    pub const ATRAC3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ATRAC3"), None, &[]);
+    /// `audio/basic`
+    pub const BASIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("basic"), None, &[]);
+    /// `audio/BV16`
+    pub const BV16: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("BV16"), None, &[]);
+    /// `audio/BV32`
+    pub const BV32: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("BV32"), None, &[]);
+    /// `audio/clearmode`
+    pub const CLEARMODE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("clearmode"), None, &[]);
+    /// `audio/CN`
+    pub const CN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("CN"), None, &[]);
+    /// `audio/csound`
+    pub const CSOUND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("csound"), None, &[]);
+    /// `audio/DAT12`
+    pub const DAT12: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("DAT12"), None, &[]);
+    /// `audio/dls`
+    pub const DLS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("dls"), None, &[]);
+    /// `audio/dsr-es201108`
+    pub const DSR_ES201108: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("dsr-es201108"), None, &[]);
+    /// `audio/dsr-es202050`
+    pub const DSR_ES202050: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("dsr-es202050"), None, &[]);
+    /// `audio/dsr-es202211`
+    pub const DSR_ES202211: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("dsr-es202211"), None, &[]);
+    /// `audio/dsr-es202212`
+    pub const DSR_ES202212: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("dsr-es202212"), None, &[]);
+    /// `audio/DV`
+    pub const DV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("DV"), None, &[]);
+    /// `audio/DVI4`
+    pub const DVI4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("DVI4"), None, &[]);
+    /// `audio/eac3`
+    pub const EAC3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("eac3"), None, &[]);
+    /// `audio/encaprtp`
+    pub const ENCAPRTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("encaprtp"), None, &[]);
+    /// `audio/EVRC`
+    pub const EVRC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRC"), None, &[]);
+    /// `audio/EVRC-QCP`
+    pub const EVRC_QCP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRC-QCP"), None, &[]);
+    /// `audio/EVRC0`
+    pub const EVRC0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRC0"), None, &[]);
+    /// `audio/EVRC1`
+    pub const EVRC1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRC1"), None, &[]);
+    /// `audio/EVRCB`
+    pub const EVRCB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCB"), None, &[]);
+    /// `audio/EVRCB0`
+    pub const EVRCB0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCB0"), None, &[]);
+    /// `audio/EVRCB1`
+    pub const EVRCB1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCB1"), None, &[]);
+    /// `audio/EVRCNW`
+    pub const EVRCNW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCNW"), None, &[]);
+    /// `audio/EVRCNW0`
+    pub const EVRCNW0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCNW0"), None, &[]);
+    /// `audio/EVRCNW1`
+    pub const EVRCNW1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCNW1"), None, &[]);
+    /// `audio/EVRCWB`
+    pub const EVRCWB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCWB"), None, &[]);
+    /// `audio/EVRCWB0`
+    pub const EVRCWB0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCWB0"), None, &[]);
+    /// `audio/EVRCWB1`
+    pub const EVRCWB1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVRCWB1"), None, &[]);
+    /// `audio/EVS`
+    pub const EVS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("EVS"), None, &[]);
+    /// `audio/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `audio/flac`
+    pub const FLAC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("flac"), None, &[]);
+    /// `audio/flexfec`
+    pub const FLEXFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("flexfec"), None, &[]);
+    /// `audio/fwdred`
+    pub const FWDRED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("fwdred"), None, &[]);
+    /// `audio/G711-0`
+    pub const G711_0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G711-0"), None, &[]);
+    /// `audio/G719`
+    pub const G719: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G719"), None, &[]);
+    /// `audio/G722`
+    pub const G722: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G722"), None, &[]);
+    /// `audio/G7221`
+    pub const G7221: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G7221"), None, &[]);
+    /// `audio/G723`
+    pub const G723: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G723"), None, &[]);
+    /// `audio/G726-16`
+    pub const G726_16: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G726-16"), None, &[]);
+    /// `audio/G726-24`
+    pub const G726_24: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G726-24"), None, &[]);
+    /// `audio/G726-32`
+    pub const G726_32: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G726-32"), None, &[]);
+    /// `audio/G726-40`
+    pub const G726_40: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G726-40"), None, &[]);
+    /// `audio/G728`
+    pub const G728: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G728"), None, &[]);
+    /// `audio/G729`
+    pub const G729: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G729"), None, &[]);
+    /// `audio/G7291`
+    pub const G7291: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G7291"), None, &[]);
+    /// `audio/G729D`
+    pub const G729D: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G729D"), None, &[]);
+    /// `audio/G729E`
+    pub const G729E: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("G729E"), None, &[]);
+    /// `audio/GSM`
+    pub const GSM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("GSM"), None, &[]);
+    /// `audio/GSM-EFR`
+    pub const GSM_EFR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("GSM-EFR"), None, &[]);
+    /// `audio/GSM-HR-08`
+    pub const GSM_HR_08: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("GSM-HR-08"), None, &[]);
+    /// `audio/iLBC`
+    pub const I_LBC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("iLBC"), None, &[]);
+    /// `audio/ip-mr_v2.5`
+    pub const IP_MR_V2_5: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ip-mr_v2.5"), None, &[]);
+    /// `audio/L16`
+    pub const L16: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("L16"), None, &[]);
+    /// `audio/L20`
+    pub const L20: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("L20"), None, &[]);
+    /// `audio/L24`
+    pub const L24: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("L24"), None, &[]);
+    /// `audio/L8`
+    pub const L8: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("L8"), None, &[]);
+    /// `audio/LPC`
+    pub const LPC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("LPC"), None, &[]);
+    /// `audio/MELP`
+    pub const MELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MELP"), None, &[]);
+    /// `audio/MELP1200`
+    pub const MELP1200: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MELP1200"), None, &[]);
+    /// `audio/MELP2400`
+    pub const MELP2400: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MELP2400"), None, &[]);
+    /// `audio/MELP600`
+    pub const MELP600: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MELP600"), None, &[]);
+    /// `audio/mhas`
+    pub const MHAS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mhas"), None, &[]);
+    /// `audio/mobile-xmf`
+    pub const MOBILE_XMF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mobile-xmf"), None, &[]);
+    /// `audio/mp4`
+    pub const MP4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mp4"), None, &[]);
+    /// `audio/MP4A-LATM`
+    pub const MP4A_LATM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MP4A-LATM"), None, &[]);
+    /// `audio/MPA`
+    pub const MPA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("MPA"), None, &[]);
+    /// `audio/mpa-robust`
+    pub const MPA_ROBUST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mpa-robust"), None, &[]);
+    /// `audio/mpeg`
+    pub const MPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mpeg"), None, &[]);
+    /// `audio/mpeg4-generic`
+    pub const MPEG4_GENERIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mpeg4-generic"), None, &[]);
+    /// `audio/mpegurl`
+    pub const MPEGURL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("mpegurl"), None, &[]);
+    /// `audio/ogg`
+    pub const OGG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ogg"), None, &[]);
+    /// `audio/opus`
+    pub const OPUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("opus"), None, &[]);
+    /// `audio/parityfec`
+    pub const PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("parityfec"), None, &[]);
+    /// `audio/PCMA`
+    pub const PCMA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("PCMA"), None, &[]);
+    /// `audio/PCMA-WB`
+    pub const PCMA_WB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("PCMA-WB"), None, &[]);
+    /// `audio/PCMU`
+    pub const PCMU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("PCMU"), None, &[]);
+    /// `audio/PCMU-WB`
+    pub const PCMU_WB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("PCMU-WB"), None, &[]);
+    /// `audio/prs.sid`
+    pub const PRS_SID: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("prs.sid"), None, &[]);
+    /// `audio/QCELP`
+    pub const QCELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("QCELP"), None, &[]);
+    /// `audio/raptorfec`
+    pub const RAPTORFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("raptorfec"), None, &[]);
+    /// `audio/RED`
+    pub const RED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("RED"), None, &[]);
+    /// `audio/rtp-enc-aescm128`
+    pub const RTP_ENC_AESCM128: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("rtp-enc-aescm128"), None, &[]);
+    /// `audio/rtp-midi`
+    pub const RTP_MIDI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("rtp-midi"), None, &[]);
+    /// `audio/rtploopback`
+    pub const RTPLOOPBACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("rtploopback"), None, &[]);
+    /// `audio/rtx`
+    pub const RTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("rtx"), None, &[]);
+    /// `audio/scip`
+    pub const SCIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("scip"), None, &[]);
+    /// `audio/SMV`
+    pub const SMV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("SMV"), None, &[]);
+    /// `audio/SMV-QCP`
+    pub const SMV_QCP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("SMV-QCP"), None, &[]);
+    /// `audio/SMV0`
+    pub const SMV0: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("SMV0"), None, &[]);
+    /// `audio/sofa`
+    pub const SOFA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("sofa"), None, &[]);
+    /// `audio/sp-midi`
+    pub const SP_MIDI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("sp-midi"), None, &[]);
+    /// `audio/speex`
+    pub const SPEEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("speex"), None, &[]);
+    /// `audio/t140c`
+    pub const T140C: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("t140c"), None, &[]);
+    /// `audio/t38`
+    pub const T38: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("t38"), None, &[]);
+    /// `audio/telephone-event`
+    pub const TELEPHONE_EVENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("telephone-event"), None, &[]);
+    /// `audio/TETRA_ACELP`
+    pub const TETRA_ACELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("TETRA_ACELP"), None, &[]);
+    /// `audio/TETRA_ACELP_BB`
+    pub const TETRA_ACELP_BB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("TETRA_ACELP_BB"), None, &[]);
+    /// `audio/tone`
+    pub const TONE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("tone"), None, &[]);
+    /// `audio/TSVCIS`
+    pub const TSVCIS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("TSVCIS"), None, &[]);
+    /// `audio/UEMCLIP`
+    pub const UEMCLIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("UEMCLIP"), None, &[]);
+    /// `audio/ulpfec`
+    pub const ULPFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("ulpfec"), None, &[]);
+    /// `audio/usac`
+    pub const USAC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("usac"), None, &[]);
+    /// `audio/VDVI`
+    pub const VDVI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("VDVI"), None, &[]);
+    /// `audio/VMR-WB`
+    pub const VMR_WB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("VMR-WB"), None, &[]);
+    /// `audio/vnd.3gpp.iufp`
+    pub const VND_3GPP_IUFP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.3gpp.iufp"), None, &[]);
+    /// `audio/vnd.4SB`
+    pub const VND_4SB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.4SB"), None, &[]);
+    /// `audio/vnd.audiokoz`
+    pub const VND_AUDIOKOZ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.audiokoz"), None, &[]);
+    /// `audio/vnd.CELP`
+    pub const VND_CELP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.CELP"), None, &[]);
+    /// `audio/vnd.cisco.nse`
+    pub const VND_CISCO_NSE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.cisco.nse"), None, &[]);
+    /// `audio/vnd.cmles.radio-events`
+    pub const VND_CMLES_RADIO_EVENTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.cmles.radio-events"), None, &[]);
+    /// `audio/vnd.cns.anp1`
+    pub const VND_CNS_ANP1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.cns.anp1"), None, &[]);
+    /// `audio/vnd.cns.inf1`
+    pub const VND_CNS_INF1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.cns.inf1"), None, &[]);
+    /// `audio/vnd.dece.audio`
+    pub const VND_DECE_AUDIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dece.audio"), None, &[]);
+    /// `audio/vnd.digital-winds`
+    pub const VND_DIGITAL_WINDS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.digital-winds"), None, &[]);
+    /// `audio/vnd.dlna.adts`
+    pub const VND_DLNA_ADTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dlna.adts"), None, &[]);
+    /// `audio/vnd.dolby.heaac.1`
+    pub const VND_DOLBY_HEAAC_1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.heaac.1"), None, &[]);
+    /// `audio/vnd.dolby.heaac.2`
+    pub const VND_DOLBY_HEAAC_2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.heaac.2"), None, &[]);
+    /// `audio/vnd.dolby.mlp`
+    pub const VND_DOLBY_MLP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.mlp"), None, &[]);
+    /// `audio/vnd.dolby.mps`
+    pub const VND_DOLBY_MPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.mps"), None, &[]);
+    /// `audio/vnd.dolby.pl2`
+    pub const VND_DOLBY_PL2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.pl2"), None, &[]);
+    /// `audio/vnd.dolby.pl2x`
+    pub const VND_DOLBY_PL2X: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.pl2x"), None, &[]);
+    /// `audio/vnd.dolby.pl2z`
+    pub const VND_DOLBY_PL2Z: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.pl2z"), None, &[]);
+    /// `audio/vnd.dolby.pulse.1`
+    pub const VND_DOLBY_PULSE_1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dolby.pulse.1"), None, &[]);
+    /// `audio/vnd.dra`
+    pub const VND_DRA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dra"), None, &[]);
+    /// `audio/vnd.dts`
+    pub const VND_DTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dts"), None, &[]);
+    /// `audio/vnd.dts.hd`
+    pub const VND_DTS_HD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dts.hd"), None, &[]);
+    /// `audio/vnd.dts.uhd`
+    pub const VND_DTS_UHD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dts.uhd"), None, &[]);
+    /// `audio/vnd.dvb.file`
+    pub const VND_DVB_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.dvb.file"), None, &[]);
+    /// `audio/vnd.everad.plj`
+    pub const VND_EVERAD_PLJ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.everad.plj"), None, &[]);
+    /// `audio/vnd.hns.audio`
+    pub const VND_HNS_AUDIO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.hns.audio"), None, &[]);
+    /// `audio/vnd.lucent.voice`
+    pub const VND_LUCENT_VOICE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.lucent.voice"), None, &[]);
+    /// `audio/vnd.ms-playready.media.pya`
+    pub const VND_MS_PLAYREADY_MEDIA_PYA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.ms-playready.media.pya"), None, &[]);
+    /// `audio/vnd.nokia.mobile-xmf`
+    pub const VND_NOKIA_MOBILE_XMF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.nokia.mobile-xmf"), None, &[]);
+    /// `audio/vnd.nortel.vbk`
+    pub const VND_NORTEL_VBK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.nortel.vbk"), None, &[]);
+    /// `audio/vnd.nuera.ecelp4800`
+    pub const VND_NUERA_ECELP4800: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.nuera.ecelp4800"), None, &[]);
+    /// `audio/vnd.nuera.ecelp7470`
+    pub const VND_NUERA_ECELP7470: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.nuera.ecelp7470"), None, &[]);
+    /// `audio/vnd.nuera.ecelp9600`
+    pub const VND_NUERA_ECELP9600: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.nuera.ecelp9600"), None, &[]);
+    /// `audio/vnd.octel.sbc`
+    pub const VND_OCTEL_SBC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.octel.sbc"), None, &[]);
+    /// `audio/vnd.presonus.multitrack`
+    pub const VND_PRESONUS_MULTITRACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.presonus.multitrack"), None, &[]);
+    /// `audio/vnd.rhetorex.32kadpcm`
+    pub const VND_RHETOREX_32KADPCM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.rhetorex.32kadpcm"), None, &[]);
+    /// `audio/vnd.rip`
+    pub const VND_RIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.rip"), None, &[]);
+    /// `audio/vnd.sealedmedia.softseal.mpeg`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_MPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.mpeg"), None, &[]);
+    /// `audio/vnd.vmx.cvsd`
+    pub const VND_VMX_CVSD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vnd.vmx.cvsd"), None, &[]);
+    /// `audio/vorbis`
+    pub const VORBIS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vorbis"), None, &[]);
+    /// `audio/vorbis-config`
+    pub const VORBIS_CONFIG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("audio"), crate::Name::new_unchecked("vorbis-config"), None, &[]);
+}
+
+/// Media types with the `font` top-level type.
+pub mod font {
+    /// `font/collection`
+    pub const COLLECTION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("collection"), None, &[]);
+    /// `font/otf`
+    pub const OTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("otf"), None, &[]);
+    /// `font/sfnt`
+    pub const SFNT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("sfnt"), None, &[]);
+    /// `font/ttf`
+    pub const TTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("ttf"), None, &[]);
+    /// `font/woff`
+    pub const WOFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("woff"), None, &[]);
+    /// `font/woff2`
+    pub const WOFF2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("font"), crate::Name::new_unchecked("woff2"), None, &[]);
+}
+
+/// Media types with the `image` top-level type.
+pub mod image {
+    /// `image/aces`
+    pub const ACES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("aces"), None, &[]);
+    /// `image/apng`
+    pub const APNG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("apng"), None, &[]);
+    /// `image/avci`
+    pub const AVCI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("avci"), None, &[]);
+    /// `image/avcs`
+    pub const AVCS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("avcs"), None, &[]);
+    /// `image/avif`
+    pub const AVIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("avif"), None, &[]);
+    /// `image/bmp`
+    pub const BMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("bmp"), None, &[]);
+    /// `image/cgm`
+    pub const CGM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("cgm"), None, &[]);
+    /// `image/dicom-rle`
+    pub const DICOM_RLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("dicom-rle"), None, &[]);
+    /// `image/dpx`
+    pub const DPX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("dpx"), None, &[]);
+    /// `image/emf`
+    pub const EMF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("emf"), None, &[]);
+    /// `image/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `image/fits`
+    pub const FITS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("fits"), None, &[]);
+    /// `image/g3fax`
+    pub const G3FAX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("g3fax"), None, &[]);
+    /// `image/gif`
+    pub const GIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("gif"), None, &[]);
+    /// `image/heic`
+    pub const HEIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("heic"), None, &[]);
+    /// `image/heic-sequence`
+    pub const HEIC_SEQUENCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("heic-sequence"), None, &[]);
+    /// `image/heif`
+    pub const HEIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("heif"), None, &[]);
+    /// `image/heif-sequence`
+    pub const HEIF_SEQUENCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("heif-sequence"), None, &[]);
+    /// `image/hej2k`
+    pub const HEJ2K: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("hej2k"), None, &[]);
+    /// `image/hsj2`
+    pub const HSJ2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("hsj2"), None, &[]);
+    /// `image/ief`
+    pub const IEF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("ief"), None, &[]);
+    /// `image/jls`
+    pub const JLS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jls"), None, &[]);
+    /// `image/jp2`
+    pub const JP2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jp2"), None, &[]);
+    /// `image/jpeg`
+    pub const JPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jpeg"), None, &[]);
+    /// `image/jph`
+    pub const JPH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jph"), None, &[]);
+    /// `image/jphc`
+    pub const JPHC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jphc"), None, &[]);
+    /// `image/jpm`
+    pub const JPM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jpm"), None, &[]);
+    /// `image/jpx`
+    pub const JPX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jpx"), None, &[]);
+    /// `image/jxl`
+    pub const JXL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxl"), None, &[]);
+    /// `image/jxr`
+    pub const JXR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxr"), None, &[]);
+    /// `image/jxrA`
+    pub const JXR_A: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxrA"), None, &[]);
+    /// `image/jxrS`
+    pub const JXR_S: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxrS"), None, &[]);
+    /// `image/jxs`
+    pub const JXS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxs"), None, &[]);
+    /// `image/jxsc`
+    pub const JXSC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxsc"), None, &[]);
+    /// `image/jxsi`
+    pub const JXSI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxsi"), None, &[]);
+    /// `image/jxss`
+    pub const JXSS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("jxss"), None, &[]);
+    /// `image/ktx`
+    pub const KTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("ktx"), None, &[]);
+    /// `image/ktx2`
+    pub const KTX2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("ktx2"), None, &[]);
+    /// `image/naplps`
+    pub const NAPLPS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("naplps"), None, &[]);
+    /// `image/png`
+    pub const PNG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("png"), None, &[]);
+    /// `image/prs.btif`
+    pub const PRS_BTIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("prs.btif"), None, &[]);
+    /// `image/prs.pti`
+    pub const PRS_PTI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("prs.pti"), None, &[]);
+    /// `image/pwg-raster`
+    pub const PWG_RASTER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("pwg-raster"), None, &[]);
+    /// `image/svg+xml`
+    pub const SVG_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("svg"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `image/t38`
+    pub const T38: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("t38"), None, &[]);
+    /// `image/tiff`
+    pub const TIFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("tiff"), None, &[]);
+    /// `image/tiff-fx`
+    pub const TIFF_FX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("tiff-fx"), None, &[]);
+    /// `image/vnd.adobe.photoshop`
+    pub const VND_ADOBE_PHOTOSHOP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.adobe.photoshop"), None, &[]);
+    /// `image/vnd.airzip.accelerator.azv`
+    pub const VND_AIRZIP_ACCELERATOR_AZV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.airzip.accelerator.azv"), None, &[]);
+    /// `image/vnd.cns.inf2`
+    pub const VND_CNS_INF2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.cns.inf2"), None, &[]);
+    /// `image/vnd.dece.graphic`
+    pub const VND_DECE_GRAPHIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.dece.graphic"), None, &[]);
+    /// `image/vnd.djvu`
+    pub const VND_DJVU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.djvu"), None, &[]);
+    /// `image/vnd.dvb.subtitle`
+    pub const VND_DVB_SUBTITLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.dvb.subtitle"), None, &[]);
+    /// `image/vnd.dwg`
+    pub const VND_DWG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.dwg"), None, &[]);
+    /// `image/vnd.dxf`
+    pub const VND_DXF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.dxf"), None, &[]);
+    /// `image/vnd.fastbidsheet`
+    pub const VND_FASTBIDSHEET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.fastbidsheet"), None, &[]);
+    /// `image/vnd.fpx`
+    pub const VND_FPX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.fpx"), None, &[]);
+    /// `image/vnd.fst`
+    pub const VND_FST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.fst"), None, &[]);
+    /// `image/vnd.fujixerox.edmics-mmr`
+    pub const VND_FUJIXEROX_EDMICS_MMR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.fujixerox.edmics-mmr"), None, &[]);
+    /// `image/vnd.fujixerox.edmics-rlc`
+    pub const VND_FUJIXEROX_EDMICS_RLC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.fujixerox.edmics-rlc"), None, &[]);
+    /// `image/vnd.globalgraphics.pgb`
+    pub const VND_GLOBALGRAPHICS_PGB: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.globalgraphics.pgb"), None, &[]);
+    /// `image/vnd.microsoft.icon`
+    pub const VND_MICROSOFT_ICON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.microsoft.icon"), None, &[]);
+    /// `image/vnd.mix`
+    pub const VND_MIX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.mix"), None, &[]);
+    /// `image/vnd.ms-modi`
+    pub const VND_MS_MODI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.ms-modi"), None, &[]);
+    /// `image/vnd.net-fpx`
+    pub const VND_NET_FPX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.net-fpx"), None, &[]);
+    /// `image/vnd.pco.b16`
+    pub const VND_PCO_B16: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.pco.b16"), None, &[]);
+    /// `image/vnd.radiance`
+    pub const VND_RADIANCE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.radiance"), None, &[]);
+    /// `image/vnd.sealed.png`
+    pub const VND_SEALED_PNG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.sealed.png"), None, &[]);
+    /// `image/vnd.sealedmedia.softseal.gif`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_GIF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.gif"), None, &[]);
+    /// `image/vnd.sealedmedia.softseal.jpg`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_JPG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.jpg"), None, &[]);
+    /// `image/vnd.svf`
+    pub const VND_SVF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.svf"), None, &[]);
+    /// `image/vnd.tencent.tap`
+    pub const VND_TENCENT_TAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.tencent.tap"), None, &[]);
+    /// `image/vnd.valve.source.texture`
+    pub const VND_VALVE_SOURCE_TEXTURE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.valve.source.texture"), None, &[]);
+    /// `image/vnd.wap.wbmp`
+    pub const VND_WAP_WBMP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.wap.wbmp"), None, &[]);
+    /// `image/vnd.xiff`
+    pub const VND_XIFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.xiff"), None, &[]);
+    /// `image/vnd.zbrush.pcx`
+    pub const VND_ZBRUSH_PCX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("vnd.zbrush.pcx"), None, &[]);
+    /// `image/webp`
+    pub const WEBP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("webp"), None, &[]);
+    /// `image/wmf`
+    pub const WMF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("image"), crate::Name::new_unchecked("wmf"), None, &[]);
+}
+
+/// Media types with the `message` top-level type.
+pub mod message {
+    /// `message/bhttp`
+    pub const BHTTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("bhttp"), None, &[]);
+    /// `message/CPIM`
+    pub const CPIM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("CPIM"), None, &[]);
+    /// `message/delivery-status`
+    pub const DELIVERY_STATUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("delivery-status"), None, &[]);
+    /// `message/disposition-notification`
+    pub const DISPOSITION_NOTIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("disposition-notification"), None, &[]);
+    /// `message/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `message/external-body`
+    pub const EXTERNAL_BODY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("external-body"), None, &[]);
+    /// `message/feedback-report`
+    pub const FEEDBACK_REPORT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("feedback-report"), None, &[]);
+    /// `message/global`
+    pub const GLOBAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("global"), None, &[]);
+    /// `message/global-delivery-status`
+    pub const GLOBAL_DELIVERY_STATUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("global-delivery-status"), None, &[]);
+    /// `message/global-disposition-notification`
+    pub const GLOBAL_DISPOSITION_NOTIFICATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("global-disposition-notification"), None, &[]);
+    /// `message/global-headers`
+    pub const GLOBAL_HEADERS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("global-headers"), None, &[]);
+    /// `message/http`
+    pub const HTTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("http"), None, &[]);
+    /// `message/imdn+xml`
+    pub const IMDN_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("imdn"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `message/partial`
+    pub const PARTIAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("partial"), None, &[]);
+    /// `message/rfc822`
+    pub const RFC822: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("rfc822"), None, &[]);
+    /// `message/s-http`
+    pub const S_HTTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("s-http"), None, &[]);
+    /// `message/sip`
+    pub const SIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("sip"), None, &[]);
+    /// `message/sipfrag`
+    pub const SIPFRAG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("sipfrag"), None, &[]);
+    /// `message/tracking-status`
+    pub const TRACKING_STATUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("tracking-status"), None, &[]);
+    /// `message/vnd.wfa.wsc`
+    pub const VND_WFA_WSC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("message"), crate::Name::new_unchecked("vnd.wfa.wsc"), None, &[]);
+}
+
+/// Media types with the `model` top-level type.
+pub mod model {
+    /// `model/3mf`
+    pub const _3MF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("3mf"), None, &[]);
+    /// `model/e57`
+    pub const E57: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("e57"), None, &[]);
+    /// `model/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `model/gltf+json`
+    pub const GLTF_PLUS_JSON: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("gltf"), Some(crate::Name::new_unchecked("json")), &[]);
+    /// `model/gltf-binary`
+    pub const GLTF_BINARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("gltf-binary"), None, &[]);
+    /// `model/iges`
+    pub const IGES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("iges"), None, &[]);
+    /// `model/JT`
+    pub const JT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("JT"), None, &[]);
+    /// `model/mesh`
+    pub const MESH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("mesh"), None, &[]);
+    /// `model/mtl`
+    pub const MTL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("mtl"), None, &[]);
+    /// `model/obj`
+    pub const OBJ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("obj"), None, &[]);
+    /// `model/prc`
+    pub const PRC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("prc"), None, &[]);
+    /// `model/step`
+    pub const STEP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("step"), None, &[]);
+    /// `model/step+xml`
+    pub const STEP_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("step"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `model/step+zip`
+    pub const STEP_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("step"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `model/step-xml+zip`
+    pub const STEP_XML_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("step-xml"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `model/stl`
+    pub const STL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("stl"), None, &[]);
+    /// `model/u3d`
+    pub const U3D: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("u3d"), None, &[]);
+    /// `model/vnd.cld`
+    pub const VND_CLD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.cld"), None, &[]);
+    /// `model/vnd.collada+xml`
+    pub const VND_COLLADA_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.collada"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `model/vnd.dwf`
+    pub const VND_DWF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.dwf"), None, &[]);
+    /// `model/vnd.flatland.3dml`
+    pub const VND_FLATLAND_3DML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.flatland.3dml"), None, &[]);
+    /// `model/vnd.gdl`
+    pub const VND_GDL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.gdl"), None, &[]);
+    /// `model/vnd.gs-gdl`
+    pub const VND_GS_GDL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.gs-gdl"), None, &[]);
+    /// `model/vnd.gtw`
+    pub const VND_GTW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.gtw"), None, &[]);
+    /// `model/vnd.moml+xml`
+    pub const VND_MOML_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.moml"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `model/vnd.mts`
+    pub const VND_MTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.mts"), None, &[]);
+    /// `model/vnd.opengex`
+    pub const VND_OPENGEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.opengex"), None, &[]);
+    /// `model/vnd.parasolid.transmit.binary`
+    pub const VND_PARASOLID_TRANSMIT_BINARY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.parasolid.transmit.binary"), None, &[]);
+    /// `model/vnd.parasolid.transmit.text`
+    pub const VND_PARASOLID_TRANSMIT_TEXT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.parasolid.transmit.text"), None, &[]);
+    /// `model/vnd.pytha.pyox`
+    pub const VND_PYTHA_PYOX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.pytha.pyox"), None, &[]);
+    /// `model/vnd.rosette.annotated-data-model`
+    pub const VND_ROSETTE_ANNOTATED_DATA_MODEL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.rosette.annotated-data-model"), None, &[]);
+    /// `model/vnd.sap.vds`
+    pub const VND_SAP_VDS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.sap.vds"), None, &[]);
+    /// `model/vnd.usda`
+    pub const VND_USDA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.usda"), None, &[]);
+    /// `model/vnd.usdz+zip`
+    pub const VND_USDZ_PLUS_ZIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.usdz"), Some(crate::Name::new_unchecked("zip")), &[]);
+    /// `model/vnd.valve.source.compiled-map`
+    pub const VND_VALVE_SOURCE_COMPILED_MAP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.valve.source.compiled-map"), None, &[]);
+    /// `model/vnd.vtu`
+    pub const VND_VTU: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vnd.vtu"), None, &[]);
+    /// `model/vrml`
+    pub const VRML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("vrml"), None, &[]);
+    /// `model/x3d+fastinfoset`
+    pub const X3D_PLUS_FASTINFOSET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("x3d"), Some(crate::Name::new_unchecked("fastinfoset")), &[]);
+    /// `model/x3d+xml`
+    pub const X3D_PLUS_XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("x3d"), Some(crate::Name::new_unchecked("xml")), &[]);
+    /// `model/x3d-vrml`
+    pub const X3D_VRML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("model"), crate::Name::new_unchecked("x3d-vrml"), None, &[]);
+}
+
+/// Media types with the `multipart` top-level type.
+pub mod multipart {
+    /// `multipart/alternative`
+    pub const ALTERNATIVE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("alternative"), None, &[]);
+    /// `multipart/appledouble`
+    pub const APPLEDOUBLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("appledouble"), None, &[]);
+    /// `multipart/byteranges`
+    pub const BYTERANGES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("byteranges"), None, &[]);
+    /// `multipart/digest`
+    pub const DIGEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("digest"), None, &[]);
+    /// `multipart/encrypted`
+    pub const ENCRYPTED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("encrypted"), None, &[]);
+    /// `multipart/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `multipart/form-data`
+    pub const FORM_DATA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("form-data"), None, &[]);
+    /// `multipart/header-set`
+    pub const HEADER_SET: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("header-set"), None, &[]);
+    /// `multipart/mixed`
+    pub const MIXED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("mixed"), None, &[]);
+    /// `multipart/multilingual`
+    pub const MULTILINGUAL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("multilingual"), None, &[]);
+    /// `multipart/parallel`
+    pub const PARALLEL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("parallel"), None, &[]);
+    /// `multipart/related`
+    pub const RELATED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("related"), None, &[]);
+    /// `multipart/report`
+    pub const REPORT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("report"), None, &[]);
+    /// `multipart/signed`
+    pub const SIGNED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("signed"), None, &[]);
+    /// `multipart/vnd.bint.med-plus`
+    pub const VND_BINT_MED_PLUS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("vnd.bint.med-plus"), None, &[]);
+    /// `multipart/voice-message`
+    pub const VOICE_MESSAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("multipart"), crate::Name::new_unchecked("voice-message"), None, &[]);
+}
+
+/// Media types with the `text` top-level type.
+pub mod text {
+    /// `text/1d-interleaved-parityfec`
+    pub const _1D_INTERLEAVED_PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("1d-interleaved-parityfec"), None, &[]);
+    /// `text/cache-manifest`
+    pub const CACHE_MANIFEST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("cache-manifest"), None, &[]);
+    /// `text/calendar`
+    pub const CALENDAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("calendar"), None, &[]);
+    /// `text/cql`
+    pub const CQL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("cql"), None, &[]);
+    /// `text/cql-extension`
+    pub const CQL_EXTENSION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("cql-extension"), None, &[]);
+    /// `text/cql-identifier`
+    pub const CQL_IDENTIFIER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("cql-identifier"), None, &[]);
+    /// `text/css`
+    pub const CSS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("css"), None, &[]);
+    /// `text/csv`
+    pub const CSV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("csv"), None, &[]);
+    /// `text/csv-schema`
+    pub const CSV_SCHEMA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("csv-schema"), None, &[]);
+    /// `text/dns`
+    pub const DNS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("dns"), None, &[]);
+    /// `text/encaprtp`
+    pub const ENCAPRTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("encaprtp"), None, &[]);
+    /// `text/enriched`
+    pub const ENRICHED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("enriched"), None, &[]);
+    /// `text/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `text/fhirpath`
+    pub const FHIRPATH: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("fhirpath"), None, &[]);
+    /// `text/flexfec`
+    pub const FLEXFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("flexfec"), None, &[]);
+    /// `text/fwdred`
+    pub const FWDRED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("fwdred"), None, &[]);
+    /// `text/gff3`
+    pub const GFF3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("gff3"), None, &[]);
+    /// `text/grammar-ref-list`
+    pub const GRAMMAR_REF_LIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("grammar-ref-list"), None, &[]);
+    /// `text/hl7v2`
+    pub const HL7V2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("hl7v2"), None, &[]);
+    /// `text/html`
+    pub const HTML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("html"), None, &[]);
+    /// `text/javascript`
+    pub const JAVASCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("javascript"), None, &[]);
+    /// `text/jcr-cnd`
+    pub const JCR_CND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("jcr-cnd"), None, &[]);
+    /// `text/markdown`
+    pub const MARKDOWN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("markdown"), None, &[]);
+    /// `text/mizar`
+    pub const MIZAR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("mizar"), None, &[]);
+    /// `text/n3`
+    pub const N3: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("n3"), None, &[]);
+    /// `text/parameters`
+    pub const PARAMETERS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("parameters"), None, &[]);
+    /// `text/parityfec`
+    pub const PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("parityfec"), None, &[]);
+    /// `text/plain`
+    pub const PLAIN: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("plain"), None, &[]);
+    /// `text/provenance-notation`
+    pub const PROVENANCE_NOTATION: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("provenance-notation"), None, &[]);
+    /// `text/prs.fallenstein.rst`
+    pub const PRS_FALLENSTEIN_RST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("prs.fallenstein.rst"), None, &[]);
+    /// `text/prs.lines.tag`
+    pub const PRS_LINES_TAG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("prs.lines.tag"), None, &[]);
+    /// `text/prs.prop.logic`
+    pub const PRS_PROP_LOGIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("prs.prop.logic"), None, &[]);
+    /// `text/raptorfec`
+    pub const RAPTORFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("raptorfec"), None, &[]);
+    /// `text/RED`
+    pub const RED: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("RED"), None, &[]);
+    /// `text/rfc822-headers`
+    pub const RFC822_HEADERS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("rfc822-headers"), None, &[]);
+    /// `text/rtf`
+    pub const RTF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("rtf"), None, &[]);
+    /// `text/rtp-enc-aescm128`
+    pub const RTP_ENC_AESCM128: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("rtp-enc-aescm128"), None, &[]);
+    /// `text/rtploopback`
+    pub const RTPLOOPBACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("rtploopback"), None, &[]);
+    /// `text/rtx`
+    pub const RTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("rtx"), None, &[]);
+    /// `text/SGML`
+    pub const SGML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("SGML"), None, &[]);
+    /// `text/shaclc`
+    pub const SHACLC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("shaclc"), None, &[]);
+    /// `text/shex`
+    pub const SHEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("shex"), None, &[]);
+    /// `text/spdx`
+    pub const SPDX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("spdx"), None, &[]);
+    /// `text/strings`
+    pub const STRINGS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("strings"), None, &[]);
+    /// `text/t140`
+    pub const T140: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("t140"), None, &[]);
+    /// `text/tab-separated-values`
+    pub const TAB_SEPARATED_VALUES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("tab-separated-values"), None, &[]);
+    /// `text/texmacs`
+    pub const TEXMACS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("texmacs"), None, &[]);
+    /// `text/troff`
+    pub const TROFF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("troff"), None, &[]);
+    /// `text/turtle`
+    pub const TURTLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("turtle"), None, &[]);
+    /// `text/ulpfec`
+    pub const ULPFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("ulpfec"), None, &[]);
+    /// `text/uri-list`
+    pub const URI_LIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("uri-list"), None, &[]);
+    /// `text/vcard`
+    pub const VCARD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vcard"), None, &[]);
+    /// `text/vnd.a`
+    pub const VND_A: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.a"), None, &[]);
+    /// `text/vnd.abc`
+    pub const VND_ABC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.abc"), None, &[]);
+    /// `text/vnd.ascii-art`
+    pub const VND_ASCII_ART: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.ascii-art"), None, &[]);
+    /// `text/vnd.curl`
+    pub const VND_CURL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.curl"), None, &[]);
+    /// `text/vnd.debian.copyright`
+    pub const VND_DEBIAN_COPYRIGHT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.debian.copyright"), None, &[]);
+    /// `text/vnd.DMClientScript`
+    pub const VND_DMCLIENT_SCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.DMClientScript"), None, &[]);
+    /// `text/vnd.dvb.subtitle`
+    pub const VND_DVB_SUBTITLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.dvb.subtitle"), None, &[]);
+    /// `text/vnd.esmertec.theme-descriptor`
+    pub const VND_ESMERTEC_THEME_DESCRIPTOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.esmertec.theme-descriptor"), None, &[]);
+    /// `text/vnd.exchangeable`
+    pub const VND_EXCHANGEABLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.exchangeable"), None, &[]);
+    /// `text/vnd.familysearch.gedcom`
+    pub const VND_FAMILYSEARCH_GEDCOM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.familysearch.gedcom"), None, &[]);
+    /// `text/vnd.ficlab.flt`
+    pub const VND_FICLAB_FLT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.ficlab.flt"), None, &[]);
+    /// `text/vnd.fly`
+    pub const VND_FLY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.fly"), None, &[]);
+    /// `text/vnd.fmi.flexstor`
+    pub const VND_FMI_FLEXSTOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.fmi.flexstor"), None, &[]);
+    /// `text/vnd.gml`
+    pub const VND_GML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.gml"), None, &[]);
+    /// `text/vnd.graphviz`
+    pub const VND_GRAPHVIZ: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.graphviz"), None, &[]);
+    /// `text/vnd.hans`
+    pub const VND_HANS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.hans"), None, &[]);
+    /// `text/vnd.hgl`
+    pub const VND_HGL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.hgl"), None, &[]);
+    /// `text/vnd.in3d.3dml`
+    pub const VND_IN3D_3DML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.in3d.3dml"), None, &[]);
+    /// `text/vnd.in3d.spot`
+    pub const VND_IN3D_SPOT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.in3d.spot"), None, &[]);
+    /// `text/vnd.IPTC.NewsML`
+    pub const VND_IPTC_NEWS_ML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.IPTC.NewsML"), None, &[]);
+    /// `text/vnd.IPTC.NITF`
+    pub const VND_IPTC_NITF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.IPTC.NITF"), None, &[]);
+    /// `text/vnd.latex-z`
+    pub const VND_LATEX_Z: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.latex-z"), None, &[]);
+    /// `text/vnd.motorola.reflex`
+    pub const VND_MOTOROLA_REFLEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.motorola.reflex"), None, &[]);
+    /// `text/vnd.ms-mediapackage`
+    pub const VND_MS_MEDIAPACKAGE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.ms-mediapackage"), None, &[]);
+    /// `text/vnd.net2phone.commcenter.command`
+    pub const VND_NET2PHONE_COMMCENTER_COMMAND: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.net2phone.commcenter.command"), None, &[]);
+    /// `text/vnd.radisys.msml-basic-layout`
+    pub const VND_RADISYS_MSML_BASIC_LAYOUT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.radisys.msml-basic-layout"), None, &[]);
+    /// `text/vnd.senx.warpscript`
+    pub const VND_SENX_WARPSCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.senx.warpscript"), None, &[]);
+    /// `text/vnd.sosi`
+    pub const VND_SOSI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.sosi"), None, &[]);
+    /// `text/vnd.sun.j2me.app-descriptor`
+    pub const VND_SUN_J2ME_APP_DESCRIPTOR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.sun.j2me.app-descriptor"), None, &[]);
+    /// `text/vnd.trolltech.linguist`
+    pub const VND_TROLLTECH_LINGUIST: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.trolltech.linguist"), None, &[]);
+    /// `text/vnd.wap.si`
+    pub const VND_WAP_SI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.wap.si"), None, &[]);
+    /// `text/vnd.wap.sl`
+    pub const VND_WAP_SL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.wap.sl"), None, &[]);
+    /// `text/vnd.wap.wml`
+    pub const VND_WAP_WML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.wap.wml"), None, &[]);
+    /// `text/vnd.wap.wmlscript`
+    pub const VND_WAP_WMLSCRIPT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vnd.wap.wmlscript"), None, &[]);
+    /// `text/vtt`
+    pub const VTT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("vtt"), None, &[]);
+    /// `text/wgsl`
+    pub const WGSL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("wgsl"), None, &[]);
+    /// `text/xml`
+    pub const XML: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("xml"), None, &[]);
+    /// `text/xml-dtd`
+    pub const XML_DTD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("xml-dtd"), None, &[]);
+    /// `text/xml-external-parsed-entity`
+    pub const XML_EXTERNAL_PARSED_ENTITY: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("text"), crate::Name::new_unchecked("xml-external-parsed-entity"), None, &[]);
+}
+
+/// Media types with the `video` top-level type.
+pub mod video {
+    /// `video/1d-interleaved-parityfec`
+    pub const _1D_INTERLEAVED_PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("1d-interleaved-parityfec"), None, &[]);
+    /// `video/3gpp`
+    pub const _3GPP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("3gpp"), None, &[]);
+    /// `video/3gpp-tt`
+    pub const _3GPP_TT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("3gpp-tt"), None, &[]);
+    /// `video/3gpp2`
+    pub const _3GPP2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("3gpp2"), None, &[]);
+    /// `video/annodex`
+    pub const ANNODEX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("annodex"), None, &[]);
+    /// `video/AV1`
+    pub const AV1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("AV1"), None, &[]);
+    /// `video/BMPEG`
+    pub const BMPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("BMPEG"), None, &[]);
+    /// `video/BT656`
+    pub const BT656: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("BT656"), None, &[]);
+    /// `video/CelB`
+    pub const CEL_B: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("CelB"), None, &[]);
+    /// `video/DV`
+    pub const DV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("DV"), None, &[]);
+    /// `video/encaprtp`
+    pub const ENCAPRTP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("encaprtp"), None, &[]);
+    /// `video/example`
+    pub const EXAMPLE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("example"), None, &[]);
+    /// `video/FFV1`
+    pub const FFV1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("FFV1"), None, &[]);
+    /// `video/flexfec`
+    pub const FLEXFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("flexfec"), None, &[]);
+    /// `video/fli`
+    pub const FLI: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("fli"), None, &[]);
+    /// `video/gl`
+    pub const GL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("gl"), None, &[]);
+    /// `video/H261`
+    pub const H261: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H261"), None, &[]);
+    /// `video/H263`
+    pub const H263: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H263"), None, &[]);
+    /// `video/H263-1998`
+    pub const H263_1998: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H263-1998"), None, &[]);
+    /// `video/H263-2000`
+    pub const H263_2000: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H263-2000"), None, &[]);
+    /// `video/H264`
+    pub const H264: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H264"), None, &[]);
+    /// `video/H264-RCDO`
+    pub const H264_RCDO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H264-RCDO"), None, &[]);
+    /// `video/H264-SVC`
+    pub const H264_SVC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H264-SVC"), None, &[]);
+    /// `video/H265`
+    pub const H265: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H265"), None, &[]);
+    /// `video/H266`
+    pub const H266: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("H266"), None, &[]);
+    /// `video/iso.segment`
+    pub const ISO_SEGMENT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("iso.segment"), None, &[]);
+    /// `video/JPEG`
+    pub const JPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("JPEG"), None, &[]);
+    /// `video/jpeg2000`
+    pub const JPEG2000: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("jpeg2000"), None, &[]);
+    /// `video/jxsv`
+    pub const JXSV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("jxsv"), None, &[]);
+    /// `video/mj2`
+    pub const MJ2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("mj2"), None, &[]);
+    /// `video/MP1S`
+    pub const MP1S: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("MP1S"), None, &[]);
+    /// `video/MP2P`
+    pub const MP2P: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("MP2P"), None, &[]);
+    /// `video/MP2T`
+    pub const MP2T: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("MP2T"), None, &[]);
+    /// `video/mp4`
+    pub const MP4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("mp4"), None, &[]);
+    /// `video/MP4V-ES`
+    pub const MP4V_ES: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("MP4V-ES"), None, &[]);
+    /// `video/mpeg`
+    pub const MPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("mpeg"), None, &[]);
+    /// `video/mpeg4-generic`
+    pub const MPEG4_GENERIC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("mpeg4-generic"), None, &[]);
+    /// `video/MPV`
+    pub const MPV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("MPV"), None, &[]);
+    /// `video/nv`
+    pub const NV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("nv"), None, &[]);
+    /// `video/ogg`
+    pub const OGG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("ogg"), None, &[]);
+    /// `video/parityfec`
+    pub const PARITYFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("parityfec"), None, &[]);
+    /// `video/pointer`
+    pub const POINTER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("pointer"), None, &[]);
+    /// `video/quicktime`
+    pub const QUICKTIME: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("quicktime"), None, &[]);
+    /// `video/raptorfec`
+    pub const RAPTORFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("raptorfec"), None, &[]);
+    /// `video/raw`
+    pub const RAW: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("raw"), None, &[]);
+    /// `video/rtp-enc-aescm128`
+    pub const RTP_ENC_AESCM128: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("rtp-enc-aescm128"), None, &[]);
+    /// `video/rtploopback`
+    pub const RTPLOOPBACK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("rtploopback"), None, &[]);
+    /// `video/rtx`
+    pub const RTX: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("rtx"), None, &[]);
+    /// `video/scip`
+    pub const SCIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("scip"), None, &[]);
+    /// `video/smpte291`
+    pub const SMPTE291: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("smpte291"), None, &[]);
+    /// `video/SMPTE292M`
+    pub const SMPTE292M: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("SMPTE292M"), None, &[]);
+    /// `video/ulpfec`
+    pub const ULPFEC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("ulpfec"), None, &[]);
+    /// `video/vc1`
+    pub const VC1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vc1"), None, &[]);
+    /// `video/vc2`
+    pub const VC2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vc2"), None, &[]);
+    /// `video/vnd.CCTV`
+    pub const VND_CCTV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.CCTV"), None, &[]);
+    /// `video/vnd.dece.hd`
+    pub const VND_DECE_HD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.hd"), None, &[]);
+    /// `video/vnd.dece.mobile`
+    pub const VND_DECE_MOBILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.mobile"), None, &[]);
+    /// `video/vnd.dece.mp4`
+    pub const VND_DECE_MP4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.mp4"), None, &[]);
+    /// `video/vnd.dece.pd`
+    pub const VND_DECE_PD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.pd"), None, &[]);
+    /// `video/vnd.dece.sd`
+    pub const VND_DECE_SD: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.sd"), None, &[]);
+    /// `video/vnd.dece.video`
+    pub const VND_DECE_VIDEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dece.video"), None, &[]);
+    /// `video/vnd.directv.mpeg`
+    pub const VND_DIRECTV_MPEG: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.directv.mpeg"), None, &[]);
+    /// `video/vnd.directv.mpeg-tts`
+    pub const VND_DIRECTV_MPEG_TTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.directv.mpeg-tts"), None, &[]);
+    /// `video/vnd.dlna.mpeg-tts`
+    pub const VND_DLNA_MPEG_TTS: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dlna.mpeg-tts"), None, &[]);
+    /// `video/vnd.dvb.file`
+    pub const VND_DVB_FILE: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.dvb.file"), None, &[]);
+    /// `video/vnd.fvt`
+    pub const VND_FVT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.fvt"), None, &[]);
+    /// `video/vnd.hns.video`
+    pub const VND_HNS_VIDEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.hns.video"), None, &[]);
+    /// `video/vnd.iptvforum.1dparityfec-1010`
+    pub const VND_IPTVFORUM_1DPARITYFEC_1010: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.1dparityfec-1010"), None, &[]);
+    /// `video/vnd.iptvforum.1dparityfec-2005`
+    pub const VND_IPTVFORUM_1DPARITYFEC_2005: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.1dparityfec-2005"), None, &[]);
+    /// `video/vnd.iptvforum.2dparityfec-1010`
+    pub const VND_IPTVFORUM_2DPARITYFEC_1010: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.2dparityfec-1010"), None, &[]);
+    /// `video/vnd.iptvforum.2dparityfec-2005`
+    pub const VND_IPTVFORUM_2DPARITYFEC_2005: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.2dparityfec-2005"), None, &[]);
+    /// `video/vnd.iptvforum.ttsavc`
+    pub const VND_IPTVFORUM_TTSAVC: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.ttsavc"), None, &[]);
+    /// `video/vnd.iptvforum.ttsmpeg2`
+    pub const VND_IPTVFORUM_TTSMPEG2: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.iptvforum.ttsmpeg2"), None, &[]);
+    /// `video/vnd.motorola.video`
+    pub const VND_MOTOROLA_VIDEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.motorola.video"), None, &[]);
+    /// `video/vnd.motorola.videop`
+    pub const VND_MOTOROLA_VIDEOP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.motorola.videop"), None, &[]);
+    /// `video/vnd.mpegurl`
+    pub const VND_MPEGURL: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.mpegurl"), None, &[]);
+    /// `video/vnd.ms-playready.media.pyv`
+    pub const VND_MS_PLAYREADY_MEDIA_PYV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.ms-playready.media.pyv"), None, &[]);
+    /// `video/vnd.nokia.interleaved-multimedia`
+    pub const VND_NOKIA_INTERLEAVED_MULTIMEDIA: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.nokia.interleaved-multimedia"), None, &[]);
+    /// `video/vnd.nokia.mp4vr`
+    pub const VND_NOKIA_MP4VR: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.nokia.mp4vr"), None, &[]);
+    /// `video/vnd.nokia.videovoip`
+    pub const VND_NOKIA_VIDEOVOIP: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.nokia.videovoip"), None, &[]);
+    /// `video/vnd.objectvideo`
+    pub const VND_OBJECTVIDEO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.objectvideo"), None, &[]);
+    /// `video/vnd.radgamettools.bink`
+    pub const VND_RADGAMETTOOLS_BINK: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.radgamettools.bink"), None, &[]);
+    /// `video/vnd.radgamettools.smacker`
+    pub const VND_RADGAMETTOOLS_SMACKER: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.radgamettools.smacker"), None, &[]);
+    /// `video/vnd.sealed.mpeg1`
+    pub const VND_SEALED_MPEG1: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.sealed.mpeg1"), None, &[]);
+    /// `video/vnd.sealed.mpeg4`
+    pub const VND_SEALED_MPEG4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.sealed.mpeg4"), None, &[]);
+    /// `video/vnd.sealed.swf`
+    pub const VND_SEALED_SWF: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.sealed.swf"), None, &[]);
+    /// `video/vnd.sealedmedia.softseal.mov`
+    pub const VND_SEALEDMEDIA_SOFTSEAL_MOV: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.sealedmedia.softseal.mov"), None, &[]);
+    /// `video/vnd.uvvu.mp4`
+    pub const VND_UVVU_MP4: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.uvvu.mp4"), None, &[]);
+    /// `video/vnd.vivo`
+    pub const VND_VIVO: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.vivo"), None, &[]);
+    /// `video/vnd.youtube.yt`
+    pub const VND_YOUTUBE_YT: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("vnd.youtube.yt"), None, &[]);
+    /// `video/VP8`
+    pub const VP8: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("VP8"), None, &[]);
+    /// `video/VP9`
+    pub const VP9: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("VP9"), None, &[]);
+    /// `video/webm`
+    pub const WEBM: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked("video"), crate::Name::new_unchecked("webm"), None, &[]);
+}
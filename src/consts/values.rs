@@ -14,8 +14,12 @@ pub const EUC_KR: crate::Value = crate::Value::new_unchecked("EUC-KR");
 pub const FIXED: crate::Value = crate::Value::new_unchecked("fixed");
 /// `flowed`
 pub const FLOWED: crate::Value = crate::Value::new_unchecked("flowed");
+/// `GB18030`
+pub const GB18030: crate::Value = crate::Value::new_unchecked("GB18030");
 /// `GB2312`
 pub const GB2312: crate::Value = crate::Value::new_unchecked("GB2312");
+/// `GBK`
+pub const GBK: crate::Value = crate::Value::new_unchecked("GBK");
 /// `ISO-2022-JP`
 pub const ISO_2022_JP: crate::Value = crate::Value::new_unchecked("ISO-2022-JP");
 /// `ISO-2022-JP-2`
@@ -26,6 +30,14 @@ pub const ISO_2022_KR: crate::Value = crate::Value::new_unchecked("ISO-2022-KR")
 pub const ISO_8859_1: crate::Value = crate::Value::new_unchecked("ISO-8859-1");
 /// `ISO-8859-10`
 pub const ISO_8859_10: crate::Value = crate::Value::new_unchecked("ISO-8859-10");
+/// `ISO-8859-13`
+pub const ISO_8859_13: crate::Value = crate::Value::new_unchecked("ISO-8859-13");
+/// `ISO-8859-14`
+pub const ISO_8859_14: crate::Value = crate::Value::new_unchecked("ISO-8859-14");
+/// `ISO-8859-15`
+pub const ISO_8859_15: crate::Value = crate::Value::new_unchecked("ISO-8859-15");
+/// `ISO-8859-16`
+pub const ISO_8859_16: crate::Value = crate::Value::new_unchecked("ISO-8859-16");
 /// `ISO-8859-2`
 pub const ISO_8859_2: crate::Value = crate::Value::new_unchecked("ISO-8859-2");
 /// `ISO-8859-3`
@@ -58,7 +70,19 @@ pub const NO: crate::Value = crate::Value::new_unchecked("no");
 pub const SHIFT_JIS: crate::Value = crate::Value::new_unchecked("Shift_JIS");
 /// `US-ASCII`
 pub const US_ASCII: crate::Value = crate::Value::new_unchecked("US-ASCII");
+/// `UTF-16`
+pub const UTF_16: crate::Value = crate::Value::new_unchecked("UTF-16");
+/// `UTF-16BE`
+pub const UTF_16BE: crate::Value = crate::Value::new_unchecked("UTF-16BE");
+/// `UTF-16LE`
+pub const UTF_16LE: crate::Value = crate::Value::new_unchecked("UTF-16LE");
 /// `UTF-8`
 pub const UTF_8: crate::Value = crate::Value::new_unchecked("UTF-8");
+/// `windows-1250`
+pub const WINDOWS_1250: crate::Value = crate::Value::new_unchecked("windows-1250");
+/// `windows-1251`
+pub const WINDOWS_1251: crate::Value = crate::Value::new_unchecked("windows-1251");
+/// `windows-1252`
+pub const WINDOWS_1252: crate::Value = crate::Value::new_unchecked("windows-1252");
 /// `yes`
 pub const YES: crate::Value = crate::Value::new_unchecked("yes");
@@ -0,0 +1,188 @@
+use super::{media_type_buf::*, media_type_list::*, names::*, params::*, parse::*};
+use std::str::FromStr;
+
+/// The effective media type resolved from multiple `Content-Type` header values.
+///
+/// The resolution follows the [WHATWG Fetch Standard](https://fetch.spec.whatwg.org/#content-type-header):
+///
+/// - Each header value is split at commas outside quoted strings.
+/// - Invalid candidates and `*/*` are ignored.
+/// - The last valid candidate wins.
+/// - If the last candidate has no `charset` parameter,
+///   the `charset` of a preceding candidate with the same essence is inherited.
+///
+/// ```
+/// use mediatype::{names::*, ReadParams, ResolvedContentType};
+///
+/// let resolved = ResolvedContentType::resolve([
+///     "text/plain; charset=UTF-8",
+///     "*/*, text/plain",
+/// ]);
+///
+/// let media_type = resolved.media_type().unwrap();
+/// assert_eq!(media_type.to_string(), "text/plain; charset=UTF-8");
+/// assert_eq!(resolved.discarded(), ["text/plain; charset=UTF-8", "*/*"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedContentType<'a> {
+    media_type: Option<MediaTypeBuf>,
+    discarded: Vec<&'a str>,
+}
+
+impl<'a> ResolvedContentType<'a> {
+    /// Resolves the effective media type from `Content-Type` header values.
+    pub fn resolve<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut candidates = Vec::new();
+        let mut current: Option<(usize, MediaTypeBuf)> = None;
+        let mut charset: Option<MediaTypeBuf> = None;
+
+        for value in values {
+            let mut list = MediaTypeList::new(value);
+            while let Some((_, candidate)) = list.next_str() {
+                candidates.push(candidate.trim_end_matches(is_ows));
+                let media_type = match MediaTypeBuf::from_str(candidate) {
+                    Ok(media_type)
+                        if !(media_type.ty() == _STAR
+                            && media_type.subty() == _STAR
+                            && media_type.suffix().is_none()) =>
+                    {
+                        media_type
+                    }
+                    _ => continue,
+                };
+
+                let same_essence = current
+                    .as_ref()
+                    .is_some_and(|(_, prev)| prev.essence() == media_type.essence());
+                let has_charset = media_type.get_param(CHARSET).is_some();
+
+                let media_type = if !same_essence {
+                    charset = has_charset.then(|| media_type.clone());
+                    media_type
+                } else if let Some(value) = charset
+                    .as_ref()
+                    .and_then(|t| t.get_param(CHARSET))
+                    .filter(|_| !has_charset)
+                {
+                    let mut inherited = media_type.to_ref();
+                    inherited.set_param(CHARSET, value);
+                    MediaTypeBuf::from(inherited)
+                } else {
+                    media_type
+                };
+
+                current = Some((candidates.len() - 1, media_type));
+            }
+        }
+
+        let (media_type, discarded) = match current {
+            Some((index, media_type)) => {
+                candidates.remove(index);
+                (Some(media_type), candidates)
+            }
+            None => (None, candidates),
+        };
+        Self {
+            media_type,
+            discarded,
+        }
+    }
+
+    /// Returns the effective media type.
+    ///
+    /// If there is no valid candidate, returns `None`.
+    #[must_use]
+    pub const fn media_type(&self) -> Option<&MediaTypeBuf> {
+        self.media_type.as_ref()
+    }
+
+    /// Returns the effective media type, consuming `self`.
+    #[must_use]
+    pub fn into_media_type(self) -> Option<MediaTypeBuf> {
+        self.media_type
+    }
+
+    /// Returns the candidates that do not form the effective media type, in the original order.
+    ///
+    /// This includes invalid candidates, `*/*`, and candidates overridden by later ones.
+    #[must_use]
+    pub fn discarded(&self) -> &[&'a str] {
+        &self.discarded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(values: &[&str]) -> Option<String> {
+        ResolvedContentType::resolve(values.iter().copied())
+            .into_media_type()
+            .map(|t| t.to_string())
+    }
+
+    #[test]
+    fn resolve_last_wins() {
+        assert_eq!(resolve(&[]), None);
+        assert_eq!(resolve(&["text/plain"]), Some("text/plain".into()));
+        assert_eq!(
+            resolve(&["text/plain", "text/html"]),
+            Some("text/html".into())
+        );
+        assert_eq!(
+            resolve(&["text/plain, text/html"]),
+            Some("text/html".into())
+        );
+        assert_eq!(
+            resolve(&["text/html", "*/*", "invalid"]),
+            Some("text/html".into())
+        );
+        assert_eq!(resolve(&["*/*", "invalid"]), None);
+    }
+
+    #[test]
+    fn resolve_charset() {
+        assert_eq!(
+            resolve(&["text/plain;charset=gbk", "text/plain"]),
+            Some("text/plain; charset=gbk".into())
+        );
+        assert_eq!(
+            resolve(&["text/plain;charset=gbk", "TEXT/PLAIN"]),
+            Some("TEXT/PLAIN; charset=gbk".into())
+        );
+        assert_eq!(
+            resolve(&["text/plain;charset=gbk", "text/html", "text/plain"]),
+            Some("text/plain".into())
+        );
+        assert_eq!(
+            resolve(&["text/plain;charset=gbk", "text/plain;charset=utf-8"]),
+            Some("text/plain; charset=utf-8".into())
+        );
+        assert_eq!(
+            resolve(&[
+                "text/plain;charset=gbk",
+                "text/plain;charset=utf-8",
+                "text/plain"
+            ]),
+            Some("text/plain; charset=gbk".into())
+        );
+        assert_eq!(
+            resolve(&["text/html;charset=gbk;a=\"b,c\", text/html;x=y"]),
+            Some("text/html; x=y; charset=gbk".into())
+        );
+    }
+
+    #[test]
+    fn discarded() {
+        let resolved =
+            ResolvedContentType::resolve(["text/plain, invalid", "*/*, text/html;charset=gbk"]);
+        assert_eq!(
+            resolved.media_type().map(MediaTypeBuf::as_str),
+            Some("text/html;charset=gbk")
+        );
+        assert_eq!(resolved.discarded(), ["text/plain", "invalid", "*/*"]);
+    }
+}
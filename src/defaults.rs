@@ -0,0 +1,88 @@
+use super::{media_type::*, media_type_buf::*, name::*, names::*, params::*, value::*, values::*};
+
+// The default values of the parameters defined by the specifications of the media types.
+const DEFAULTS: &[(Name, Name, Name, Value)] = &[
+    // RFC 2046, Section 4.1.2
+    (TEXT, PLAIN, CHARSET, US_ASCII),
+    // RFC 3676, Section 4
+    (TEXT, PLAIN, FORMAT, FIXED),
+    (TEXT, PLAIN, DELSP, NO),
+];
+
+impl<'a> MediaType<'a> {
+    /// Returns the parameter value, or the default value defined by the specification
+    /// of the media type if the parameter is missing.
+    ///
+    /// The known defaults are `charset=US-ASCII`
+    /// ([RFC 2046](https://www.rfc-editor.org/rfc/rfc2046#section-4.1.2)),
+    /// `format=fixed` and `delsp=no`
+    /// ([RFC 3676](https://www.rfc-editor.org/rfc/rfc3676#section-4)) for `text/plain`.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaType};
+    /// let media_type = MediaType::parse("text/plain; format=flowed").unwrap();
+    /// assert_eq!(media_type.param_or_default(CHARSET), Some(US_ASCII));
+    /// assert_eq!(media_type.param_or_default(FORMAT), Some(FLOWED));
+    ///
+    /// let media_type = MediaType::parse("text/html").unwrap();
+    /// assert_eq!(media_type.param_or_default(CHARSET), None);
+    /// ```
+    #[must_use]
+    pub fn param_or_default(&self, name: Name) -> Option<Value<'_>> {
+        self.get_param(name)
+            .or_else(|| default_param(self.ty, self.subty, self.suffix, name))
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the parameter value, or the default value defined by the specification
+    /// of the media type if the parameter is missing.
+    ///
+    /// See [`MediaType::param_or_default`](./struct.MediaType.html#method.param_or_default)
+    /// for the known defaults.
+    #[must_use]
+    pub fn param_or_default(&self, name: Name) -> Option<Value<'_>> {
+        self.get_param(name)
+            .or_else(|| default_param(self.ty(), self.subty(), self.suffix(), name))
+    }
+}
+
+fn default_param(
+    ty: Name,
+    subty: Name,
+    suffix: Option<Name>,
+    name: Name,
+) -> Option<Value<'static>> {
+    if suffix.is_some() {
+        return None;
+    }
+    DEFAULTS
+        .iter()
+        .find(|&&(t, s, n, _)| t == ty && s == subty && n == name)
+        .map(|&(.., value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn param_or_default() {
+        let param = |s: &str, name| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            media_type.param_or_default(name).map(|v| v.to_string())
+        };
+        assert_eq!(param("text/plain", CHARSET), Some("US-ASCII".into()));
+        assert_eq!(param("TEXT/Plain", CHARSET), Some("US-ASCII".into()));
+        assert_eq!(
+            param("text/plain; charset=UTF-8", CHARSET),
+            Some("UTF-8".into())
+        );
+        assert_eq!(param("text/plain", FORMAT), Some("fixed".into()));
+        assert_eq!(param("text/plain", DELSP), Some("no".into()));
+        assert_eq!(param("text/plain", BOUNDARY), None);
+        assert_eq!(param("text/plain+xml", CHARSET), None);
+        assert_eq!(param("text/markdown", CHARSET), None);
+    }
+}
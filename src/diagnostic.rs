@@ -0,0 +1,167 @@
+use super::{media_type::*, media_type_buf::*, name::*, names::*, params::*, top_level::*};
+use std::fmt;
+
+// The recommended maximum length of a name. (RFC 6838, Section 4.2)
+const RECOMMENDED_NAME_LENGTH: usize = 64;
+
+/// A problem found by [`MediaType::validate`](./struct.MediaType.html#method.validate).
+///
+/// Unlike [`MediaTypeError`](./enum.MediaTypeError.html), these do not make the media type invalid,
+/// but may cause interoperability problems.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Diagnostic {
+    /// A name other than a parameter value contains uppercase letters.
+    NonCanonicalCase,
+    /// The subtype uses the deprecated `x-` prefix. ([RFC 6648](https://www.rfc-editor.org/rfc/rfc6648))
+    LegacyPrefix,
+    /// The top-level type is not registered.
+    UnknownTopLevelType,
+    /// The multipart media type has no `boundary` parameter.
+    MissingBoundary,
+    /// The `boundary` parameter does not match the syntax of
+    /// [RFC 2046](https://www.rfc-editor.org/rfc/rfc2046#section-5.1.1).
+    InvalidBoundary,
+    /// A name is longer than 64 characters. ([RFC 6838](https://www.rfc-editor.org/rfc/rfc6838#section-4.2))
+    LongName,
+    /// A parameter name appears more than once.
+    DuplicateParam,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::NonCanonicalCase => "Non-canonical case",
+            Self::LegacyPrefix => "Legacy x- prefix",
+            Self::UnknownTopLevelType => "Unknown top-level type",
+            Self::MissingBoundary => "Missing boundary",
+            Self::InvalidBoundary => "Invalid boundary",
+            Self::LongName => "Long name",
+            Self::DuplicateParam => "Duplicate param",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl<'a> MediaType<'a> {
+    /// Checks the media type for problems which do not make it invalid.
+    ///
+    /// Each kind of [`Diagnostic`](./enum.Diagnostic.html) is reported at most once,
+    /// in the order of the variants.
+    ///
+    /// ```
+    /// # use mediatype::{Diagnostic, MediaType};
+    /// let media_type = MediaType::parse("Multipart/x-mixed-replace").unwrap();
+    /// assert_eq!(
+    ///     media_type.validate(),
+    ///     [
+    ///         Diagnostic::NonCanonicalCase,
+    ///         Diagnostic::LegacyPrefix,
+    ///         Diagnostic::MissingBoundary
+    ///     ]
+    /// );
+    ///
+    /// let media_type = MediaType::parse("application/json").unwrap();
+    /// assert!(media_type.validate().is_empty());
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let names = || {
+            [self.ty, self.subty]
+                .into_iter()
+                .chain(self.suffix)
+                .chain(self.params().map(|(name, _)| name))
+        };
+        let is_multipart = self.ty == MULTIPART;
+        let boundary = self.get_param(BOUNDARY);
+        [
+            (
+                Diagnostic::NonCanonicalCase,
+                names().any(|name| name.as_str().bytes().any(|b| b.is_ascii_uppercase())),
+            ),
+            (Diagnostic::LegacyPrefix, self.has_unregistered_prefix()),
+            (
+                Diagnostic::UnknownTopLevelType,
+                self.ty != _STAR && matches!(self.top_level(), TopLevelType::Other(_)),
+            ),
+            (
+                Diagnostic::MissingBoundary,
+                is_multipart && boundary.is_none(),
+            ),
+            (
+                Diagnostic::InvalidBoundary,
+                is_multipart && boundary.is_some() && self.boundary().is_none(),
+            ),
+            (
+                Diagnostic::LongName,
+                names().any(|name| name.as_str().len() > RECOMMENDED_NAME_LENGTH),
+            ),
+            (Diagnostic::DuplicateParam, has_duplicate_params(self)),
+        ]
+        .into_iter()
+        .filter_map(|(diagnostic, found)| found.then_some(diagnostic))
+        .collect()
+    }
+}
+
+impl MediaTypeBuf {
+    /// Checks the media type for problems which do not make it invalid.
+    ///
+    /// See [`MediaType::validate`](./struct.MediaType.html#method.validate) for the details.
+    #[must_use]
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        self.to_ref().validate()
+    }
+}
+
+fn has_duplicate_params(media_type: &MediaType) -> bool {
+    let names = media_type
+        .params()
+        .map(|(name, _)| name)
+        .collect::<Vec<Name>>();
+    names
+        .iter()
+        .enumerate()
+        .any(|(i, name)| names[..i].contains(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn validate() {
+        let validate = |s: &str| MediaTypeBuf::from_str(s).unwrap().validate();
+        assert_eq!(validate("text/plain; charset=UTF-8"), []);
+        assert_eq!(validate("*/*"), []);
+        assert_eq!(validate("multipart/form-data; boundary=abc"), []);
+        assert_eq!(
+            validate("text/plain; Charset=UTF-8"),
+            [Diagnostic::NonCanonicalCase]
+        );
+        assert_eq!(
+            validate("image/X-icon"),
+            [Diagnostic::NonCanonicalCase, Diagnostic::LegacyPrefix]
+        );
+        assert_eq!(
+            validate("chemical/x-pdb"),
+            [Diagnostic::LegacyPrefix, Diagnostic::UnknownTopLevelType]
+        );
+        assert_eq!(validate("multipart/mixed"), [Diagnostic::MissingBoundary]);
+        assert_eq!(
+            validate("multipart/mixed; boundary=\"trailing \""),
+            [Diagnostic::InvalidBoundary]
+        );
+        assert_eq!(
+            validate(&format!("application/{}", "a".repeat(65))),
+            [Diagnostic::LongName]
+        );
+        assert_eq!(validate(&format!("application/{}", "a".repeat(64))), []);
+        assert_eq!(
+            validate("text/plain; charset=UTF-8; charset=UTF-8"),
+            [Diagnostic::DuplicateParam]
+        );
+        assert_eq!(Diagnostic::MissingBoundary.to_string(), "Missing boundary");
+    }
+}
@@ -0,0 +1,123 @@
+use super::{alias::*, media_type::*, media_type_buf::*, media_type_map::*};
+
+/// A database of equivalent media types.
+///
+/// Each registered essence is mapped to a canonical essence,
+/// and the essences not registered fall back to the built-in aliases of
+/// [`MediaType::normalize_alias`](./struct.MediaType.html#method.normalize_alias).
+/// The names are compared case-insensitively and the parameters are ignored.
+///
+/// ```
+/// use mediatype::{media_type, Equivalences, MediaType};
+///
+/// let mut db = Equivalences::new();
+/// db.insert_group(
+///     &media_type!(APPLICATION / JSON),
+///     ["application/vnd.acme.event", "application/vnd.acme.batch"].map(|s| MediaType::parse(s).unwrap()),
+/// );
+///
+/// let event = MediaType::parse("application/vnd.acme.event; version=2").unwrap();
+/// let batch = MediaType::parse("application/vnd.acme.batch").unwrap();
+/// assert!(db.is_equivalent(&event, &batch));
+/// assert!(db.is_equivalent(&event, &media_type!(APPLICATION / JSON)));
+/// assert!(db.is_equivalent(&media_type!(TEXT / XML), &media_type!(APPLICATION / XML)));
+/// assert!(!db.is_equivalent(&event, &media_type!(TEXT / PLAIN)));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Equivalences {
+    canonical: MediaTypeMap<MediaTypeBuf>,
+}
+
+impl Equivalences {
+    /// Constructs a database with only the built-in aliases.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `alias` as equivalent to `canonical`.
+    ///
+    /// The registration overrides the built-in aliases.
+    /// `canonical` is not resolved further, so it should not be an alias itself.
+    ///
+    /// Returns `true` if `alias` has not been registered yet.
+    pub fn insert(&mut self, alias: &MediaType, canonical: &MediaType) -> bool {
+        let canonical = MediaTypeBuf::from(canonical.essence());
+        self.canonical.insert(alias, canonical).is_none()
+    }
+
+    /// Registers all the media types in `aliases` as equivalent to `canonical`.
+    pub fn insert_group<'a>(
+        &mut self,
+        canonical: &MediaType,
+        aliases: impl IntoIterator<Item = MediaType<'a>>,
+    ) {
+        for alias in aliases {
+            self.insert(&alias, canonical);
+        }
+    }
+
+    /// Unregisters `alias`.
+    ///
+    /// Returns `true` if `alias` has been registered.
+    pub fn remove(&mut self, alias: &MediaType) -> bool {
+        self.canonical.remove(alias).is_some()
+    }
+
+    /// Returns the canonical essence of `media_type`.
+    ///
+    /// ```
+    /// # use mediatype::{media_type, Equivalences, MediaType};
+    /// let db = Equivalences::new();
+    /// let media_type = MediaType::parse("image/jpg; q=1").unwrap();
+    /// assert_eq!(db.canonical_essence(&media_type), media_type!(IMAGE / JPEG));
+    /// ```
+    #[must_use]
+    pub fn canonical_essence<'s, 'b: 's>(&'s self, media_type: &MediaType<'b>) -> MediaType<'s> {
+        if let Some(canonical) = self.canonical.get(media_type) {
+            return canonical.essence();
+        }
+        let mut essence: MediaType<'s> =
+            MediaType::from_parts(media_type.ty, media_type.subty, media_type.suffix, &[]);
+        if let Some((ty, subty)) = alias_target(essence.ty, essence.subty) {
+            essence.ty = ty;
+            essence.subty = subty;
+        }
+        essence
+    }
+
+    /// Returns `true` if `a` and `b` have the same canonical essence.
+    #[must_use]
+    pub fn is_equivalent(&self, a: &MediaType, b: &MediaType) -> bool {
+        self.canonical_essence(a)
+            .eq_essence(self.canonical_essence(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::names::*;
+
+    #[test]
+    fn equivalences() {
+        let parse = |s| MediaType::parse(s).unwrap();
+        let mut db = Equivalences::new();
+        assert!(db.is_equivalent(&parse("text/xml"), &parse("Application/XML")));
+        assert!(db.is_equivalent(&parse("text/xml"), &parse("text/xml; charset=UTF-8")));
+        assert!(!db.is_equivalent(&parse("text/xml"), &parse("text/html")));
+
+        let json = MediaType::new(APPLICATION, JSON);
+        assert!(db.insert(&parse("application/x-acme"), &json));
+        assert!(!db.insert(&parse("Application/X-Acme"), &json));
+        db.insert_group(&json, [parse("text/json"), parse("text/xml")]);
+        assert!(db.is_equivalent(&parse("application/x-acme"), &parse("text/json")));
+        assert!(db.is_equivalent(&parse("text/xml"), &json));
+        assert!(!db.is_equivalent(&parse("text/xml"), &parse("application/xml")));
+        assert!(!db.is_equivalent(&parse("application/x-acme+zip"), &json));
+
+        assert!(db.remove(&parse("text/xml")));
+        assert!(!db.remove(&parse("text/xml")));
+        assert!(db.is_equivalent(&parse("text/xml"), &parse("application/xml")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::{error, fmt};
+use std::{error, fmt, ops::Range};
 
 /// Media-type format error.
 #[non_exhaustive]
@@ -16,6 +16,10 @@ pub enum MediaTypeError {
     InvalidParamName,
     /// An invalid parameter value is detected.
     InvalidParamValue,
+    /// The media type does not fit in a fixed-capacity buffer.
+    CapacityExceeded,
+    /// A parameter name appears more than once.
+    DuplicateParam,
 }
 
 impl fmt::Display for MediaTypeError {
@@ -27,9 +31,95 @@ impl fmt::Display for MediaTypeError {
             Self::InvalidParams => "Invalid params",
             Self::InvalidParamName => "Invalid param name",
             Self::InvalidParamValue => "Invalid param value",
+            Self::CapacityExceeded => "Capacity exceeded",
+            Self::DuplicateParam => "Duplicate param",
         };
         f.write_str(msg)
     }
 }
 
 impl error::Error for MediaTypeError {}
+
+/// Media-type parse error with the offending token and its location in the input.
+///
+/// ```
+/// use mediatype::{MediaType, MediaTypeError};
+///
+/// let err = MediaType::parse("text/plain; charset=UTF-8; f\u{f6}rmat=flowed").unwrap_err();
+/// assert_eq!(err.kind(), MediaTypeError::InvalidParamName);
+/// assert_eq!(err.token(), "f\u{f6}rmat");
+/// assert_eq!(err.span(), 27..34);
+/// assert_eq!(err.to_string(), "Invalid param name \"f\u{f6}rmat\" at 27..34");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ParseError {
+    kind: MediaTypeError,
+    token: Box<str>,
+    span: Range<usize>,
+}
+
+impl ParseError {
+    pub(crate) fn new(kind: MediaTypeError, token: &str, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            token: token.into(),
+            span,
+        }
+    }
+
+    // Constructs an error for the token at `span` in `s`.
+    pub(crate) fn at(kind: MediaTypeError, s: &str, span: Range<usize>) -> Self {
+        Self::new(kind, s.get(span.clone()).unwrap_or_default(), span)
+    }
+
+    pub(crate) fn offset(self, offset: usize) -> Self {
+        Self {
+            span: self.span.start + offset..self.span.end + offset,
+            ..self
+        }
+    }
+
+    /// Returns the kind of the error.
+    #[must_use]
+    pub const fn kind(&self) -> MediaTypeError {
+        self.kind
+    }
+
+    /// Returns the offending token.
+    ///
+    /// Invalid UTF-8 sequences are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    #[must_use]
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Returns the byte range of the offending token in the input.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Returns the byte position where the offending token starts.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.span.start
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} at {}..{}",
+            self.kind, self.token, self.span.start, self.span.end
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+impl From<ParseError> for MediaTypeError {
+    fn from(err: ParseError) -> Self {
+        err.kind
+    }
+}
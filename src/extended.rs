@@ -0,0 +1,190 @@
+use super::{name::*, value::*};
+use std::borrow::Cow;
+
+// Decodes a parameter value, following RFC 2231 and RFC 8187.
+//
+// `name*` takes precedence over continuations `name*0`, `name*1*`, ...,
+// which take precedence over `name`. Extended values which fail to be decoded are ignored.
+pub fn decode_param<'a, I>(params: I, name: Name) -> Option<Cow<'a, str>>
+where
+    I: Iterator<Item = (Name<'a>, Value<'a>)>,
+{
+    let name = name.as_str();
+    let mut plain = None;
+    let mut extended = None;
+    let mut segments = Vec::new();
+
+    for (key, value) in params {
+        let key = key.as_str();
+        let rest = match key.get(..name.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(name) => &key[name.len()..],
+            _ => continue,
+        };
+        match rest.strip_prefix('*') {
+            None if rest.is_empty() => plain = Some(value),
+            Some("") => extended = Some(value),
+            Some(rest) => {
+                let (index, encoded) = match rest.strip_suffix('*') {
+                    Some(index) => (index, true),
+                    None => (rest, false),
+                };
+                if let Some(index) = parse_index(index) {
+                    segments.push((index, encoded, value));
+                }
+            }
+            None => (),
+        }
+    }
+
+    extended
+        .and_then(|value| decode_extended(value.as_str()))
+        .or_else(|| decode_continuations(segments))
+        .or_else(|| plain.map(|value| value.unquoted_str()))
+}
+
+fn parse_index(s: &str) -> Option<usize> {
+    if s == "0" || (!s.starts_with('0') && !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+fn decode_extended<'a>(s: &str) -> Option<Cow<'a, str>> {
+    let (charset, s) = s.split_once('\'')?;
+    let (_language, s) = s.split_once('\'')?;
+    let mut bytes = Vec::new();
+    percent_decode(s, &mut bytes)?;
+    decode_charset(charset, bytes).map(Cow::Owned)
+}
+
+fn decode_continuations<'a>(mut segments: Vec<(usize, bool, Value)>) -> Option<Cow<'a, str>> {
+    segments.sort_by_key(|&(index, ..)| index);
+    segments.dedup_by_key(|&mut (index, ..)| index);
+
+    let mut charset = None;
+    let mut bytes = Vec::new();
+    for (i, (index, encoded, value)) in segments.into_iter().enumerate() {
+        if index != i {
+            break;
+        }
+        if encoded {
+            let mut s = value.as_str();
+            if index == 0 {
+                let (cs, rest) = s.split_once('\'')?;
+                let (_language, rest) = rest.split_once('\'')?;
+                charset = Some(cs);
+                s = rest;
+            }
+            percent_decode(s, &mut bytes)?;
+        } else {
+            bytes.extend_from_slice(value.unquoted_str().as_bytes());
+        }
+    }
+
+    if bytes.is_empty() && charset.is_none() {
+        return None;
+    }
+    decode_charset(charset.unwrap_or("utf-8"), bytes).map(Cow::Owned)
+}
+
+fn percent_decode(s: &str, out: &mut Vec<u8>) -> Option<()> {
+    let mut bytes = s.bytes();
+    while let Some(b) = bytes.next() {
+        if b == b'%' {
+            let hi = char::from(bytes.next()?).to_digit(16)?;
+            let lo = char::from(bytes.next()?).to_digit(16)?;
+            out.push((hi * 16 + lo) as u8);
+        } else {
+            out.push(b);
+        }
+    }
+    Some(())
+}
+
+fn decode_charset(charset: &str, bytes: Vec<u8>) -> Option<String> {
+    if charset.eq_ignore_ascii_case("utf-8") {
+        String::from_utf8(bytes).ok()
+    } else if charset.eq_ignore_ascii_case("us-ascii") {
+        if bytes.is_ascii() {
+            String::from_utf8(bytes).ok()
+        } else {
+            None
+        }
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+        Some(bytes.into_iter().map(char::from).collect())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{media_type::*, params::*};
+
+    fn decode(s: &str) -> Option<String> {
+        let media_type = MediaType::parse(s).unwrap();
+        decode_param(media_type.params(), Name::new("title").unwrap()).map(Cow::into_owned)
+    }
+
+    #[test]
+    fn extended() {
+        assert_eq!(
+            decode("text/plain; title*=utf-8''%E2%82%AC"),
+            Some("€".into())
+        );
+        assert_eq!(
+            decode("text/plain; TITLE*=UTF-8'en'%e2%82%ac%20rates"),
+            Some("€ rates".into())
+        );
+        assert_eq!(
+            decode("text/plain; title=fallback; title*=iso-8859-1''%A3"),
+            Some("£".into())
+        );
+        assert_eq!(
+            decode("text/plain; title=fallback; title*=koi8-r''%C1"),
+            Some("fallback".into())
+        );
+        assert_eq!(
+            decode("text/plain; title=fallback; title*=utf-8''%E2%82"),
+            Some("fallback".into())
+        );
+        assert_eq!(
+            decode("text/plain; title=fallback; title*=utf-8''%E"),
+            Some("fallback".into())
+        );
+        assert_eq!(decode("text/plain; title*=us-ascii''%A3"), None);
+    }
+
+    #[test]
+    fn continuations() {
+        assert_eq!(
+            decode(
+                "message/external-body; \
+                 title*0*=us-ascii'en'This%20is%20even%20more%20; \
+                 title*1*=%2A%2A%2Afun%2A%2A%2A%20; \
+                 title*2=\"isn't it!\""
+            ),
+            Some("This is even more ***fun*** isn't it!".into())
+        );
+        assert_eq!(
+            decode("text/plain; title*1=b; title*0=a"),
+            Some("ab".into())
+        );
+        assert_eq!(decode("text/plain; title*0=a; title*2=c"), Some("a".into()));
+        assert_eq!(
+            decode("text/plain; title=fallback; title*1=b"),
+            Some("fallback".into())
+        );
+        assert_eq!(decode("text/plain; title*01=a"), None);
+    }
+
+    #[test]
+    fn plain() {
+        assert_eq!(decode("text/plain"), None);
+        assert_eq!(decode("text/plain; title=a"), Some("a".into()));
+        assert_eq!(decode("text/plain; title=\"a b\""), Some("a b".into()));
+        assert_eq!(decode("text/plain; titles=a"), None);
+    }
+}
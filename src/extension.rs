@@ -0,0 +1,299 @@
+#![cfg(feature = "extensions")]
+
+use super::{media_type::*, media_type_buf::*, name::*};
+use std::path::Path;
+
+const fn entry(
+    ty: &'static str,
+    subty: &'static str,
+    suffix: Option<&'static str>,
+    extensions: &'static [&'static str],
+) -> (MediaType<'static>, &'static [&'static str]) {
+    let suffix = match suffix {
+        Some(suffix) => Some(Name::new_unchecked(suffix)),
+        None => None,
+    };
+    (
+        MediaType::from_parts(
+            Name::new_unchecked(ty),
+            Name::new_unchecked(subty),
+            suffix,
+            &[],
+        ),
+        extensions,
+    )
+}
+
+/// Known media types and their file extensions. The first extension is the preferred one.
+static EXTENSIONS: &[(MediaType<'static>, &[&str])] = &[
+    entry("application", "atom", Some("xml"), &["atom"]),
+    entry("application", "epub", Some("zip"), &["epub"]),
+    entry("application", "gzip", None, &["gz"]),
+    entry("application", "java-archive", None, &["jar"]),
+    entry("application", "json", None, &["json"]),
+    entry("application", "ld", Some("json"), &["jsonld"]),
+    entry("application", "manifest", Some("json"), &["webmanifest"]),
+    entry("application", "msword", None, &["doc"]),
+    entry("application", "octet-stream", None, &["bin"]),
+    entry("application", "pdf", None, &["pdf"]),
+    entry("application", "rss", Some("xml"), &["rss"]),
+    entry("application", "rtf", None, &["rtf"]),
+    entry("application", "sql", None, &["sql"]),
+    entry("application", "toml", None, &["toml"]),
+    entry("application", "vnd.ms-excel", None, &["xls"]),
+    entry("application", "vnd.ms-powerpoint", None, &["ppt"]),
+    entry(
+        "application",
+        "vnd.oasis.opendocument.presentation",
+        None,
+        &["odp"],
+    ),
+    entry(
+        "application",
+        "vnd.oasis.opendocument.spreadsheet",
+        None,
+        &["ods"],
+    ),
+    entry("application", "vnd.oasis.opendocument.text", None, &["odt"]),
+    entry(
+        "application",
+        "vnd.openxmlformats-officedocument.presentationml.presentation",
+        None,
+        &["pptx"],
+    ),
+    entry(
+        "application",
+        "vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        None,
+        &["xlsx"],
+    ),
+    entry(
+        "application",
+        "vnd.openxmlformats-officedocument.wordprocessingml.document",
+        None,
+        &["docx"],
+    ),
+    entry("application", "vnd.rar", None, &["rar"]),
+    entry("application", "wasm", None, &["wasm"]),
+    entry("application", "x-7z-compressed", None, &["7z"]),
+    entry("application", "x-bzip2", None, &["bz2"]),
+    entry("application", "x-sh", None, &["sh"]),
+    entry("application", "x-tar", None, &["tar"]),
+    entry("application", "xhtml", Some("xml"), &["xhtml", "xht"]),
+    entry("application", "xml", None, &["xml"]),
+    entry("application", "yaml", None, &["yaml", "yml"]),
+    entry("application", "zip", None, &["zip"]),
+    entry("audio", "aac", None, &["aac"]),
+    entry("audio", "flac", None, &["flac"]),
+    entry("audio", "midi", None, &["mid", "midi"]),
+    entry("audio", "mp4", None, &["m4a"]),
+    entry("audio", "mpeg", None, &["mp3"]),
+    entry("audio", "ogg", None, &["oga", "ogg"]),
+    entry("audio", "opus", None, &["opus"]),
+    entry("audio", "wav", None, &["wav"]),
+    entry("audio", "webm", None, &["weba"]),
+    entry("font", "collection", None, &["ttc"]),
+    entry("font", "otf", None, &["otf"]),
+    entry("font", "ttf", None, &["ttf"]),
+    entry("font", "woff", None, &["woff"]),
+    entry("font", "woff2", None, &["woff2"]),
+    entry("image", "apng", None, &["apng"]),
+    entry("image", "avif", None, &["avif"]),
+    entry("image", "bmp", None, &["bmp"]),
+    entry("image", "gif", None, &["gif"]),
+    entry("image", "heic", None, &["heic"]),
+    entry("image", "jpeg", None, &["jpg", "jpeg", "jpe"]),
+    entry("image", "jxl", None, &["jxl"]),
+    entry("image", "png", None, &["png"]),
+    entry("image", "svg", Some("xml"), &["svg", "svgz"]),
+    entry("image", "tiff", None, &["tif", "tiff"]),
+    entry("image", "vnd.microsoft.icon", None, &["ico"]),
+    entry("image", "webp", None, &["webp"]),
+    entry("model", "gltf", Some("json"), &["gltf"]),
+    entry("model", "gltf-binary", None, &["glb"]),
+    entry("text", "calendar", None, &["ics"]),
+    entry("text", "css", None, &["css"]),
+    entry("text", "csv", None, &["csv"]),
+    entry("text", "html", None, &["html", "htm"]),
+    entry("text", "javascript", None, &["js", "mjs"]),
+    entry("text", "markdown", None, &["md", "markdown"]),
+    entry("text", "plain", None, &["txt", "text", "log"]),
+    entry("text", "tab-separated-values", None, &["tsv"]),
+    entry("text", "vcard", None, &["vcf"]),
+    entry("video", "mp4", None, &["mp4", "m4v"]),
+    entry("video", "mpeg", None, &["mpeg", "mpg"]),
+    entry("video", "ogg", None, &["ogv"]),
+    entry("video", "quicktime", None, &["mov"]),
+    entry("video", "webm", None, &["webm"]),
+    entry("video", "x-matroska", None, &["mkv"]),
+    entry("video", "x-msvideo", None, &["avi"]),
+];
+
+impl MediaType<'static> {
+    /// Returns the media type for a file extension.
+    ///
+    /// The extension is matched case-insensitively and must not include the leading dot.
+    ///
+    /// This method requires the `extensions` feature.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// assert_eq!(MediaType::from_extension("png"), Some(MediaType::new(IMAGE, PNG)));
+    /// assert_eq!(MediaType::from_extension("JPG"), Some(MediaType::new(IMAGE, JPEG)));
+    /// assert_eq!(MediaType::from_extension("unknown"), None);
+    /// ```
+    #[must_use]
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        EXTENSIONS
+            .iter()
+            .find(|(_, exts)| exts.iter().any(|e| e.eq_ignore_ascii_case(ext)))
+            .map(|(media_type, _)| media_type.clone())
+    }
+
+    /// Returns the media type for the extension of a file path.
+    ///
+    /// This method requires the `extensions` feature.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// assert_eq!(
+    ///     MediaType::from_path("assets/logo.svg"),
+    ///     Some(MediaType::from_parts(IMAGE, SVG, Some(XML), &[]))
+    /// );
+    /// assert_eq!(MediaType::from_path("README"), None);
+    /// ```
+    #[must_use]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        Self::from_extension(path.as_ref().extension()?.to_str()?)
+    }
+}
+
+fn extensions(ty: Name, subty: Name, suffix: Option<Name>) -> &'static [&'static str] {
+    EXTENSIONS
+        .iter()
+        .find(|(media_type, _)| {
+            media_type.ty == ty && media_type.subty == subty && media_type.suffix == suffix
+        })
+        .map_or(&[], |(_, exts)| exts)
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns the preferred file extension for the media type.
+    ///
+    /// Parameters are ignored. This method requires the `extensions` feature.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// assert_eq!(MediaType::new(IMAGE, JPEG).preferred_extension(), Some("jpg"));
+    /// assert_eq!(MediaType::new(IMAGE, _STAR).preferred_extension(), None);
+    /// ```
+    #[must_use]
+    pub fn preferred_extension(&self) -> Option<&'static str> {
+        self.extensions().first().copied()
+    }
+
+    /// Returns all known file extensions for the media type, the preferred one first.
+    ///
+    /// Parameters are ignored. This method requires the `extensions` feature.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// assert_eq!(MediaType::new(IMAGE, JPEG).extensions(), ["jpg", "jpeg", "jpe"]);
+    /// ```
+    #[must_use]
+    pub fn extensions(&self) -> &'static [&'static str] {
+        extensions(self.ty, self.subty, self.suffix)
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the preferred file extension for the media type.
+    ///
+    /// Parameters are ignored. This method requires the `extensions` feature.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "Text/HTML; charset=UTF-8".parse().unwrap();
+    /// assert_eq!(media_type.preferred_extension(), Some("html"));
+    /// ```
+    #[must_use]
+    pub fn preferred_extension(&self) -> Option<&'static str> {
+        self.extensions().first().copied()
+    }
+
+    /// Returns all known file extensions for the media type, the preferred one first.
+    ///
+    /// Parameters are ignored. This method requires the `extensions` feature.
+    #[must_use]
+    pub fn extensions(&self) -> &'static [&'static str] {
+        extensions(self.ty(), self.subty(), self.suffix())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::names::*;
+    use std::{collections::HashSet, str::FromStr};
+
+    #[test]
+    fn table() {
+        let mut seen = HashSet::new();
+        for (media_type, exts) in EXTENSIONS {
+            assert_eq!(
+                MediaTypeBuf::from_str(&media_type.to_string()).unwrap(),
+                *media_type
+            );
+            assert!(!exts.is_empty());
+            for ext in *exts {
+                assert_eq!(ext.to_ascii_lowercase(), *ext);
+                assert!(seen.insert(*ext), "duplicate extension: {}", ext);
+            }
+        }
+    }
+
+    #[test]
+    fn from_extension() {
+        assert_eq!(
+            MediaType::from_extension("html"),
+            Some(MediaType::new(TEXT, HTML))
+        );
+        assert_eq!(
+            MediaType::from_extension("HTM"),
+            Some(MediaType::new(TEXT, HTML))
+        );
+        assert_eq!(MediaType::from_extension(".html"), None);
+        assert_eq!(MediaType::from_extension(""), None);
+    }
+
+    #[test]
+    fn from_path() {
+        assert_eq!(
+            MediaType::from_path("archive.tar.gz"),
+            Some(MediaType::new(APPLICATION, GZIP))
+        );
+        assert_eq!(
+            MediaType::from_path(Path::new("/tmp/Photo.JPEG")),
+            Some(MediaType::new(IMAGE, JPEG))
+        );
+        assert_eq!(MediaType::from_path(".png"), None);
+        assert_eq!(MediaType::from_path("dir/"), None);
+    }
+
+    #[test]
+    fn extensions() {
+        for (media_type, exts) in EXTENSIONS {
+            assert_eq!(media_type.extensions(), *exts);
+            assert_eq!(
+                MediaType::from_extension(media_type.preferred_extension().unwrap()).as_ref(),
+                Some(media_type)
+            );
+        }
+
+        let svg = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap();
+        assert_eq!(svg.preferred_extension(), Some("svg"));
+        assert_eq!(svg.extensions(), ["svg", "svgz"]);
+        assert_eq!(MediaType::new(IMAGE, SVG).extensions(), [] as [&str; 0]);
+        assert_eq!(MediaType::new(_STAR, _STAR).preferred_extension(), None);
+    }
+}
@@ -0,0 +1,154 @@
+use super::{error::*, media_type::*, media_type_buf::*};
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+    str::FromStr,
+};
+
+/// A media type with the precomputed hash.
+///
+/// The case-insensitive hash of the media type is computed once on construction,
+/// and hashing the value only writes the precomputed `u64`.
+/// Comparisons check the hashes first, so unequal values are rejected quickly.
+/// It dereferences to [`MediaTypeBuf`].
+///
+/// ```
+/// use mediatype::HashedMediaType;
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+/// for s in ["text/html; charset=utf-8", "TEXT/HTML; CHARSET=utf-8", "image/png"] {
+///     seen.insert(s.parse::<HashedMediaType>().unwrap());
+/// }
+/// assert_eq!(seen.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashedMediaType {
+    hash: u64,
+    media_type: MediaTypeBuf,
+}
+
+impl HashedMediaType {
+    /// Constructs a `HashedMediaType` from a media type.
+    #[must_use]
+    pub fn new(media_type: MediaTypeBuf) -> Self {
+        let mut hasher = DefaultHasher::new();
+        media_type.hash(&mut hasher);
+        Self {
+            hash: hasher.finish(),
+            media_type,
+        }
+    }
+
+    /// Returns the precomputed hash.
+    ///
+    /// Equal media types have the same hash in the same build of the crate,
+    /// but the value is not guaranteed to be stable across versions.
+    #[must_use]
+    pub const fn hash_value(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the inner [`MediaTypeBuf`].
+    #[must_use]
+    pub fn into_inner(self) -> MediaTypeBuf {
+        self.media_type
+    }
+}
+
+impl Deref for HashedMediaType {
+    type Target = MediaTypeBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.media_type
+    }
+}
+
+impl AsRef<MediaTypeBuf> for HashedMediaType {
+    fn as_ref(&self) -> &MediaTypeBuf {
+        &self.media_type
+    }
+}
+
+impl From<MediaTypeBuf> for HashedMediaType {
+    fn from(media_type: MediaTypeBuf) -> Self {
+        Self::new(media_type)
+    }
+}
+
+impl From<MediaType<'_>> for HashedMediaType {
+    fn from(media_type: MediaType) -> Self {
+        Self::new(media_type.into())
+    }
+}
+
+impl From<HashedMediaType> for MediaTypeBuf {
+    fn from(media_type: HashedMediaType) -> Self {
+        media_type.media_type
+    }
+}
+
+impl FromStr for HashedMediaType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MediaTypeBuf::from_str(s).map(Self::new)
+    }
+}
+
+impl PartialEq for HashedMediaType {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.media_type == other.media_type
+    }
+}
+
+impl Eq for HashedMediaType {}
+
+impl PartialEq<MediaTypeBuf> for HashedMediaType {
+    fn eq(&self, other: &MediaTypeBuf) -> bool {
+        self.media_type == *other
+    }
+}
+
+impl PartialEq<MediaType<'_>> for HashedMediaType {
+    fn eq(&self, other: &MediaType) -> bool {
+        self.media_type == *other
+    }
+}
+
+impl Hash for HashedMediaType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl fmt::Display for HashedMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.media_type.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::names::*;
+
+    #[test]
+    fn hashed() {
+        let a = HashedMediaType::from_str("text/plain; charset=UTF-8; format=fixed").unwrap();
+        let b = HashedMediaType::from_str("TEXT/plain; format=fixed; Charset=\"UTF-8\"").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.hash_value(), b.hash_value());
+        assert_eq!(
+            a,
+            MediaTypeBuf::from_str("text/plain; charset=UTF-8; format=fixed").unwrap()
+        );
+
+        let c = HashedMediaType::from(MediaType::new(TEXT, PLAIN));
+        assert_ne!(a, c);
+        assert_eq!(c.subty(), PLAIN);
+        assert_eq!(c.into_inner().as_str(), "text/plain");
+    }
+}
@@ -1,8 +1,8 @@
 //! This crate provides two media type structs: [`MediaType`] and [`MediaTypeBuf`].
 //!
 //! - [`MediaType`] does not copy data during parsing
-//!     and borrows the original string. It is also const-constructible.
-//! - [`MediaTypeBuf`] is an owned  and immutable version of [`MediaType`].
+//!   and borrows the original string. It is also const-constructible.
+//! - [`MediaTypeBuf`] is an owned version of [`MediaType`].
 //!
 //! [`MadiaType`]: ./struct.MediaType.html
 //! [`MediaTypeBuf`]: ./struct.MediaTypeBuf.html
@@ -37,35 +37,106 @@
 //! assert_eq!(lower.ty(), "Text");
 //! assert_eq!(upper.subty(), "Plain");
 //! ```
+//!
+//! # Panics
+//!
+//! Parsing never panics on any input, and no function panics on values
+//! constructed by the validated constructors such as [`Name::new`], [`Value::new`]
+//! and the predefined constants.
+//!
+//! [`Name::new_unchecked`] skips the validation, so functions taking such names may panic
+//! if they are not valid. Fallible alternatives like [`MediaTypeBuf::try_from_parts`]
+//! are provided for these cases.
+//!
+//! [`Name::new`]: ./struct.Name.html#method.new
+//! [`Name::new_unchecked`]: ./struct.Name.html#method.new_unchecked
+//! [`Value::new`]: ./struct.Value.html#method.new
+//! [`MediaTypeBuf::try_from_parts`]: ./struct.MediaTypeBuf.html#method.try_from_parts
 
 #![forbid(unsafe_code)]
 #![forbid(clippy::all)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod accept;
+mod alias;
+mod cache;
+mod canonical;
+mod charset;
+mod codecs;
+mod const_parse;
 mod consts;
+mod content_type;
+mod defaults;
+mod diagnostic;
+mod equivalence;
 mod error;
+mod extended;
+mod extension;
+mod hashed;
 mod media_type;
 mod media_type_buf;
+mod media_type_inline;
 mod media_type_list;
+mod media_type_list_buf;
+mod media_type_map;
+mod media_type_set;
+mod multipart;
 mod name;
 mod params;
 mod parse;
+mod parse_options;
+mod qvalue;
 mod serde;
+mod shared;
+mod sniff;
+mod stream;
+mod suffix;
+mod top_level;
+mod tree;
 mod value;
+mod visit;
 
+#[cfg(feature = "serde")]
+pub use crate::serde::compact;
+pub use accept::*;
+pub use cache::*;
+pub use canonical::*;
+pub use charset::*;
+pub use codecs::*;
 pub use consts::*;
+pub use content_type::*;
+pub use diagnostic::*;
+pub use equivalence::*;
 pub use error::*;
+pub use hashed::*;
 pub use media_type::*;
 pub use media_type_buf::*;
+pub use media_type_inline::*;
 pub use media_type_list::*;
+pub use media_type_list_buf::*;
+pub use media_type_map::*;
+pub use media_type_set::*;
 pub use name::*;
 pub use params::*;
+pub use parse::Indices;
+pub use parse_options::*;
+pub use qvalue::*;
+pub use shared::*;
+#[cfg(feature = "sniff")]
+pub use sniff::*;
+pub use stream::*;
+pub use suffix::*;
+pub use top_level::*;
+pub use tree::*;
 pub use value::*;
+pub use visit::*;
 
 /// Convenient macro to construct a [`MediaType`].
 ///
 /// [`MadiaType`]: ./struct.MediaType.html
 ///
+/// The media type can be written with the constants in [`names`](./names/index.html)
+/// and [`values`](./values/index.html):
 ///
 /// ```
 /// # use mediatype::media_type;
@@ -81,8 +152,31 @@ pub use value::*;
 ///     "application/vnd.openstreetmap.data+xml"
 /// );
 /// ```
+///
+/// Or as a string literal, which is parsed and validated at compile time:
+///
+/// ```
+/// # use mediatype::{media_type, MediaType};
+/// const JSON_API: MediaType = media_type!("application/vnd.api+json; charset=utf-8");
+/// assert_eq!(JSON_API.to_string(), "application/vnd.api+json; charset=utf-8");
+/// ```
+///
+/// The literal must match the strict grammar of [`ParseOptions::strict`](./struct.ParseOptions.html#method.strict),
+/// so duplicate parameter names are rejected.
+///
+/// ```compile_fail
+/// # use mediatype::{media_type, MediaType};
+/// const TEXT: MediaType = media_type!("text/plain; charset=utf-8; Charset=utf-8");
+/// ```
 #[macro_export]
 macro_rules! media_type {
+    ($s:literal) => {{
+        const S: &str = $s;
+        const PARAMS: [($crate::Name<'static>, $crate::Value<'static>);
+            $crate::__private::count_params(S)] = $crate::__private::params(S);
+        const MEDIA_TYPE: $crate::MediaType<'static> = $crate::__private::media_type(S, &PARAMS);
+        MEDIA_TYPE
+    }};
     ($ty:ident / $prefix:ident $(:: $subty:ident)* $(;$name:ident = $value:ident)*) => {
         $crate::MediaType::from_parts(
             $crate::names::$ty,
@@ -100,3 +194,58 @@ macro_rules! media_type {
         )
     };
 }
+
+/// Constructs a [`Name`] from a string literal, which is validated at compile time.
+///
+/// [`Name`]: ./struct.Name.html
+///
+/// ```
+/// # use mediatype::{name, MediaType, Name, names::*};
+/// const EXAMPLE: Name = name!("vnd.example.app");
+/// assert_eq!(MediaType::new(APPLICATION, EXAMPLE).to_string(), "application/vnd.example.app");
+/// ```
+///
+/// Invalid names fail to compile.
+///
+/// ```compile_fail
+/// # use mediatype::{name, Name};
+/// const INVALID: Name = name!("-example");
+/// ```
+#[macro_export]
+macro_rules! name {
+    ($s:literal) => {{
+        const NAME: $crate::Name<'static> = $crate::__private::name($s);
+        NAME
+    }};
+}
+
+/// Constructs a [`Value`] from a string literal, which is validated at compile time.
+///
+/// Quoted strings are also accepted.
+///
+/// [`Value`]: ./struct.Value.html
+///
+/// ```
+/// # use mediatype::{value, Value};
+/// const TITLE: Value = value!("\"Hello world!\"");
+/// assert_eq!(TITLE.unquoted_str(), "Hello world!");
+/// ```
+///
+/// Invalid values fail to compile.
+///
+/// ```compile_fail
+/// # use mediatype::{value, Value};
+/// const INVALID: Value = value!("Hello world!");
+/// ```
+#[macro_export]
+macro_rules! value {
+    ($s:literal) => {{
+        const VALUE: $crate::Value<'static> = $crate::__private::value($s);
+        VALUE
+    }};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::const_parse::*;
+}
@@ -1,4 +1,7 @@
-use super::{error::*, media_type_buf::*, name::*, params::*, parse::*, value::*};
+use super::{
+    const_parse::*, error::*, media_type_buf::*, name::*, names::*, params::*, parse::*,
+    parse_options::*, value::*, visit::*,
+};
 use std::{
     borrow::Cow,
     collections::BTreeMap,
@@ -87,6 +90,41 @@ impl<'a> MediaType<'a> {
         }
     }
 
+    /// Constructs a `MediaType` with an optional suffix and parameters,
+    /// validating all the names and values.
+    ///
+    /// In a const context, an invalid media type is a compile error.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaType};
+    /// const TEXT_FLOWED: MediaType =
+    ///     MediaType::from_parts_checked(TEXT, PLAIN, None, &[(CHARSET, UTF_8), (FORMAT, FLOWED)]);
+    /// assert_eq!(
+    ///     TEXT_FLOWED.to_string(),
+    ///     "text/plain; charset=UTF-8; format=flowed"
+    /// );
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use mediatype::{names::*, values::*, MediaType};
+    /// const TEXT_PLAIN: MediaType =
+    ///     MediaType::from_parts_checked(TEXT, PLAIN, None, &[(CHARSET, UTF_8), (CHARSET, US_ASCII)]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a name or a value is not valid, or a parameter name appears more than once.
+    #[must_use]
+    pub const fn from_parts_checked(
+        ty: Name<'a>,
+        subty: Name<'a>,
+        suffix: Option<Name<'a>>,
+        params: &'a [(Name<'a>, Value<'a>)],
+    ) -> Self {
+        check_parts(ty, subty, suffix, params);
+        Self::from_parts(ty, subty, suffix, params)
+    }
+
     pub(crate) const fn from_parts_unchecked(
         ty: Name<'a>,
         subty: Name<'a>,
@@ -103,13 +141,217 @@ impl<'a> MediaType<'a> {
 
     /// Constructs a `MediaType` from `str` without copying the string.
     ///
+    /// The result borrows `s`, so it cannot outlive the input string.
+    /// Use [`into_owned`](#method.into_owned) or [`MediaTypeBuf`](./struct.MediaTypeBuf.html)
+    /// to keep it after the input string is dropped.
+    ///
+    /// Only a media type without parameters is parsed without allocation;
+    /// the parameters are collected into a `Vec`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string fails to be parsed.
+    pub fn parse<'s: 'a>(s: &'s str) -> Result<Self, ParseError> {
+        // The parameters are collected directly, without going through `Indices`,
+        // so a media type without parameters is parsed without allocation.
+        let [ty, subty, suffix] = parse_essence(s)?;
+        let mut params = Vec::new();
+        parse_params_with(
+            s,
+            essence_len(ty, subty, suffix),
+            |[key_start, key_end, value_start, value_end]| {
+                params.push((
+                    Name::new_unchecked(&s[key_start..key_end]),
+                    Value::new_unchecked(&s[value_start..value_end]),
+                ));
+                Ok(())
+            },
+        )?;
+        Ok(Self {
+            ty: Name::new_unchecked(&s[..ty]),
+            subty: Name::new_unchecked(&s[ty + 1..ty + 1 + subty]),
+            suffix: (suffix > 0).then(|| {
+                let start = ty + 1 + subty + 1;
+                Name::new_unchecked(&s[start..start + suffix])
+            }),
+            params: if params.is_empty() {
+                Cow::Borrowed(&[])
+            } else {
+                Cow::Owned(params)
+            },
+        })
+    }
+
+    /// Constructs a `MediaType` from bytes without copying them.
+    ///
+    /// Header values often arrive as bytes. Non-ASCII bytes are only allowed in
+    /// quoted parameter values, and they must be valid UTF-8.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, MediaTypeError};
+    /// let media_type = MediaType::parse_bytes(b"text/plain; charset=UTF-8").unwrap();
+    /// assert_eq!(media_type.ty, TEXT);
+    ///
+    /// let err = MediaType::parse_bytes(b"text/plain; title=\"\xff\"").unwrap_err();
+    /// assert_eq!(err.kind(), MediaTypeError::InvalidParamValue);
+    /// assert_eq!(err.span(), 19..20);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    pub fn parse_bytes<'s: 'a>(b: &'s [u8]) -> Result<Self, ParseError> {
+        Self::parse(str_from_bytes(b)?)
+    }
+
+    /// Constructs a `MediaType` from `str` with [`ParseOptions`].
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, ParseOptions};
+    /// assert_eq!(
+    ///     MediaType::parse_with("*; q=.2", ParseOptions::lenient()).unwrap(),
+    ///     MediaType::parse("*/*; q=.2").unwrap()
+    /// );
+    /// ```
+    ///
     /// # Errors
     ///
     /// Returns an error if the string fails to be parsed.
-    pub fn parse<'s: 'a>(s: &'s str) -> Result<Self, MediaTypeError> {
-        let (indices, _) = Indices::parse(s)?;
-        let params = indices
-            .params()
+    pub fn parse_with<'s: 'a>(s: &'s str, options: ParseOptions) -> Result<Self, ParseError> {
+        let mut media_type = Self::parse_mode(s, options.mode())?;
+        let params = &media_type.params;
+        let duplicated = |i: usize, others: &[(Name, Value)]| {
+            others.iter().any(|(name, _)| *name == params[i].0)
+        };
+        match options.duplicate_params_policy() {
+            DuplicateParams::Keep => (),
+            DuplicateParams::Reject => {
+                if let Some(i) = (0..params.len()).find(|&i| duplicated(i, &params[..i])) {
+                    let name = params[i].0.as_str();
+                    // The names of the parsed parameters are slices of `s`.
+                    let start = name.as_ptr() as usize - s.as_ptr() as usize;
+                    return Err(ParseError::at(
+                        MediaTypeError::DuplicateParam,
+                        s,
+                        start..start + name.len(),
+                    ));
+                }
+            }
+            DuplicateParams::First | DuplicateParams::Last => {
+                let first = options.duplicate_params_policy() == DuplicateParams::First;
+                let kept = (0..params.len())
+                    .filter(|&i| {
+                        let others = if first {
+                            &params[..i]
+                        } else {
+                            &params[i + 1..]
+                        };
+                        !duplicated(i, others)
+                    })
+                    .map(|i| params[i])
+                    .collect::<Vec<_>>();
+                if kept.len() < params.len() {
+                    media_type.params = Cow::Owned(kept);
+                }
+            }
+        }
+        Ok(media_type)
+    }
+
+    fn parse_mode<'s: 'a>(s: &'s str, mode: ParseMode) -> Result<Self, ParseError> {
+        match mode {
+            ParseMode::Default => Self::parse(s),
+            ParseMode::Strict => {
+                let (indices, len) = Indices::parse(s)?;
+                if len != s.len() {
+                    return Err(ParseError::at(
+                        MediaTypeError::InvalidParams,
+                        s,
+                        len..s.len(),
+                    ));
+                }
+                Ok(Self::from_indices(s, &indices))
+            }
+            ParseMode::Lenient => {
+                let is_whitespace = |c| is_ows(c) || c == '\r' || c == '\n';
+                let offset = s.len() - s.trim_start_matches(is_whitespace).len();
+                let s = s.trim_matches(is_whitespace);
+                let (mut media_type, rest) = match s.strip_prefix('*') {
+                    Some(rest)
+                        if rest.trim_start_matches(is_ows).is_empty()
+                            || rest.trim_start_matches(is_ows).starts_with(';') =>
+                    {
+                        (Self::new(_STAR, _STAR), rest)
+                    }
+                    _ => {
+                        let [ty, subty, suffix] =
+                            parse_essence(s).map_err(|err| err.offset(offset))?;
+                        let (essence, rest) = s.split_at(essence_len(ty, subty, suffix));
+                        let media_type = Self::from_parts(
+                            Name::new_unchecked(&essence[..ty]),
+                            Name::new_unchecked(&essence[ty + 1..ty + 1 + subty]),
+                            (suffix > 0)
+                                .then(|| Name::new_unchecked(&essence[essence.len() - suffix..])),
+                            &[],
+                        );
+                        (media_type, rest)
+                    }
+                };
+                let trimmed = rest.trim_start_matches(is_ows);
+                if !trimmed.is_empty() && !trimmed.starts_with(';') {
+                    let start = offset + s.len() - trimmed.len();
+                    return Err(ParseError::new(
+                        MediaTypeError::InvalidParams,
+                        trimmed,
+                        start..offset + s.len(),
+                    ));
+                }
+                let params = parse_params_lenient(rest);
+                if !params.is_empty() {
+                    media_type.params = Cow::Owned(params);
+                }
+                Ok(media_type)
+            }
+            ParseMode::Email | ParseMode::Folded => {
+                let comments = mode == ParseMode::Email;
+                if !comments {
+                    if !s.contains(['\r', '\n']) {
+                        return Self::parse(s);
+                    }
+                    check_folds(s)?;
+                }
+                let (ty, subty, suffix, params) = parse_folded(s, comments)?;
+                let mut media_type = Self::from_parts(
+                    Name::new_unchecked(&s[ty]),
+                    Name::new_unchecked(&s[subty]),
+                    suffix.map(|suffix| Name::new_unchecked(&s[suffix])),
+                    &[],
+                );
+                if !params.is_empty() {
+                    media_type.params = Cow::Owned(
+                        params
+                            .into_iter()
+                            .map(|(name, value)| {
+                                (
+                                    Name::new_unchecked(&s[name]),
+                                    Value::new_unchecked(&s[value]),
+                                )
+                            })
+                            .collect(),
+                    );
+                }
+                Ok(media_type)
+            }
+        }
+    }
+
+    pub(crate) fn from_indices(s: &'a str, indices: &Indices) -> Self {
+        Self::from_indices_with(s, indices, indices.params())
+    }
+
+    // Uses `params` instead of the parameters of `indices`.
+    pub(crate) fn from_indices_with(s: &'a str, indices: &Indices, params: &[[usize; 4]]) -> Self {
+        let params = params
             .iter()
             .map(|param| {
                 (
@@ -118,12 +360,12 @@ impl<'a> MediaType<'a> {
                 )
             })
             .collect();
-        Ok(Self {
+        Self {
             ty: Name::new_unchecked(&s[indices.ty()]),
             subty: Name::new_unchecked(&s[indices.subty()]),
             suffix: indices.suffix().map(|range| Name::new_unchecked(&s[range])),
             params: Cow::Owned(params),
-        })
+        }
     }
 
     /// Returns a [`MediaType`] without parameters.
@@ -142,14 +384,162 @@ impl<'a> MediaType<'a> {
     pub const fn essence(&self) -> MediaType<'_> {
         MediaType::from_parts(self.ty, self.subty, self.suffix, &[])
     }
+
+    /// Returns `true` if `self` and `other` have the same type, subtype and suffix.
+    ///
+    /// The names are compared case-insensitively and the parameters are ignored.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, MediaTypeBuf};
+    /// let media_type = MediaType::parse("Text/HTML; charset=UTF-8").unwrap();
+    /// assert!(media_type.eq_essence(MediaType::new(TEXT, HTML)));
+    /// assert!(!media_type.eq_essence(MediaType::new(TEXT, PLAIN)));
+    ///
+    /// let buf: MediaTypeBuf = "text/html; charset=ISO-8859-1".parse().unwrap();
+    /// assert!(media_type.eq_essence(&buf));
+    /// ```
+    #[must_use]
+    pub fn eq_essence<'b>(&self, other: impl Into<MediaType<'b>>) -> bool {
+        let other = other.into();
+        self.ty == other.ty && self.subty == other.subty && self.suffix == other.suffix
+    }
+
+    /// Converts the media type into a [`MediaTypeBuf`](./struct.MediaTypeBuf.html),
+    /// which owns its string and is not tied to the lifetime of the source.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, MediaTypeBuf};
+    /// fn content_type(header: &str) -> Option<MediaTypeBuf> {
+    ///     let media_type = MediaType::parse(header).ok()?;
+    ///     Some(media_type.into_owned())
+    /// }
+    ///
+    /// let header = String::from("text/html; charset=UTF-8");
+    /// let media_type = content_type(&header).unwrap();
+    /// drop(header);
+    /// assert_eq!(media_type.as_str(), "text/html; charset=UTF-8");
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> MediaTypeBuf {
+        MediaTypeBuf::from(self)
+    }
+
+    /// Returns an object that implements [`Display`](std::fmt::Display)
+    /// for the type, subtype and suffix without parameters.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("multipart/form-data; boundary=XYZ").unwrap();
+    /// assert_eq!(media_type.display_essence().to_string(), "multipart/form-data");
+    /// ```
+    #[must_use]
+    pub const fn display_essence(&self) -> DisplayEssence<'a> {
+        DisplayEssence {
+            ty: self.ty,
+            subty: self.subty,
+            suffix: self.suffix,
+        }
+    }
+
+    /// Returns an iterator over the `+` segments of the subtype and the suffix, from left to right.
+    ///
+    /// Only the last segment is the suffix; the others are a part of the subtype.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, Name};
+    /// let did = MediaType::parse("application/did+ld+json").unwrap();
+    /// assert_eq!(did.subty, "did+ld");
+    /// assert_eq!(did.suffix, Some(JSON));
+    /// assert_eq!(did.suffixes().collect::<Vec<_>>(), ["ld", "json"]);
+    ///
+    /// let amr = MediaType::parse("audio/amr-wb+").unwrap();
+    /// assert_eq!(amr.subty, "amr-wb+");
+    /// assert_eq!(amr.suffix, None);
+    /// assert_eq!(amr.suffixes().count(), 0);
+    /// ```
+    pub fn suffixes(&self) -> impl Iterator<Item = Name<'a>> {
+        suffixes(self.subty, self.suffix)
+    }
+
+    /// Returns a copy of `self` without parameters.
+    ///
+    /// Unlike [`essence`](#method.essence), the result does not borrow `self`.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// let essence = MediaType::parse("image/svg+xml; charset=UTF-8")
+    ///     .unwrap()
+    ///     .without_params();
+    /// assert_eq!(essence, MediaType::from_parts(IMAGE, SVG, Some(XML), &[]));
+    /// ```
+    #[must_use]
+    pub const fn without_params(&self) -> Self {
+        Self::from_parts(self.ty, self.subty, self.suffix, &[])
+    }
+
+    /// Traverses the components with a [`MediaTypeVisitor`].
+    ///
+    /// The spans are byte ranges in the string generated by [`Display`](std::fmt::Display).
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, MediaTypeVisitor, Name};
+    /// # use std::ops::Range;
+    /// struct Spans(Vec<Range<usize>>);
+    ///
+    /// impl MediaTypeVisitor for Spans {
+    ///     fn visit_suffix(&mut self, _suffix: Name, span: Range<usize>) {
+    ///         self.0.push(span);
+    ///     }
+    /// }
+    ///
+    /// let svg = MediaType::parse("image/svg+xml;   charset=UTF-8").unwrap();
+    /// let mut spans = Spans(Vec::new());
+    /// svg.visit(&mut spans);
+    /// assert_eq!(&svg.to_string()[spans.0[0].clone()], "xml");
+    /// ```
+    pub fn visit(&self, v: &mut impl MediaTypeVisitor) {
+        let mut end = self.ty.as_str().len();
+        v.visit_type(self.ty, 0..end);
+
+        let start = end + 1;
+        end = start + self.subty.as_str().len();
+        v.visit_subtype(self.subty, start..end);
+
+        if let Some(suffix) = self.suffix {
+            let start = end + 1;
+            end = start + suffix.as_str().len();
+            v.visit_suffix(suffix, start..end);
+        }
+
+        for &(name, value) in self.params.iter() {
+            let name_start = end + 2;
+            let name_end = name_start + name.as_str().len();
+            let value_start = name_end + 1;
+            end = value_start + value.as_str().len();
+            v.visit_param(name, name_start..name_end, value, value_start..end);
+        }
+    }
+}
+
+pub(crate) fn suffixes<'a>(
+    subty: Name<'a>,
+    suffix: Option<Name<'a>>,
+) -> impl Iterator<Item = Name<'a>> {
+    subty
+        .as_str()
+        .split('+')
+        .skip(1)
+        .filter(|s| !s.is_empty())
+        .map(Name::new_unchecked)
+        .chain(suffix)
 }
 
 impl<'a> ReadParams for MediaType<'a> {
-    fn params(&self) -> Params {
+    fn params(&self) -> Params<'_> {
         Params::from_slice(&self.params)
     }
 
-    fn get_param(&self, name: Name) -> Option<Value> {
+    fn get_param(&self, name: Name) -> Option<Value<'_>> {
         self.params
             .iter()
             .rev()
@@ -173,25 +563,49 @@ impl<'a> WriteParams<'a> for MediaType<'a> {
     }
 
     fn clear_params(&mut self) {
-        if !self.params.is_empty() {
-            self.params.to_mut().clear();
-        }
+        self.params = Cow::Borrowed(&[]);
     }
 }
 
 impl<'a> fmt::Display for MediaType<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_essence().fmt(f)?;
+        for (name, value) in &*self.params {
+            write!(f, "; {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Helper struct for printing the essence of a media type without parameters.
+///
+/// This is created by [`MediaType::display_essence`](./struct.MediaType.html#method.display_essence)
+/// and [`MediaTypeBuf::display_essence`](./struct.MediaTypeBuf.html#method.display_essence).
+#[derive(Debug, Copy, Clone)]
+pub struct DisplayEssence<'a> {
+    ty: Name<'a>,
+    subty: Name<'a>,
+    suffix: Option<Name<'a>>,
+}
+
+impl<'a> fmt::Display for DisplayEssence<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}/{}", self.ty, self.subty)?;
         if let Some(suffix) = self.suffix {
             write!(f, "+{}", suffix)?;
         }
-        for (name, value) in &*self.params {
-            write!(f, "; {}={}", name, value)?;
-        }
         Ok(())
     }
 }
 
+impl<'a> TryFrom<&'a str> for MediaType<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
 impl<'a> From<&'a MediaTypeBuf> for MediaType<'a> {
     fn from(t: &'a MediaTypeBuf) -> Self {
         t.to_ref()
@@ -226,6 +640,29 @@ impl<'a> PartialEq<&MediaTypeBuf> for MediaType<'a> {
     }
 }
 
+impl<'a> PartialEq<str> for MediaType<'a> {
+    /// Parses `other` and compares it with `self`.
+    ///
+    /// Returns `false` if `other` fails to be parsed.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// let media_type = MediaType::new(APPLICATION, JSON);
+    /// assert!(media_type == "Application/JSON");
+    /// assert!(media_type != "application/json; charset=UTF-8");
+    /// assert!(media_type != "application");
+    /// ```
+    fn eq(&self, other: &str) -> bool {
+        MediaType::parse(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl<'a> PartialEq<&str> for MediaType<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(*other)
+    }
+}
+
 impl<'a> Hash for MediaType<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.ty.hash(state);
@@ -242,7 +679,7 @@ impl<'a> Hash for MediaType<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{names::*, values::*};
+    use crate::values::*;
     use std::collections::hash_map::DefaultHasher;
     use std::str::FromStr;
 
@@ -268,6 +705,263 @@ mod tests {
             MediaType::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)]).to_string(),
             "image/svg+xml; charset=UTF-8"
         );
+
+        let title = Name::new("title").unwrap();
+        for raw in ["", "UTF-8", "a b", "a; b=c", "\"quoted\"", "back\\slash"] {
+            let quoted = Value::quote(raw);
+            let params = [(title, Value::new(&quoted).unwrap())];
+            let media_type = MediaType::from_parts(TEXT, PLAIN, None, &params);
+            let s = media_type.to_string();
+            let parsed = MediaType::parse(&s).unwrap();
+            assert_eq!(parsed, media_type);
+            assert_eq!(parsed.get_param(title).unwrap().unquoted_str(), raw);
+        }
+        assert!(Value::new("").is_some());
+        assert_eq!(Value::new("\"\"").unwrap().unquoted_str(), "");
+    }
+
+    #[test]
+    fn suffixes() {
+        let parse = |s| {
+            let media_type = MediaType::parse(s).unwrap();
+            (
+                media_type.subty.as_str(),
+                media_type.suffix.map(|suffix| suffix.as_str()),
+                media_type
+                    .suffixes()
+                    .map(|s| s.as_str())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        assert_eq!(parse("a/b+c"), ("b", Some("c"), vec!["c"]));
+        assert_eq!(parse("a/b+c+d; e=f"), ("b+c", Some("d"), vec!["c", "d"]));
+        assert_eq!(parse("a/b++c"), ("b+", Some("c"), vec!["c"]));
+        assert_eq!(parse("audio/amr-wb+"), ("amr-wb+", None, vec![]));
+        assert_eq!(parse("audio/amr-wb+;"), ("amr-wb+", None, vec![]));
+        assert_eq!(
+            MediaType::parse("a/b+-c"),
+            Err(ParseError::new(MediaTypeError::InvalidSuffix, "-c", 4..6))
+        );
+        assert_eq!(
+            MediaType::parse("audio/amr-wb+").unwrap().to_string(),
+            "audio/amr-wb+"
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        assert_eq!(
+            MediaType::try_from("text/plain; charset=UTF-8"),
+            MediaType::parse("text/plain; charset=UTF-8")
+        );
+        assert_eq!(
+            MediaType::try_from("text"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "text",
+                0..4
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_with() {
+        fn parse(s: &str, options: ParseOptions) -> Result<String, ParseError> {
+            MediaType::parse_with(s, options).map(|t| t.to_string())
+        }
+
+        let strict = ParseOptions::strict();
+        assert_eq!(
+            parse("image/svg+xml; charset=UTF-8", strict),
+            Ok("image/svg+xml; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("text/plain ;\tcharset=UTF-8", strict),
+            Ok("text/plain; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("text/plain;", strict),
+            Err(ParseError::new(MediaTypeError::InvalidParams, ";", 10..11))
+        );
+        assert_eq!(
+            parse("text/plain; charset=UTF-8 ", strict),
+            Err(ParseError::new(MediaTypeError::InvalidParams, " ", 25..26))
+        );
+        assert_eq!(
+            parse(
+                "text/plain; charset=UTF-8; format=flowed; Charset=UTF-8",
+                strict
+            ),
+            Err(ParseError::new(
+                MediaTypeError::DuplicateParam,
+                "Charset",
+                42..49
+            ))
+        );
+
+        assert_eq!(
+            parse(
+                "text/plain; charset=UTF-8; Charset=UTF-8",
+                strict.duplicate_params(DuplicateParams::Keep)
+            ),
+            Ok("text/plain; charset=UTF-8; Charset=UTF-8".into())
+        );
+
+        let default = ParseOptions::default();
+        assert_eq!(
+            parse("text/plain; charset=UTF-8; ", default),
+            Ok("text/plain; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("text/plain; charset=UTF-8; charset=UTF-8", default),
+            Ok("text/plain; charset=UTF-8; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("*", default),
+            Err(ParseError::new(MediaTypeError::InvalidTypeName, "*", 0..1))
+        );
+
+        let s = "text/plain; a=1; b=2; A=3; c=4; a=5";
+        assert_eq!(
+            parse(s, default.duplicate_params(DuplicateParams::First)),
+            Ok("text/plain; a=1; b=2; c=4".into())
+        );
+        assert_eq!(
+            parse(s, default.duplicate_params(DuplicateParams::Last)),
+            Ok("text/plain; b=2; c=4; a=5".into())
+        );
+        assert_eq!(
+            parse(s, default.duplicate_params(DuplicateParams::Reject)),
+            Err(ParseError::new(MediaTypeError::DuplicateParam, "A", 22..23))
+        );
+
+        let lenient = ParseOptions::lenient();
+        assert_eq!(
+            parse("\r\n text/plain ;; charset=UTF-8;;\t", lenient),
+            Ok("text/plain; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("image/svg+xml; =; a; b=\"c", lenient),
+            Ok("image/svg+xml".into())
+        );
+        assert_eq!(
+            parse("text/html; a=\"b;c\"; é=1; d=e", lenient),
+            Ok("text/html; a=\"b;c\"; d=e".into())
+        );
+        assert_eq!(
+            parse(
+                " text/plain;; a=1; a=2",
+                lenient.duplicate_params(DuplicateParams::Reject)
+            ),
+            Err(ParseError::new(MediaTypeError::DuplicateParam, "a", 19..20))
+        );
+        assert_eq!(parse("*", lenient), Ok("*/*".into()));
+        assert_eq!(parse(" * ; q=0.2", lenient), Ok("*/*; q=0.2".into()));
+        assert_eq!(
+            parse("*x", lenient),
+            Err(ParseError::new(MediaTypeError::InvalidTypeName, "*x", 0..2))
+        );
+        assert_eq!(
+            parse("text/plain garbage", lenient),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "garbage",
+                11..18
+            ))
+        );
+        assert_eq!(
+            parse("text", lenient),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "text",
+                0..4
+            ))
+        );
+
+        let email = ParseOptions::email();
+        assert_eq!(
+            parse("text/plain (a (nested) comment); charset=us-ascii", email),
+            Ok("text/plain; charset=us-ascii".into())
+        );
+        assert_eq!(
+            parse(
+                "(c) multipart (c) / (c) mixed+xml (c) ;\r\n\tboundary (c) = (c) \"a (b)\" (c)",
+                email
+            ),
+            Ok("multipart/mixed+xml; boundary=\"a (b)\"".into())
+        );
+        assert_eq!(
+            parse("text/plain; title=a(\\)) (c);", email),
+            Ok("text/plain; title=a".into())
+        );
+        assert_eq!(
+            parse("text/plain (unterminated", email),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "(unterminated",
+                11..24
+            ))
+        );
+        assert_eq!(
+            parse("text/plain (c) garbage ", email),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "garbage",
+                15..22
+            ))
+        );
+        assert_eq!(
+            parse("text/plain; (c) charset", email),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "charset",
+                16..23
+            ))
+        );
+        assert_eq!(
+            parse("text (c) plain", email),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "text",
+                0..4
+            ))
+        );
+        assert_eq!(
+            parse("text/plain; -a=b", email),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "-a",
+                12..14
+            ))
+        );
+
+        let folded = ParseOptions::folded();
+        assert_eq!(
+            parse("text/plain; charset=UTF-8", folded),
+            Ok("text/plain; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse("text/plain;\r\n charset=UTF-8;\r\n\t format=flowed", folded),
+            Ok("text/plain; charset=UTF-8; format=flowed".into())
+        );
+        assert_eq!(
+            parse("text/plain;\r\ncharset=UTF-8", folded),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "\r\ncharset=UTF-8",
+                11..26
+            ))
+        );
+        assert_eq!(
+            parse("text/plain;\n charset=UTF-8", folded),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "\n charset=UTF-8",
+                11..26
+            ))
+        );
+        assert!(parse("text/plain; title=\"a\r\n b\"", folded).is_err());
+        assert!(parse("text/plain (c);\r\n charset=UTF-8", folded).is_err());
     }
 
     #[test]
@@ -349,6 +1043,54 @@ mod tests {
         const TEXT_PLAIN: MediaType = MediaType::from_parts(TEXT, PLAIN, None, &[]);
         let text_plain = MediaType::parse("text/plain").unwrap();
         assert_eq!(text_plain.essence(), TEXT_PLAIN);
+
+        assert_eq!(text_plain, "TEXT/plain");
+        assert_eq!(
+            MediaType::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)]),
+            *"image/svg+xml; Charset=UTF-8"
+        );
+        assert_ne!(text_plain, "text/plain; charset=UTF-8");
+        assert_ne!(text_plain, "text/html");
+        assert_ne!(text_plain, "");
+    }
+
+    #[test]
+    fn parse_borrowed() {
+        let media_type = MediaType::parse("image/svg+xml").unwrap();
+        assert!(matches!(media_type.params, Cow::Borrowed([])));
+        assert_eq!(media_type.suffix, Some(XML));
+
+        let media_type = MediaType::parse("text/plain; charset=UTF-8; format=flowed").unwrap();
+        assert_eq!(*media_type.params, [(CHARSET, UTF_8), (FORMAT, FLOWED)]);
+    }
+
+    #[test]
+    fn parse_bytes() {
+        assert_eq!(
+            MediaType::parse_bytes("text/plain; title=\"ü\"".as_bytes()).unwrap(),
+            MediaType::parse("text/plain; title=\"ü\"").unwrap()
+        );
+        assert_eq!(
+            MediaType::parse_bytes(b"text/plain; title=\"\xc3\"").unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidParamValue, "\u{fffd}", 19..20)
+        );
+        assert_eq!(
+            MediaType::parse_bytes(b"\xfftext/plain").unwrap_err(),
+            ParseError::new(MediaTypeError::InvalidTypeName, "\u{fffd}", 0..1)
+        );
+    }
+
+    #[test]
+    fn eq_essence() {
+        let svg = MediaType::parse("image/svg+xml; charset=UTF-8").unwrap();
+        assert!(svg.eq_essence(MediaType::parse("IMAGE/SVG+XML; CHARSET=US-ASCII").unwrap()));
+        assert!(svg.eq_essence(&MediaTypeBuf::from_str("image/svg+xml").unwrap()));
+        assert!(!svg.eq_essence(MediaType::new(IMAGE, SVG)));
+        assert!(!svg.eq_essence(MediaType::from_parts(IMAGE, PNG, Some(XML), &[])));
+        assert!(!svg.eq_essence(MediaType::from_parts(TEXT, SVG, Some(XML), &[])));
+        assert!(MediaTypeBuf::from_str("text/plain; a=b")
+            .unwrap()
+            .eq_essence(MediaType::new(TEXT, PLAIN)));
     }
 
     #[test]
@@ -1,13 +1,17 @@
-use super::{error::*, media_type::*, name::*, params::*, parse::*, value::*};
+use super::{
+    error::*, media_type::*, media_type_list_buf::*, name::*, params::*, parse::*, value::*,
+    visit::*,
+};
 use std::{
     borrow::Cow,
     collections::BTreeMap,
     fmt,
     hash::{Hash, Hasher},
+    ops::Range,
     str::FromStr,
 };
 
-/// An owned and immutable media type.
+/// An owned media type.
 ///
 /// ```
 /// use mediatype::{names::*, values::*, MediaType, MediaTypeBuf, ReadParams};
@@ -27,12 +31,21 @@ pub struct MediaTypeBuf {
 
 impl MediaTypeBuf {
     /// Constructs a `MediaTypeBuf` from a top-level type and a subtype.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ty` or `subty` is constructed by [`Name::new_unchecked`] and is not valid.
     #[must_use]
     pub fn new(ty: Name, subty: Name) -> Self {
-        Self::from_string(format!("{}/{}", ty, subty)).expect("`ty` and `subty` should be valid")
+        Self::from_parts(ty, subty, None, &[])
     }
 
     /// Constructs a `MediaTypeBuf` with an optional suffix and parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a name is constructed by [`Name::new_unchecked`] and is not valid.
+    /// Use [`MediaTypeBuf::try_from_parts`] for the fallible version.
     #[must_use]
     pub fn from_parts(
         ty: Name,
@@ -40,16 +53,107 @@ impl MediaTypeBuf {
         suffix: Option<Name>,
         params: &[(Name, Value)],
     ) -> Self {
-        use std::fmt::Write;
+        Self::try_from_parts(ty, subty, suffix, params).expect("all names should be valid")
+    }
+
+    /// Constructs a `MediaTypeBuf` with an optional suffix and parameters.
+    ///
+    /// If `suffix` is `None`, a suffix in `subty` is split at the last `+` as the parser does.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaTypeBuf, MediaTypeError, Name};
+    /// assert_eq!(
+    ///     MediaTypeBuf::try_from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)])
+    ///         .unwrap()
+    ///         .as_str(),
+    ///     "image/svg+xml; charset=UTF-8"
+    /// );
+    /// assert_eq!(
+    ///     MediaTypeBuf::try_from_parts(IMAGE, Name::new_unchecked("svg xml"), None, &[]),
+    ///     Err(MediaTypeError::InvalidSubtypeName)
+    /// );
+    /// assert_eq!(
+    ///     MediaTypeBuf::try_from_parts(IMAGE, Name::new("svg+xml").unwrap(), None, &[])
+    ///         .unwrap()
+    ///         .suffix(),
+    ///     Some(XML)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name is constructed by [`Name::new_unchecked`] and is not valid.
+    pub fn try_from_parts(
+        ty: Name,
+        subty: Name,
+        suffix: Option<Name>,
+        params: &[(Name, Value)],
+    ) -> Result<Self, MediaTypeError> {
+        Self::build(ty, subty, suffix, params.iter().copied(), false)
+    }
+
+    pub(crate) fn build<'p>(
+        ty: Name,
+        subty: Name,
+        suffix: Option<Name>,
+        params: impl IntoIterator<Item = (Name<'p>, Value<'p>)>,
+        lowercase: bool,
+    ) -> Result<Self, MediaTypeError> {
+        if !is_restricted_name(ty.as_str()) {
+            return Err(MediaTypeError::InvalidTypeName);
+        }
+        if !is_restricted_name(subty.as_str()) {
+            return Err(MediaTypeError::InvalidSubtypeName);
+        }
+        if suffix.is_some_and(|suffix| !is_restricted_name(suffix.as_str())) {
+            return Err(MediaTypeError::InvalidSuffix);
+        }
+
+        let (subty_len, suffix_len) = split_suffix(subty, suffix)?;
+
         let mut s = String::new();
-        write!(s, "{}/{}", ty, subty).expect("`ty` and `subty` should be valid");
+        s.push_str(ty.as_str());
+        s.push('/');
+        s.push_str(subty.as_str());
         if let Some(suffix) = suffix {
-            write!(s, "+{}", suffix).unwrap();
+            s.push('+');
+            s.push_str(suffix.as_str());
         }
+        if lowercase {
+            s.make_ascii_lowercase();
+        }
+
+        let mut indices = Vec::new();
         for (name, value) in params {
-            write!(s, "; {}={}", name, value).unwrap();
+            if !is_restricted_name(name.as_str()) {
+                return Err(MediaTypeError::InvalidParamName);
+            }
+            if !is_value(value.as_str()) {
+                return Err(MediaTypeError::InvalidParamValue);
+            }
+            s.push_str("; ");
+            let key_start = s.len();
+            s.push_str(name.as_str());
+            if lowercase {
+                s[key_start..].make_ascii_lowercase();
+            }
+            let key_end = s.len();
+            s.push('=');
+            s.push_str(value.as_str());
+            indices.push([key_start, key_end, key_end + 1, s.len()]);
         }
-        Self::from_string(s).expect("all values should be valid")
+
+        let indices = Indices::from_lengths(
+            ty.as_str().len(),
+            subty_len,
+            suffix_len,
+            indices.into_boxed_slice(),
+        )
+        .ok_or(MediaTypeError::InvalidTypeName)?;
+        Ok(Self {
+            data: s.into(),
+            indices,
+        })
     }
 
     /// Constructs a `MediaTypeBuf` from [`String`].
@@ -63,7 +167,7 @@ impl MediaTypeBuf {
     ///
     /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
     /// [`FromStr::from_str`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
-    pub fn from_string(mut s: String) -> Result<Self, MediaTypeError> {
+    pub fn from_string(mut s: String) -> Result<Self, ParseError> {
         let (indices, len) = Indices::parse(&s)?;
         s.truncate(len);
         Ok(Self {
@@ -72,26 +176,123 @@ impl MediaTypeBuf {
         })
     }
 
+    /// Constructs a `MediaTypeBuf` from bytes.
+    ///
+    /// Non-ASCII bytes are only allowed in quoted parameter values,
+    /// and they must be valid UTF-8.
+    ///
+    /// This parses the text form; use [`MediaTypeBuf::from_bytes`] to decode
+    /// the output of [`MediaTypeBuf::to_bytes`].
+    ///
+    /// ```
+    /// # use mediatype::{MediaTypeBuf, MediaTypeError};
+    /// let media_type = MediaTypeBuf::parse_bytes(b"image/svg+xml; charset=UTF-8").unwrap();
+    /// assert_eq!(media_type.as_str(), "image/svg+xml; charset=UTF-8");
+    ///
+    /// let err = MediaTypeBuf::parse_bytes(b"text/\xffplain").unwrap_err();
+    /// assert_eq!(err.kind(), MediaTypeError::InvalidSubtypeName);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    pub fn parse_bytes(b: &[u8]) -> Result<Self, ParseError> {
+        str_from_bytes(b)?.parse()
+    }
+
+    /// Parses a comma-separated list of media types like the `Accept-Patch` header.
+    ///
+    /// Commas in quoted strings do not separate the media types, and empty elements are ignored.
+    /// This is [`MediaTypeListBuf::parse_media_types`](./struct.MediaTypeListBuf.html#method.parse_media_types)
+    /// split into separate values, so wildcards are rejected as well.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let list = MediaTypeBuf::parse_many(
+    ///     "application/example, text/example; title=\"a, b\"",
+    /// ).unwrap();
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list[1].as_str(), "text/example; title=\"a, b\"");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error if any of the media types fails to be parsed.
+    /// The span of the error is a byte range in the whole list.
+    pub fn parse_many(s: &str) -> Result<Vec<Self>, ParseError> {
+        Ok(MediaTypeListBuf::parse_media_types(s)?
+            .iter()
+            .map(Self::from)
+            .collect())
+    }
+
     /// Returns the top-level type.
     #[must_use]
-    pub fn ty(&self) -> Name {
+    pub fn ty(&self) -> Name<'_> {
         Name::new_unchecked(&self.data[self.indices.ty()])
     }
 
     /// Returns the subtype.
     #[must_use]
-    pub fn subty(&self) -> Name {
+    pub fn subty(&self) -> Name<'_> {
         Name::new_unchecked(&self.data[self.indices.subty()])
     }
 
     /// Returns the suffix.
     #[must_use]
-    pub fn suffix(&self) -> Option<Name> {
+    pub fn suffix(&self) -> Option<Name<'_>> {
         self.indices
             .suffix()
             .map(|range| Name::new_unchecked(&self.data[range]))
     }
 
+    /// Returns the byte range of the top-level type in [`as_str`](#method.as_str).
+    ///
+    /// For a media type constructed by parsing, the ranges of the components also apply
+    /// to the original input, so the header can be edited in place.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let header = "image/svg+xml; charset=UTF-8";
+    /// let media_type: MediaTypeBuf = header.parse().unwrap();
+    /// assert_eq!(&header[media_type.ty_span()], "image");
+    /// assert_eq!(&header[media_type.subty_span()], "svg");
+    /// assert_eq!(media_type.suffix_span().map(|span| &header[span]), Some("xml"));
+    ///
+    /// let (name, value) = media_type.param_spans().next().unwrap();
+    /// assert_eq!(&header[name], "charset");
+    /// assert_eq!(&header[value], "UTF-8");
+    /// ```
+    #[must_use]
+    pub fn ty_span(&self) -> Range<usize> {
+        self.indices.ty()
+    }
+
+    /// Returns the byte range of the subtype in [`as_str`](#method.as_str).
+    #[must_use]
+    pub fn subty_span(&self) -> Range<usize> {
+        self.indices.subty()
+    }
+
+    /// Returns the byte range of the suffix in [`as_str`](#method.as_str).
+    #[must_use]
+    pub fn suffix_span(&self) -> Option<Range<usize>> {
+        self.indices.suffix()
+    }
+
+    /// Returns an iterator over the byte ranges of the names and the values of the parameters
+    /// in [`as_str`](#method.as_str), in the original order.
+    ///
+    /// The ranges of quoted values include the quotes.
+    pub fn param_spans(&self) -> impl Iterator<Item = (Range<usize>, Range<usize>)> + '_ {
+        self.indices
+            .params()
+            .iter()
+            .map(|&[key_start, key_end, value_start, value_end]| {
+                (key_start..key_end, value_start..value_end)
+            })
+    }
+
     /// Returns a [`MediaType`] without parameters.
     ///
     /// ```
@@ -107,12 +308,127 @@ impl MediaTypeBuf {
         MediaType::from_parts(self.ty(), self.subty(), self.suffix(), &[])
     }
 
+    /// Returns `true` if `self` and `other` have the same type, subtype and suffix.
+    ///
+    /// The names are compared case-insensitively and the parameters are ignored.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, MediaTypeBuf};
+    /// let media_type: MediaTypeBuf = "image/SVG+xml; charset=UTF-8".parse().unwrap();
+    /// assert!(media_type.eq_essence(MediaType::from_parts(IMAGE, SVG, Some(XML), &[])));
+    /// assert!(media_type.eq_essence(&media_type.clone()));
+    /// ```
+    #[must_use]
+    pub fn eq_essence<'b>(&self, other: impl Into<MediaType<'b>>) -> bool {
+        self.essence().eq_essence(other)
+    }
+
+    /// Returns an object that implements [`Display`](std::fmt::Display)
+    /// for the type, subtype and suffix without parameters.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "image/svg+xml; charset=UTF-8".parse().unwrap();
+    /// assert_eq!(format!("{}", media_type.display_essence()), "image/svg+xml");
+    /// ```
+    #[must_use]
+    pub fn display_essence(&self) -> DisplayEssence<'_> {
+        self.essence().display_essence()
+    }
+
+    /// Returns an iterator over the `+` segments of the subtype and the suffix, from left to right.
+    ///
+    /// Only the last segment is the suffix; the others are a part of the subtype.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "application/did+ld+json".parse().unwrap();
+    /// assert_eq!(media_type.suffixes().collect::<Vec<_>>(), ["ld", "json"]);
+    /// ```
+    pub fn suffixes(&self) -> impl Iterator<Item = Name<'_>> {
+        suffixes(self.subty(), self.suffix())
+    }
+
+    /// Returns a copy of `self` without parameters.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "image/svg+xml; charset=UTF-8".parse().unwrap();
+    /// assert_eq!(media_type.without_params().as_str(), "image/svg+xml");
+    /// ```
+    #[must_use]
+    pub fn without_params(&self) -> Self {
+        Self::build(self.ty(), self.subty(), self.suffix(), [], false)
+            .expect("`self` should be valid")
+    }
+
     /// Returns the underlying string.
     #[must_use]
     pub const fn as_str(&self) -> &str {
         &self.data
     }
 
+    /// Returns the underlying string, consuming `self`.
+    ///
+    /// The buffer is moved into the [`String`] without copying.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "text/plain; charset=UTF-8".parse().unwrap();
+    /// assert_eq!(media_type.into_string(), "text/plain; charset=UTF-8");
+    /// ```
+    ///
+    /// [`String`]: https://doc.rust-lang.org/std/string/struct.String.html
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.data.into()
+    }
+
+    pub(crate) fn into_raw(self) -> (Box<str>, Indices) {
+        (self.data, self.indices)
+    }
+
+    /// Decomposes `self` into the owned type, subtype, suffix and parameters.
+    ///
+    /// The parameters are in the original order, and the values are kept as written,
+    /// including the quotes of quoted strings.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "image/svg+xml; charset=UTF-8; title=\"a b\"".parse().unwrap();
+    /// let (ty, subty, suffix, params) = media_type.into_parts();
+    /// assert_eq!(ty, "image");
+    /// assert_eq!(subty, "svg");
+    /// assert_eq!(suffix.as_deref(), Some("xml"));
+    /// assert_eq!(
+    ///     params,
+    ///     [
+    ///         ("charset".to_string(), "UTF-8".to_string()),
+    ///         ("title".to_string(), "\"a b\"".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (String, String, Option<String>, Vec<(String, String)>) {
+        let params = self
+            .indices
+            .params()
+            .iter()
+            .map(|&[key_start, key_end, value_start, value_end]| {
+                (
+                    self.data[key_start..key_end].to_string(),
+                    self.data[value_start..value_end].to_string(),
+                )
+            })
+            .collect();
+        (
+            self.ty().to_string(),
+            self.subty().to_string(),
+            self.suffix().map(|suffix| suffix.to_string()),
+            params,
+        )
+    }
+
     /// Returns the canonicalized `MediaTypeBuf`.
     ///
     /// All strings except parameter values will be converted to lowercase.
@@ -127,30 +443,30 @@ impl MediaTypeBuf {
     /// ```
     #[must_use]
     pub fn canonicalize(&self) -> Self {
-        use std::fmt::Write;
-        let mut s = String::with_capacity(self.data.len());
-        write!(
-            s,
-            "{}/{}",
-            self.ty().as_str().to_ascii_lowercase(),
-            self.subty().as_str().to_ascii_lowercase()
-        )
-        .unwrap();
-        if let Some(suffix) = self.suffix() {
-            write!(s, "+{}", suffix.as_str().to_ascii_lowercase())
-                .expect("`write` should not fail on a `String`");
-        }
-        for (name, value) in self.params() {
-            write!(s, "; {}={}", name.as_str().to_ascii_lowercase(), value)
-                .expect("`write` should not fail on a `String`");
-        }
-        s.shrink_to_fit();
-        Self::from_string(s).expect("all values should be valid")
+        Self::build(self.ty(), self.subty(), self.suffix(), self.params(), true)
+            .expect("`self` should be valid")
     }
 
-    /// Constructs a `MediaType` from `self`.
+    /// Constructs a `MediaType` borrowing the names and values from `self`.
+    ///
+    /// Nothing is copied or reparsed except the list of the parameters,
+    /// which is allocated only if the media type has parameters.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaType, MediaTypeBuf, ReadParams};
+    /// fn is_utf8_text(media_type: &MediaType) -> bool {
+    ///     media_type.ty == TEXT && media_type.get_param(CHARSET) == Some(UTF_8)
+    /// }
+    ///
+    /// let media_type: MediaTypeBuf = "text/markdown; charset=UTF-8".parse().unwrap();
+    /// assert!(is_utf8_text(&media_type.to_ref()));
+    ///
+    /// let mut html = media_type.to_ref();
+    /// html.subty = HTML;
+    /// assert_eq!(html.to_string(), "text/html; charset=UTF-8");
+    /// ```
     #[must_use]
-    pub fn to_ref(&self) -> MediaType {
+    pub fn to_ref(&self) -> MediaType<'_> {
         let params = self.params().collect::<Vec<_>>();
         let params = if params.is_empty() {
             Cow::Borrowed([].as_slice())
@@ -159,29 +475,309 @@ impl MediaTypeBuf {
         };
         MediaType::from_parts_unchecked(self.ty(), self.subty(), self.suffix(), params)
     }
+
+    /// Edits a copy of `self` and applies it only if the closure succeeds.
+    ///
+    /// The closure receives a mutable reference to a clone of `self`.
+    /// If the closure returns `Err`, the clone is dropped and `self` is left unchanged,
+    /// so a failing step rolls back the earlier ones.
+    ///
+    /// No validation happens before the clone is committed, as every mutator
+    /// of `MediaTypeBuf` rebuilds and validates the string, so the clone is always valid.
+    /// The mutators copy their arguments, so the names and values may borrow local data.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaTypeBuf, MediaTypeError};
+    /// let mut media_type: MediaTypeBuf = "text/plain; charset=US-ASCII".parse().unwrap();
+    ///
+    /// let format = String::from("flowed");
+    /// media_type
+    ///     .edit(|t| {
+    ///         t.set_param(CHARSET, UTF_8);
+    ///         t.set_param_str("format", &format).map(drop)
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(media_type.as_str(), "text/plain; charset=UTF-8; format=flowed");
+    ///
+    /// let result = media_type.edit(|t| {
+    ///     t.clear_params();
+    ///     Err(MediaTypeError::InvalidParams)
+    /// });
+    /// assert!(result.is_err());
+    /// assert_eq!(media_type.as_str(), "text/plain; charset=UTF-8; format=flowed");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the closure.
+    pub fn edit<F, E>(&mut self, f: F) -> Result<(), E>
+    where
+        F: FnOnce(&mut Self) -> Result<(), E>,
+    {
+        let mut editor = self.clone();
+        f(&mut editor)?;
+        *self = editor;
+        Ok(())
+    }
+
+    /// Sets a parameter value.
+    ///
+    /// If the parameters with the name already exist, they will be removed.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaTypeBuf};
+    /// let mut media_type: MediaTypeBuf = "text/plain; charset=US-ASCII; format=fixed"
+    ///     .parse()
+    ///     .unwrap();
+    /// media_type.set_param(CHARSET, UTF_8);
+    /// assert_eq!(media_type.as_str(), "text/plain; format=fixed; charset=UTF-8");
+    /// ```
+    ///
+    /// Unlike [`WriteParams::set_param`], the name and the value are copied,
+    /// so they can be borrowed from temporary strings.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaTypeBuf, Name, Value};
+    /// let mut media_type = MediaTypeBuf::new(MULTIPART, FORM_DATA);
+    /// for (name, value) in [("boundary".to_string(), format!("{:x}", 0xdead_beef_u32))] {
+    ///     media_type.set_param(Name::new(&name).unwrap(), Value::new(&value).unwrap());
+    /// }
+    /// assert_eq!(media_type.as_str(), "multipart/form-data; boundary=deadbeef");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is constructed by [`Name::new_unchecked`] and is not valid.
+    pub fn set_param(&mut self, name: Name, value: Value) {
+        let params = self
+            .params()
+            .filter(|&(key, _)| key != name)
+            .chain(std::iter::once((name, value)));
+        *self = Self::build(self.ty(), self.subty(), self.suffix(), params, false)
+            .expect("all names should be valid");
+    }
+
+    /// Validates the name and the unquoted value, and sets the parameter.
+    ///
+    /// The value is quoted with [`Value::try_quote`] if necessary.
+    /// Returns the unquoted previous value, if any.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaTypeBuf, MediaTypeError};
+    /// let mut media_type = MediaTypeBuf::new(TEXT, PLAIN);
+    /// let title = String::from("a b");
+    /// assert_eq!(media_type.set_param_str("title", &title), Ok(None));
+    /// assert_eq!(media_type.as_str(), "text/plain; title=\"a b\"");
+    /// assert_eq!(
+    ///     media_type.set_param_str("title", "c"),
+    ///     Ok(Some("a b".into()))
+    /// );
+    ///
+    /// let err = media_type.set_param_str("-charset", "UTF-8").unwrap_err();
+    /// assert_eq!(err.kind(), MediaTypeError::InvalidParamName);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not valid or the value contains control characters.
+    /// The span of the error is relative to the offending argument.
+    pub fn set_param_str(&mut self, name: &str, value: &str) -> Result<Option<String>, ParseError> {
+        let name = validate_param_name(name)?;
+        let quoted = Value::try_quote(value).ok_or_else(|| {
+            ParseError::at(MediaTypeError::InvalidParamValue, value, 0..value.len())
+        })?;
+        let previous = self
+            .get_param(name)
+            .map(|value| value.unquoted_str().into_owned());
+        self.set_param(name, Value::new_unchecked(&quoted));
+        Ok(previous)
+    }
+
+    /// Removes all parameters with the name.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaTypeBuf};
+    /// let mut media_type: MediaTypeBuf = "text/plain; charset=UTF-8; format=fixed; charset=gbk"
+    ///     .parse()
+    ///     .unwrap();
+    /// media_type.remove_params(CHARSET);
+    /// assert_eq!(media_type.as_str(), "text/plain; format=fixed");
+    /// ```
+    pub fn remove_params(&mut self, name: Name) {
+        if self.get_param(name).is_some() {
+            let params = self.params().filter(|&(key, _)| key != name);
+            *self = Self::build(self.ty(), self.subty(), self.suffix(), params, false)
+                .expect("`self` should be valid");
+        }
+    }
+
+    /// Removes all parameters.
+    pub fn clear_params(&mut self) {
+        if self.params().next().is_some() {
+            *self = Self::build(self.ty(), self.subty(), self.suffix(), [], false)
+                .expect("`self` should be valid");
+        }
+    }
+
+    /// Sets or removes the suffix.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaTypeBuf};
+    /// let mut media_type: MediaTypeBuf = "application/ld; charset=UTF-8".parse().unwrap();
+    /// media_type.set_suffix(Some(JSON));
+    /// assert_eq!(media_type.as_str(), "application/ld+json; charset=UTF-8");
+    ///
+    /// media_type.set_suffix(None);
+    /// assert_eq!(media_type.as_str(), "application/ld; charset=UTF-8");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suffix` is constructed by [`Name::new_unchecked`] and is not valid.
+    pub fn set_suffix(&mut self, suffix: Option<Name>) {
+        *self = Self::build(self.ty(), self.subty(), suffix, self.params(), false)
+            .expect("all names should be valid");
+    }
+
+    /// Traverses the components with a [`MediaTypeVisitor`].
+    ///
+    /// The spans are byte ranges in [`MediaTypeBuf::as_str`].
+    pub fn visit(&self, v: &mut impl MediaTypeVisitor) {
+        v.visit_type(self.ty(), self.indices.ty());
+        v.visit_subtype(self.subty(), self.indices.subty());
+        if let Some(range) = self.indices.suffix() {
+            v.visit_suffix(Name::new_unchecked(&self.data[range.clone()]), range);
+        }
+        for &[key_start, key_end, value_start, value_end] in self.indices.params() {
+            v.visit_param(
+                Name::new_unchecked(&self.data[key_start..key_end]),
+                key_start..key_end,
+                Value::new_unchecked(&self.data[value_start..value_end]),
+                value_start..value_end,
+            );
+        }
+    }
+
+    /// Encodes `self` into a compact binary format.
+    ///
+    /// The encoded bytes contain both the string and the parsed indices,
+    /// so [`MediaTypeBuf::from_bytes`] can restore the value without parsing the string again.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type: MediaTypeBuf = "image/svg+xml; charset=UTF-8".parse().unwrap();
+    /// let bytes = media_type.to_bytes();
+    /// assert_eq!(MediaTypeBuf::from_bytes(&bytes), Some(media_type));
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let params = self.indices.params();
+        let mut bytes = Vec::with_capacity(self.data.len() + 8 + params.len() * 8);
+        bytes.push(BINARY_FORMAT_VERSION);
+        write_varint(&mut bytes, self.data.len());
+        bytes.extend_from_slice(self.data.as_bytes());
+        write_varint(&mut bytes, self.indices.ty().len());
+        write_varint(&mut bytes, self.indices.subty().len());
+        write_varint(
+            &mut bytes,
+            self.indices.suffix().map_or(0, |range| range.len()),
+        );
+        write_varint(&mut bytes, params.len());
+        for param in params {
+            for &index in param {
+                write_varint(&mut bytes, index);
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a `MediaTypeBuf` from bytes generated by [`MediaTypeBuf::to_bytes`].
+    ///
+    /// The indices are validated against the string, but the string is not parsed again.
+    ///
+    /// If the bytes are not valid, returns `None`.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&version, mut bytes) = bytes.split_first()?;
+        if version != BINARY_FORMAT_VERSION {
+            return None;
+        }
+
+        let len = read_varint(&mut bytes)?;
+        if len > bytes.len() {
+            return None;
+        }
+        let (data, mut bytes) = bytes.split_at(len);
+        let data = std::str::from_utf8(data).ok()?;
+
+        let ty = read_varint(&mut bytes)?;
+        let subty = read_varint(&mut bytes)?;
+        let suffix = read_varint(&mut bytes)?;
+        let params_len = read_varint(&mut bytes)?;
+        if params_len > bytes.len() / 4 {
+            return None;
+        }
+        let mut params = Vec::with_capacity(params_len);
+        for _ in 0..params_len {
+            params.push([
+                read_varint(&mut bytes)?,
+                read_varint(&mut bytes)?,
+                read_varint(&mut bytes)?,
+                read_varint(&mut bytes)?,
+            ]);
+        }
+        if !bytes.is_empty() {
+            return None;
+        }
+
+        let indices = Indices::from_raw(data, ty, subty, suffix, params.into_boxed_slice())?;
+        Some(Self {
+            data: data.into(),
+            indices,
+        })
+    }
+}
+
+const BINARY_FORMAT_VERSION: u8 = 1;
+
+fn write_varint(bytes: &mut Vec<u8>, mut n: usize) {
+    while n >= 0x80 {
+        bytes.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    bytes.push(n as u8);
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Option<usize> {
+    let mut n: usize = 0;
+    for shift in (0..usize::BITS).step_by(7) {
+        let (&byte, rest) = bytes.split_first()?;
+        *bytes = rest;
+        n |= usize::from(byte & 0x7f).checked_shl(shift)?;
+        if byte & 0x80 == 0 {
+            return Some(n);
+        }
+    }
+    None
 }
 
 impl ReadParams for MediaTypeBuf {
-    fn params(&self) -> Params {
+    fn params(&self) -> Params<'_> {
         Params::from_indices(&self.data, &self.indices)
     }
 
-    fn get_param(&self, name: Name) -> Option<Value> {
+    fn get_param(&self, name: Name) -> Option<Value<'_>> {
         self.indices
             .params()
             .iter()
             .rev()
-            .find(|&&[start, end, _, _]| {
-                name == Name::new_unchecked(&self.data[start..end])
-            })
-            .map(|&[_, _, start, end]| {
-                Value::new_unchecked(&self.data[start..end])
-            })
+            .find(|&&[start, end, _, _]| name == Name::new_unchecked(&self.data[start..end]))
+            .map(|&[_, _, start, end]| Value::new_unchecked(&self.data[start..end]))
     }
 }
 
 impl FromStr for MediaTypeBuf {
-    type Err = MediaTypeError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let (indices, len) = Indices::parse(s)?;
@@ -192,15 +788,45 @@ impl FromStr for MediaTypeBuf {
     }
 }
 
+impl TryFrom<&str> for MediaTypeBuf {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::from_str(s)
+    }
+}
+
+impl TryFrom<String> for MediaTypeBuf {
+    type Error = ParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::from_string(s)
+    }
+}
+
 impl From<MediaType<'_>> for MediaTypeBuf {
+    /// # Panics
+    ///
+    /// Panics if a name is constructed by [`Name::new_unchecked`] and is not valid.
+    /// Use [`MediaTypeBuf::try_from_parts`] for the fallible version.
     fn from(t: MediaType) -> Self {
-        Self::from_string(t.to_string()).expect("`t` should be valid")
+        Self::from(&t)
     }
 }
 
 impl From<&MediaType<'_>> for MediaTypeBuf {
+    /// # Panics
+    ///
+    /// Panics if a name is constructed by [`Name::new_unchecked`] and is not valid.
+    /// Use [`MediaTypeBuf::try_from_parts`] for the fallible version.
     fn from(t: &MediaType) -> Self {
-        Self::from_string(t.to_string()).expect("`t` should be valid")
+        Self::try_from_parts(t.ty, t.subty, t.suffix, &t.params).expect("`t` should be valid")
+    }
+}
+
+impl From<MediaTypeBuf> for String {
+    fn from(media_type: MediaTypeBuf) -> Self {
+        media_type.into_string()
     }
 }
 
@@ -244,12 +870,24 @@ impl PartialEq<MediaType<'_>> for &MediaTypeBuf {
     }
 }
 
+impl PartialEq<str> for MediaTypeBuf {
+    /// Parses `other` and compares it with `self`.
+    ///
+    /// Returns `false` if `other` fails to be parsed.
+    fn eq(&self, other: &str) -> bool {
+        MediaType::parse(other).is_ok_and(|other| *self == other)
+    }
+}
+
+impl PartialEq<&str> for MediaTypeBuf {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(*other)
+    }
+}
+
 impl fmt::Display for MediaTypeBuf {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}/{}", self.ty(), self.subty())?;
-        if let Some(suffix) = self.suffix() {
-            write!(f, "+{}", suffix)?;
-        }
+        self.display_essence().fmt(f)?;
         for (name, value) in self.params() {
             write!(f, "; {}={}", name, value)?;
         }
@@ -288,6 +926,74 @@ mod tests {
             MediaTypeBuf::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)]).to_string(),
             "image/svg+xml; charset=UTF-8"
         );
+
+        for (subty, s) in [
+            ("svg+xml", "image/svg+xml"),
+            ("a+b+json", "image/a+b+json"),
+            ("amr-wb+", "image/amr-wb+"),
+        ] {
+            let built = MediaTypeBuf::from_parts(IMAGE, Name::new(subty).unwrap(), None, &[]);
+            let parsed = MediaTypeBuf::from_str(s).unwrap();
+            assert_eq!(built.subty(), parsed.subty());
+            assert_eq!(built.suffix(), parsed.suffix());
+            assert_eq!(built, parsed);
+            assert_eq!(calculate_hash(&built), calculate_hash(&parsed));
+        }
+        assert_eq!(
+            MediaTypeBuf::from_parts(IMAGE, Name::new("svg+xml").unwrap(), None, &[]).suffix(),
+            Some(XML)
+        );
+        assert_eq!(
+            MediaTypeBuf::try_from_parts(IMAGE, Name::new("svg+-xml").unwrap(), None, &[]),
+            Err(MediaTypeError::InvalidSuffix)
+        );
+        assert_eq!(
+            MediaTypeBuf::try_from_parts(
+                TEXT,
+                PLAIN,
+                None,
+                &[(CHARSET, Value::new_unchecked("a; b=c"))]
+            ),
+            Err(MediaTypeError::InvalidParamValue)
+        );
+    }
+
+    #[test]
+    fn parse_many() {
+        let parse = |s| {
+            MediaTypeBuf::parse_many(s).map(|list| {
+                list.iter()
+                    .map(|media_type| media_type.to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+        assert_eq!(parse("a/b, , c/d"), Ok(vec!["a/b".into(), "c/d".into()]));
+        assert_eq!(parse("a/b,"), Ok(vec!["a/b".into()]));
+        assert_eq!(parse(""), Ok(vec![]));
+        assert_eq!(
+            parse("a/b, text/*"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidSubtypeName,
+                "*",
+                10..11
+            ))
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let media_type = MediaTypeBuf::try_from("image/svg+xml; charset=UTF-8").unwrap();
+        assert_eq!(media_type.as_str(), "image/svg+xml; charset=UTF-8");
+        let media_type = MediaTypeBuf::try_from(String::from("text/plain;")).unwrap();
+        assert_eq!(media_type.as_str(), "text/plain");
+        assert_eq!(
+            MediaTypeBuf::try_from("text/plain; charset"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "charset",
+                12..19
+            ))
+        );
     }
 
     #[test]
@@ -312,6 +1018,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_params() {
+        let media_type =
+            MediaTypeBuf::from_str("image/svg+xml; HELLO=WORLD; charset=UTF-8; hello=world")
+                .unwrap();
+        let hello = Name::new("hello").unwrap();
+        assert_eq!(
+            media_type.get_params(hello).collect::<Vec<_>>(),
+            ["WORLD", "world"]
+        );
+        assert_eq!(media_type.get_params(CHARSET).collect::<Vec<_>>(), [UTF_8]);
+        assert_eq!(media_type.get_params(FORMAT).count(), 0);
+    }
+
+    #[test]
+    fn without_params() {
+        let media_type = MediaTypeBuf::from_str("Image/SVG+xml; charset=UTF-8 ; a=b").unwrap();
+        assert_eq!(media_type.without_params().as_str(), "Image/SVG+xml");
+        assert_eq!(media_type.without_params(), media_type.essence());
+        assert_eq!(
+            MediaTypeBuf::from_str("text/plain")
+                .unwrap()
+                .without_params()
+                .as_str(),
+            "text/plain"
+        );
+    }
+
     #[test]
     fn essence() {
         assert_eq!(
@@ -355,6 +1089,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn edit() {
+        let mut media_type = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap();
+        let value = String::from("world");
+        media_type
+            .edit(|t| {
+                t.set_suffix(None);
+                t.set_param(Name::new("hello").unwrap(), Value::new(&value).unwrap());
+                Ok::<_, MediaTypeError>(())
+            })
+            .unwrap();
+        assert_eq!(
+            media_type.to_string(),
+            "image/svg; charset=UTF-8; hello=world"
+        );
+
+        let result = media_type.edit(|t| {
+            t.remove_params(CHARSET);
+            t.set_param_str("a b", "c").map(drop)
+        });
+        assert_eq!(
+            result,
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "a b",
+                0..3
+            ))
+        );
+        assert_eq!(
+            media_type.to_string(),
+            "image/svg; charset=UTF-8; hello=world"
+        );
+    }
+
+    #[test]
+    fn set_param_str() {
+        let mut media_type = MediaTypeBuf::new(TEXT, PLAIN);
+        assert_eq!(media_type.set_param_str("title", "\"quoted\""), Ok(None));
+        assert_eq!(media_type.as_str(), "text/plain; title=\"\\\"quoted\\\"\"");
+        assert_eq!(
+            media_type.set_param_str("Title", ""),
+            Ok(Some("\"quoted\"".into()))
+        );
+        assert_eq!(media_type.as_str(), "text/plain; Title=\"\"");
+        assert_eq!(
+            media_type.set_param_str("title", "a\r\nb"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "a\r\nb",
+                0..4
+            ))
+        );
+        assert_eq!(media_type.as_str(), "text/plain; Title=\"\"");
+    }
+
+    #[test]
+    fn set_param() {
+        let mut media_type = MediaTypeBuf::from_str("text/plain;charset=gbk").unwrap();
+        media_type.set_param(CHARSET, UTF_8);
+        assert_eq!(media_type.as_str(), "text/plain; charset=UTF-8");
+        assert_eq!(media_type.get_param(CHARSET), Some(UTF_8));
+
+        media_type.set_param(FORMAT, Value::new("flowed").unwrap());
+        assert_eq!(
+            media_type.as_str(),
+            "text/plain; charset=UTF-8; format=flowed"
+        );
+
+        media_type.remove_params(CHARSET);
+        assert_eq!(media_type.as_str(), "text/plain; format=flowed");
+        media_type.remove_params(CHARSET);
+        assert_eq!(media_type.as_str(), "text/plain; format=flowed");
+
+        media_type.set_suffix(Some(XML));
+        assert_eq!(media_type.as_str(), "text/plain+xml; format=flowed");
+        assert_eq!(media_type.suffix(), Some(XML));
+
+        media_type.clear_params();
+        assert_eq!(media_type.as_str(), "text/plain+xml");
+        assert_eq!(
+            media_type,
+            MediaType::from_parts(TEXT, PLAIN, Some(XML), &[])
+        );
+    }
+
+    #[test]
+    fn bytes() {
+        for s in [
+            "*/*",
+            "text/plain",
+            "image/svg+xml; charset=UTF-8",
+            "text/html; charset=UTF-8; message=\"Hello, world!\";   ",
+            "text/plain; a=",
+            "text/plain; a=; b=c",
+            "text/plain; title=\"\"",
+        ] {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            let decoded = MediaTypeBuf::from_bytes(&media_type.to_bytes()).unwrap();
+            assert_eq!(decoded.as_str(), media_type.as_str());
+            assert_eq!(decoded, media_type);
+        }
+
+        let bytes = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8")
+            .unwrap()
+            .to_bytes();
+        for len in 0..bytes.len() {
+            assert_eq!(MediaTypeBuf::from_bytes(&bytes[..len]), None);
+        }
+        for i in 0..bytes.len() {
+            let mut bytes = bytes.clone();
+            bytes[i] ^= 0x01;
+            if let Some(decoded) = MediaTypeBuf::from_bytes(&bytes) {
+                assert_eq!(MediaTypeBuf::from_str(decoded.as_str()).unwrap(), decoded);
+            }
+        }
+        assert_eq!(MediaTypeBuf::from_bytes(&[0]), None);
+    }
+
     #[test]
     fn cmp() {
         assert_eq!(
@@ -382,6 +1234,12 @@ mod tests {
             &MediaTypeBuf::from_str("image/svg+xml").unwrap(),
             media_type!(IMAGE / SVG + XML)
         );
+
+        let media_type = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap();
+        assert_eq!(media_type, "IMAGE/SVG+XML; CHARSET=UTF-8");
+        assert_eq!(media_type, *"image/svg+xml;charset=UTF-8;");
+        assert_ne!(media_type, "image/svg+xml");
+        assert_ne!(media_type, "image/svg+xml; charset=");
     }
 
     #[test]
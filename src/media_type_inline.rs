@@ -0,0 +1,451 @@
+use super::{error::*, media_type::*, media_type_buf::*, name::*, params::*, parse::*, value::*};
+use std::{
+    borrow::Cow,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    str::FromStr,
+};
+
+/// A fixed-capacity media type stored without heap allocation.
+///
+/// The string is stored in an inline buffer of `N` bytes,
+/// and up to `P` parameters can be stored.
+/// The crate depends on `std`, so this type is not available for `no_std` targets.
+///
+/// ```
+/// use mediatype::{names::*, values::*, MediaTypeError, MediaTypeInline, ReadParams};
+///
+/// let svg = MediaTypeInline::<32>::parse("image/svg+xml; charset=UTF-8").unwrap();
+/// assert_eq!(svg.subty(), SVG);
+/// assert_eq!(svg.get_param(CHARSET), Some(UTF_8));
+///
+/// let text_plain = MediaTypeInline::<16>::from_parts(TEXT, PLAIN, None, &[]).unwrap();
+/// assert_eq!(text_plain.as_str(), "text/plain");
+///
+/// assert_eq!(
+///     MediaTypeInline::<8>::parse("image/svg+xml").unwrap_err().kind(),
+///     MediaTypeError::CapacityExceeded
+/// );
+/// ```
+#[derive(Clone, Copy)]
+pub struct MediaTypeInline<const N: usize, const P: usize = 4> {
+    data: [u8; N],
+    len: usize,
+    essence: [usize; 3],
+    params: [[usize; 4]; P],
+    params_len: usize,
+}
+
+impl<const N: usize, const P: usize> MediaTypeInline<N, P> {
+    /// Constructs a `MediaTypeInline` from `str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string fails to be parsed or exceeds the capacity.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let [ty, subty, suffix] = parse_essence(s)?;
+        let mut params = [[0; 4]; P];
+        let mut params_len = 0;
+        let len = parse_params_with(s, essence_len(ty, subty, suffix), |param| {
+            let slot = params.get_mut(params_len).ok_or_else(|| {
+                ParseError::at(MediaTypeError::CapacityExceeded, s, param[0]..param[3])
+            })?;
+            *slot = param;
+            params_len += 1;
+            Ok(())
+        })?;
+
+        let mut data = [0; N];
+        data.get_mut(..len)
+            .ok_or_else(|| ParseError::at(MediaTypeError::CapacityExceeded, s, 0..len))?
+            .copy_from_slice(&s.as_bytes()[..len]);
+        Ok(Self {
+            data,
+            len,
+            essence: [ty, subty, suffix],
+            params,
+            params_len,
+        })
+    }
+
+    /// Constructs a `MediaTypeInline` with an optional suffix and parameters.
+    ///
+    /// If `suffix` is `None`, a suffix in `subty` is split at the last `+` as the parser does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a name is not valid or the media type exceeds the capacity.
+    pub fn from_parts(
+        ty: Name,
+        subty: Name,
+        suffix: Option<Name>,
+        params: &[(Name, Value)],
+    ) -> Result<Self, MediaTypeError> {
+        if !is_restricted_name(ty.as_str()) {
+            return Err(MediaTypeError::InvalidTypeName);
+        }
+        if !is_restricted_name(subty.as_str()) {
+            return Err(MediaTypeError::InvalidSubtypeName);
+        }
+        if suffix.is_some_and(|suffix| !is_restricted_name(suffix.as_str())) {
+            return Err(MediaTypeError::InvalidSuffix);
+        }
+        let (subty_len, suffix_len) = split_suffix(subty, suffix)?;
+        if params.len() > P {
+            return Err(MediaTypeError::CapacityExceeded);
+        }
+
+        let mut t = Self {
+            data: [0; N],
+            len: 0,
+            essence: [ty.as_str().len(), subty_len, suffix_len],
+            params: [[0; 4]; P],
+            params_len: params.len(),
+        };
+        t.push(ty.as_str())?;
+        t.push("/")?;
+        t.push(subty.as_str())?;
+        if let Some(suffix) = suffix {
+            t.push("+")?;
+            t.push(suffix.as_str())?;
+        }
+        for (i, (name, value)) in params.iter().enumerate() {
+            if !is_restricted_name(name.as_str()) {
+                return Err(MediaTypeError::InvalidParamName);
+            }
+            t.push("; ")?;
+            let key_start = t.len;
+            t.push(name.as_str())?;
+            let key_end = t.len;
+            if !is_value(value.as_str()) {
+                return Err(MediaTypeError::InvalidParamValue);
+            }
+            t.push("=")?;
+            t.push(value.as_str())?;
+            t.params[i] = [key_start, key_end, key_end + 1, t.len];
+        }
+        Ok(t)
+    }
+
+    fn push(&mut self, s: &str) -> Result<(), MediaTypeError> {
+        let end = self.len + s.len();
+        self.data
+            .get_mut(self.len..end)
+            .ok_or(MediaTypeError::CapacityExceeded)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+
+    // Returns a part of the string. Only the requested range is checked as UTF-8,
+    // as `#![forbid(unsafe_code)]` rules out skipping the check.
+    fn slice(&self, range: Range<usize>) -> &str {
+        std::str::from_utf8(&self.data[range]).expect("`data` should be valid UTF-8")
+    }
+
+    /// Returns the top-level type.
+    #[must_use]
+    pub fn ty(&self) -> Name<'_> {
+        Name::new_unchecked(self.slice(0..self.essence[0]))
+    }
+
+    /// Returns the subtype.
+    #[must_use]
+    pub fn subty(&self) -> Name<'_> {
+        let start = self.essence[0] + 1;
+        Name::new_unchecked(self.slice(start..start + self.essence[1]))
+    }
+
+    /// Returns the suffix.
+    #[must_use]
+    pub fn suffix(&self) -> Option<Name<'_>> {
+        let start = self.essence[0] + 1 + self.essence[1] + 1;
+        if self.essence[2] == 0 {
+            None
+        } else {
+            Some(Name::new_unchecked(
+                self.slice(start..start + self.essence[2]),
+            ))
+        }
+    }
+
+    /// Returns a [`MediaType`] without parameters.
+    #[must_use]
+    pub fn essence(&self) -> MediaType<'_> {
+        MediaType::from_parts(self.ty(), self.subty(), self.suffix(), &[])
+    }
+
+    /// Returns the underlying string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.slice(0..self.len)
+    }
+
+    /// Constructs a `MediaType` from `self`.
+    ///
+    /// Unlike the other methods, this allocates a vector if there are parameters,
+    /// as [`MediaType`] stores the parameters as a slice.
+    #[must_use]
+    pub fn to_ref(&self) -> MediaType<'_> {
+        let mut media_type = self.essence();
+        if self.params_len > 0 {
+            media_type.params = Cow::Owned(self.params().collect());
+        }
+        media_type
+    }
+
+    // Returns the parameters except those overridden by a later one with the same name.
+    fn last_params(&self) -> impl Iterator<Item = (Name<'_>, Value<'_>)> {
+        let params = &self.params[..self.params_len];
+        let name =
+            move |[start, end, _, _]: [usize; 4]| Name::new_unchecked(self.slice(start..end));
+        params
+            .iter()
+            .enumerate()
+            .filter(move |&(i, &param)| {
+                !params[i + 1..]
+                    .iter()
+                    .any(|&later| name(later) == name(param))
+            })
+            .map(move |(_, &param)| {
+                (
+                    name(param),
+                    Value::new_unchecked(self.slice(param[2]..param[3])),
+                )
+            })
+    }
+
+    // Compares the parameters in O(P²) without collecting them.
+    fn eq_params(&self, other: &impl ReadParams) -> bool {
+        self.last_params()
+            .all(|(name, value)| other.get_param(name) == Some(value))
+            && other
+                .params()
+                .all(|(name, _)| self.get_param(name).is_some())
+    }
+}
+
+impl<const N: usize, const P: usize> ReadParams for MediaTypeInline<N, P> {
+    fn params(&self) -> Params<'_> {
+        Params::from_ranges(self.as_str(), &self.params[..self.params_len])
+    }
+
+    fn get_param(&self, name: Name) -> Option<Value<'_>> {
+        self.params[..self.params_len]
+            .iter()
+            .rev()
+            .find(|&&[start, end, _, _]| name == Name::new_unchecked(self.slice(start..end)))
+            .map(|&[_, _, start, end]| Value::new_unchecked(self.slice(start..end)))
+    }
+}
+
+impl<const N: usize, const P: usize> FromStr for MediaTypeInline<N, P> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl<const N: usize, const P: usize> AsRef<str> for MediaTypeInline<N, P> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize, const P: usize> fmt::Debug for MediaTypeInline<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MediaTypeInline")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize, const P: usize> fmt::Display for MediaTypeInline<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.ty(), self.subty())?;
+        if let Some(suffix) = self.suffix() {
+            write!(f, "+{}", suffix)?;
+        }
+        for (name, value) in self.params() {
+            write!(f, "; {}={}", name, value)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize, const P: usize, const M: usize, const Q: usize>
+    PartialEq<MediaTypeInline<M, Q>> for MediaTypeInline<N, P>
+{
+    fn eq(&self, other: &MediaTypeInline<M, Q>) -> bool {
+        self.ty() == other.ty()
+            && self.subty() == other.subty()
+            && self.suffix() == other.suffix()
+            && self.eq_params(other)
+    }
+}
+
+impl<const N: usize, const P: usize> Eq for MediaTypeInline<N, P> {}
+
+impl<const N: usize, const P: usize> PartialEq<MediaType<'_>> for MediaTypeInline<N, P> {
+    fn eq(&self, other: &MediaType) -> bool {
+        self.ty() == other.ty
+            && self.subty() == other.subty
+            && self.suffix() == other.suffix
+            && self.eq_params(other)
+    }
+}
+
+impl<const N: usize, const P: usize> PartialEq<MediaTypeBuf> for MediaTypeInline<N, P> {
+    fn eq(&self, other: &MediaTypeBuf) -> bool {
+        self.ty() == other.ty()
+            && self.subty() == other.subty()
+            && self.suffix() == other.suffix()
+            && self.eq_params(other)
+    }
+}
+
+impl<const N: usize, const P: usize> Hash for MediaTypeInline<N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ty().hash(state);
+        self.subty().hash(state);
+        self.suffix().hash(state);
+
+        // Hashes the parameters sorted by name, in the same way as `MediaType`,
+        // by selecting the next name in O(P²) without collecting them.
+        let len = self.last_params().count();
+        state.write_usize(len);
+        let mut prev = None;
+        for _ in 0..len {
+            let param = self
+                .last_params()
+                .filter(|&(name, _)| Some(name) > prev)
+                .min_by(|a, b| a.0.cmp(&b.0))
+                .expect("the number of parameters should be `len`");
+            param.hash(state);
+            prev = Some(param.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{names::*, values::*};
+    use std::collections::hash_map::DefaultHasher;
+
+    fn calculate_hash<T: Hash>(t: &T) -> u64 {
+        let mut s = DefaultHasher::new();
+        t.hash(&mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn parse() {
+        let media_type = MediaTypeInline::<64>::parse("image/svg+xml; charset=UTF-8;  ").unwrap();
+        assert_eq!(media_type.as_str(), "image/svg+xml; charset=UTF-8");
+        assert_eq!(media_type.ty(), IMAGE);
+        assert_eq!(media_type.subty(), SVG);
+        assert_eq!(media_type.suffix(), Some(XML));
+        assert_eq!(media_type.get_param(CHARSET), Some(UTF_8));
+        assert_eq!(media_type.to_string(), "image/svg+xml; charset=UTF-8");
+
+        assert_eq!(
+            MediaTypeInline::<10>::parse("text/plain").unwrap().as_str(),
+            "text/plain"
+        );
+        assert_eq!(
+            MediaTypeInline::<9>::parse("text/plain"),
+            Err(ParseError::new(
+                MediaTypeError::CapacityExceeded,
+                "text/plain",
+                0..10
+            ))
+        );
+        assert_eq!(
+            MediaTypeInline::<64, 1>::parse("text/plain; a=b; c=d"),
+            Err(ParseError::new(
+                MediaTypeError::CapacityExceeded,
+                "c=d",
+                17..20
+            ))
+        );
+        assert_eq!(
+            MediaTypeInline::<64>::parse("text/plain;;"),
+            Err(ParseError::new(MediaTypeError::InvalidParams, ";;", 10..12))
+        );
+    }
+
+    #[test]
+    fn from_parts() {
+        assert_eq!(
+            MediaTypeInline::<64>::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)])
+                .unwrap()
+                .as_str(),
+            "image/svg+xml; charset=UTF-8"
+        );
+        assert_eq!(
+            MediaTypeInline::<16>::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)]),
+            Err(MediaTypeError::CapacityExceeded)
+        );
+        assert_eq!(
+            MediaTypeInline::<64, 0>::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)]),
+            Err(MediaTypeError::CapacityExceeded)
+        );
+
+        let built =
+            MediaTypeInline::<64>::from_parts(IMAGE, Name::new("svg+xml").unwrap(), None, &[])
+                .unwrap();
+        let parsed = MediaTypeInline::<64>::parse("image/svg+xml").unwrap();
+        assert_eq!(built.subty(), SVG);
+        assert_eq!(built.suffix(), Some(XML));
+        assert_eq!(built, parsed);
+        assert_eq!(calculate_hash(&built), calculate_hash(&parsed));
+        assert_eq!(
+            MediaTypeInline::<64>::from_parts(IMAGE, Name::new("svg+-xml").unwrap(), None, &[]),
+            Err(MediaTypeError::InvalidSuffix)
+        );
+    }
+
+    #[test]
+    fn cmp() {
+        let a = MediaTypeInline::<64>::parse("image/svg+xml; charset=UTF-8").unwrap();
+        let b = MediaTypeInline::<32, 2>::parse("IMAGE/SVG+XML; CHARSET=UTF-8").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(
+            a,
+            MediaType::from_parts(IMAGE, SVG, Some(XML), &[(CHARSET, UTF_8)])
+        );
+        assert_eq!(
+            a,
+            MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap()
+        );
+        assert_eq!(calculate_hash(&a), calculate_hash(&b));
+        assert_eq!(
+            calculate_hash(&a),
+            calculate_hash(&MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap())
+        );
+
+        let c = MediaTypeInline::<64>::parse("text/plain; b=1; a=2; B=3; c=4").unwrap();
+        let d = MediaTypeInline::<64>::parse("text/plain; c=4; b=3; A=2").unwrap();
+        let buf = MediaTypeBuf::from_str("text/plain; a=2; b=3; c=4").unwrap();
+        assert_eq!(c, d);
+        assert_eq!(c, buf);
+        assert_eq!(c, buf.to_ref());
+        assert_eq!(calculate_hash(&c), calculate_hash(&d));
+        assert_eq!(calculate_hash(&c), calculate_hash(&buf));
+        assert_ne!(
+            c,
+            MediaTypeInline::<64>::parse("text/plain; a=2; b=1; c=4").unwrap()
+        );
+        assert_ne!(
+            c,
+            MediaTypeInline::<64>::parse("text/plain; a=2; b=3").unwrap()
+        );
+        assert_ne!(
+            MediaTypeInline::<64>::parse("text/plain; a=2; b=3").unwrap(),
+            c
+        );
+    }
+}
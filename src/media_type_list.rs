@@ -19,31 +19,31 @@ use super::{error::*, media_type::*, parse::*};
 /// assert_eq!(list.next(), Some(MediaType::parse("text/html; message=\"Hello, world!\"")));
 /// assert_eq!(list.next(), None);
 /// ```
-pub struct MediaTypeList<'a>(&'a str);
+pub struct MediaTypeList<'a> {
+    s: &'a str,
+    offset: usize,
+}
 
 impl<'a> MediaTypeList<'a> {
     /// Constructs a `MediaTypeList`.
     pub fn new(s: &'a str) -> Self {
-        Self(s)
+        Self { s, offset: 0 }
     }
-}
-
-impl<'a> Iterator for MediaTypeList<'a> {
-    type Item = Result<MediaType<'a>, MediaTypeError>;
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(index) = self.0.find(|c| !is_ows(c)) {
-            self.0 = &self.0[index..];
+    // Returns the next item and its byte position in the original string.
+    pub(crate) fn next_str(&mut self) -> Option<(usize, &'a str)> {
+        if let Some(index) = self.s.find(|c| !is_ows(c)) {
+            self.advance(index);
         } else {
             return None;
         }
-        if self.0.is_empty() {
+        if self.s.is_empty() {
             return None;
         }
         let mut end = 0;
         let mut quoted = false;
         let mut escaped = false;
-        while let Some(c) = self.0.as_bytes().get(end) {
+        while let Some(c) = self.s.as_bytes().get(end) {
             if escaped {
                 escaped = false;
             } else {
@@ -56,14 +56,30 @@ impl<'a> Iterator for MediaTypeList<'a> {
             }
             end += 1;
         }
-        let madia_type = MediaType::parse(&self.0[..end]);
-        let end = self.0.as_bytes().len().min(end + 1);
-        self.0 = &self.0[end..];
-        Some(madia_type)
+        let item = (self.offset, &self.s[..end]);
+        self.advance(self.s.len().min(end + 1));
+        Some(item)
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.s = &self.s[len..];
+        self.offset += len;
+    }
+}
+
+impl<'a> Iterator for MediaTypeList<'a> {
+    type Item = Result<MediaType<'a>, ParseError>;
+
+    /// Returns the next media type.
+    ///
+    /// The span of an error is a byte range in the whole list.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_str()
+            .map(|(offset, s)| MediaType::parse(s).map_err(|err| err.offset(offset)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (0, Some(self.0.matches(',').count() + 1))
+        (0, Some(self.s.matches(',').count() + 1))
     }
 }
 
@@ -82,11 +98,35 @@ mod tests {
 
     #[test]
     fn invalid() {
+        let error = |token, span| {
+            Some(Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                token,
+                span,
+            )))
+        };
         let mut list = MediaTypeList::new(",,,");
-        assert_eq!(list.next(), Some(MediaType::parse("")));
-        assert_eq!(list.next(), Some(MediaType::parse("")));
-        assert_eq!(list.next(), Some(MediaType::parse("")));
+        assert_eq!(list.next(), error("", 0..0));
+        assert_eq!(list.next(), error("", 1..1));
+        assert_eq!(list.next(), error("", 2..2));
+        assert_eq!(list.next(), None);
+
+        let mut list = MediaTypeList::new("text/html, text/plain;charset=\"UTF-8, image/png");
+        assert_eq!(list.next(), Some(MediaType::parse("text/html")));
+        assert_eq!(
+            list.next(),
+            Some(Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "\"UTF-8, image/png",
+                30..47
+            )))
+        );
         assert_eq!(list.next(), None);
+
+        let mut list = MediaTypeList::new("text/html,  invalid , image/png");
+        assert_eq!(list.next(), Some(MediaType::parse("text/html")));
+        assert_eq!(list.next(), error("invalid", 12..19));
+        assert_eq!(list.next(), Some(MediaType::parse("image/png")));
     }
 
     #[test]
@@ -0,0 +1,345 @@
+use super::{error::*, media_type::*, media_type_buf::*, media_type_list::*, parse::*};
+use std::{fmt, ops::Range, str::FromStr};
+
+/// An owned comma-separated list of media types.
+///
+/// The list is stored in three allocations regardless of its length: the string,
+/// the indices of the items and the indices of the parameters of all items.
+/// Accessing an item does not parse it again,
+/// but collects its parameters into a `Vec` if it has any.
+/// Empty list elements are ignored.
+///
+/// ```
+/// use mediatype::{names::*, MediaType, MediaTypeListBuf};
+///
+/// let list: MediaTypeListBuf = "text/html, application/xml;q=0.9, , */*;q=0.8".parse().unwrap();
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.get(0), Some(MediaType::new(TEXT, HTML)));
+/// assert_eq!(
+///     list.iter().map(|media_type| media_type.subty).collect::<Vec<_>>(),
+///     [HTML, XML, _STAR]
+/// );
+/// assert_eq!(list.to_string(), "text/html, application/xml; q=0.9, */*; q=0.8");
+/// ```
+#[derive(Debug, Clone)]
+pub struct MediaTypeListBuf {
+    data: Box<str>,
+    items: Box<[Item]>,
+    params: Box<[[usize; 4]]>,
+}
+
+// The indices of an item without its parameters, which are `params` in the list.
+#[derive(Debug, Clone)]
+struct Item {
+    offset: usize,
+    essence: Indices,
+    params: Range<usize>,
+}
+
+impl MediaTypeListBuf {
+    /// Parses a comma-separated list of media types.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the items fails to be parsed.
+    ///
+    /// The span of the error is a byte range in the whole list.
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let (list, errors) = Self::parse_lenient(s);
+        errors.into_iter().next().map_or(Ok(list), Err)
+    }
+
+    /// Parses a comma-separated list of media types, skipping invalid items.
+    ///
+    /// Returns the list of the valid items and the errors of the invalid items.
+    /// The span of an error is a byte range in the whole list.
+    ///
+    /// ```
+    /// # use mediatype::{MediaTypeError, MediaTypeListBuf};
+    /// let (list, errors) = MediaTypeListBuf::parse_lenient("text/html, junk, */*; q=2; =");
+    /// assert_eq!(list.to_string(), "text/html");
+    /// assert_eq!(errors.len(), 2);
+    /// assert_eq!(errors[0].kind(), MediaTypeError::InvalidTypeName);
+    /// assert_eq!(errors[0].span(), 11..15);
+    /// ```
+    #[must_use]
+    pub fn parse_lenient(s: &str) -> (Self, Vec<ParseError>) {
+        Self::parse_items(s, true)
+    }
+
+    /// Parses a comma-separated list of media types without wildcards,
+    /// as used in the `Accept-Patch` ([RFC 5789](https://www.rfc-editor.org/rfc/rfc5789#section-3.1))
+    /// and `Accept-Post` ([LDP](https://www.w3.org/TR/ldp/#header-accept-post)) headers.
+    ///
+    /// These lists have no weights, so `q` is treated as an ordinary parameter.
+    /// The list can be formatted with [`to_string`](#impl-Display-for-MediaTypeListBuf)
+    /// after being collected from media types.
+    ///
+    /// ```
+    /// # use mediatype::{media_type, MediaTypeListBuf};
+    /// let list = MediaTypeListBuf::parse_media_types("application/json-patch+json, text/plain").unwrap();
+    /// assert_eq!(list.get(1), Some(media_type!(TEXT / PLAIN)));
+    /// assert!(MediaTypeListBuf::parse_media_types("text/*").is_err());
+    ///
+    /// let list = [
+    ///     media_type!(APPLICATION / JSON_PATCH + JSON),
+    ///     media_type!(APPLICATION / MERGE_PATCH + JSON),
+    /// ]
+    /// .into_iter()
+    /// .collect::<MediaTypeListBuf>();
+    /// assert_eq!(
+    ///     list.to_string(),
+    ///     "application/json-patch+json, application/merge-patch+json"
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the items fails to be parsed or has a wildcard.
+    ///
+    /// The span of the error is a byte range in the whole list.
+    pub fn parse_media_types(s: &str) -> Result<Self, ParseError> {
+        let (list, errors) = Self::parse_items(s, false);
+        errors.into_iter().next().map_or(Ok(list), Err)
+    }
+
+    fn parse_items(s: &str, ranges: bool) -> (Self, Vec<ParseError>) {
+        let mut list = MediaTypeList::new(s);
+        let mut builder = Builder::default();
+        let mut errors = Vec::new();
+        while let Some((offset, item)) = list.next_str() {
+            if !item.is_empty() {
+                match Indices::parse(item) {
+                    Ok((indices, _)) if !ranges && &item[indices.ty()] == "*" => errors.push(
+                        ParseError::at(MediaTypeError::InvalidTypeName, item, indices.ty())
+                            .offset(offset),
+                    ),
+                    Ok((indices, _)) if !ranges && &item[indices.subty()] == "*" => errors.push(
+                        ParseError::at(MediaTypeError::InvalidSubtypeName, item, indices.subty())
+                            .offset(offset),
+                    ),
+                    Ok((indices, _)) => builder.push(offset, indices),
+                    Err(err) => errors.push(err.offset(offset)),
+                }
+            }
+        }
+        (builder.build(s.into()), errors)
+    }
+
+    /// Returns the original string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.data
+    }
+
+    /// Returns the media type at `index`.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<MediaType<'_>> {
+        self.items.get(index).map(|item| self.media_type(item))
+    }
+
+    /// Returns an iterator over the media types.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = MediaType<'_>> + '_ {
+        self.items.iter().map(|item| self.media_type(item))
+    }
+
+    /// Returns the number of the media types.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no media types.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn media_type(&self, item: &Item) -> MediaType<'_> {
+        MediaType::from_indices_with(
+            &self.data[item.offset..],
+            &item.essence,
+            &self.params[item.params.clone()],
+        )
+    }
+}
+
+#[derive(Default)]
+struct Builder {
+    items: Vec<Item>,
+    params: Vec<[usize; 4]>,
+}
+
+impl Builder {
+    fn push(&mut self, offset: usize, mut indices: Indices) {
+        let start = self.params.len();
+        self.params.extend_from_slice(&indices.take_params());
+        self.items.push(Item {
+            offset,
+            essence: indices,
+            params: start..self.params.len(),
+        });
+    }
+
+    fn build(self, data: Box<str>) -> MediaTypeListBuf {
+        MediaTypeListBuf {
+            data,
+            items: self.items.into_boxed_slice(),
+            params: self.params.into_boxed_slice(),
+        }
+    }
+}
+
+impl FromStr for MediaTypeListBuf {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for MediaTypeListBuf {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl<'a> FromIterator<MediaType<'a>> for MediaTypeListBuf {
+    /// # Panics
+    ///
+    /// Panics if a name is constructed by [`Name::new_unchecked`](./struct.Name.html#method.new_unchecked)
+    /// and is not valid.
+    fn from_iter<T: IntoIterator<Item = MediaType<'a>>>(iter: T) -> Self {
+        let mut data = String::new();
+        let mut builder = Builder::default();
+        for media_type in iter {
+            if !data.is_empty() {
+                data.push_str(", ");
+            }
+            let offset = data.len();
+            let (s, indices) = MediaTypeBuf::from(media_type).into_raw();
+            data.push_str(&s);
+            builder.push(offset, indices);
+        }
+        builder.build(data.into())
+    }
+}
+
+impl PartialEq for MediaTypeListBuf {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl Eq for MediaTypeListBuf {}
+
+impl fmt::Display for MediaTypeListBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, media_type) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            media_type.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{names::*, values::*};
+
+    #[test]
+    fn parse() {
+        let list = MediaTypeListBuf::parse("").unwrap();
+        assert!(list.is_empty());
+        assert_eq!(list.get(0), None);
+        assert_eq!(list.to_string(), "");
+
+        let s = " text/plain; charset=UTF-8 ,, image/svg+xml; title=\"a, b\"";
+        let list = MediaTypeListBuf::parse(s).unwrap();
+        assert_eq!(list.as_str(), s);
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            [
+                MediaType::from_parts(TEXT, PLAIN, None, &[(CHARSET, UTF_8)]),
+                MediaType::parse("image/svg+xml; title=\"a, b\"").unwrap(),
+            ]
+        );
+        assert_eq!(
+            list.to_string(),
+            "text/plain; charset=UTF-8, image/svg+xml; title=\"a, b\""
+        );
+        assert_eq!(list, list.to_string().parse().unwrap());
+        assert_eq!(list.get(1).unwrap().suffix, Some(XML));
+        assert_eq!(list.get(2), None);
+
+        let (list, errors) = MediaTypeListBuf::parse_lenient("text/html, */*, text/plain; =");
+        assert_eq!(list.to_string(), "text/html, */*");
+        assert_eq!(
+            errors,
+            [ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "",
+                28..28
+            )]
+        );
+        assert_eq!(
+            MediaTypeListBuf::parse("text/html, */*, text/plain; ="),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "",
+                28..28
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_media_types() {
+        let list =
+            MediaTypeListBuf::parse_media_types("text/plain; q=0.5 , image/svg+xml").unwrap();
+        assert_eq!(list.to_string(), "text/plain; q=0.5, image/svg+xml");
+        let list = MediaTypeListBuf::parse_media_types("a/b, , c/d,").unwrap();
+        assert_eq!(list.to_string(), "a/b, c/d");
+        assert_eq!(
+            MediaTypeListBuf::parse_media_types("text/plain, */*"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "*",
+                12..13
+            ))
+        );
+        assert_eq!(
+            MediaTypeListBuf::parse_media_types("text/plain, image/*"),
+            Err(ParseError::new(
+                MediaTypeError::InvalidSubtypeName,
+                "*",
+                18..19
+            ))
+        );
+    }
+
+    #[test]
+    fn from_iter() {
+        let list = [
+            MediaType::from_parts(TEXT, PLAIN, None, &[(CHARSET, UTF_8)]),
+            MediaType::new(IMAGE, _STAR),
+            MediaType::from_parts(IMAGE, SVG, Some(XML), &[]),
+        ]
+        .into_iter()
+        .collect::<MediaTypeListBuf>();
+        assert_eq!(
+            list.as_str(),
+            "text/plain; charset=UTF-8, image/*, image/svg+xml"
+        );
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().nth(2).unwrap().suffix, Some(XML));
+        assert_eq!(list, list.as_str().parse().unwrap());
+        assert!(std::iter::empty::<MediaType>()
+            .collect::<MediaTypeListBuf>()
+            .is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+use super::{media_type::*, name::*, names::*};
+use std::{borrow::Cow, collections::HashMap};
+
+// The suffixes are keyed by the empty string if the subtype has no suffix.
+type Subtypes<V> = HashMap<Box<str>, HashMap<Box<str>, V>>;
+
+/// A map keyed by media types and media ranges.
+///
+/// The essences are stored in nested hash maps keyed by the type, the subtype and the suffix,
+/// so lookups take constant time regardless of the number of the entries.
+/// The names are compared case-insensitively and the parameters are ignored.
+///
+/// ```
+/// use mediatype::{MediaType, MediaTypeMap};
+///
+/// let mut handlers = MediaTypeMap::new();
+/// handlers.insert(&MediaType::parse("*/*").unwrap(), "download");
+/// handlers.insert(&MediaType::parse("image/*").unwrap(), "image");
+/// handlers.insert(&MediaType::parse("image/svg+xml").unwrap(), "svg");
+///
+/// let handler = |s| handlers.get_matching(&MediaType::parse(s).unwrap()).copied();
+/// assert_eq!(handler("image/svg+xml; charset=UTF-8"), Some("svg"));
+/// assert_eq!(handler("image/png"), Some("image"));
+/// assert_eq!(handler("application/pdf"), Some("download"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MediaTypeMap<V> {
+    types: HashMap<Box<str>, Subtypes<V>>,
+    len: usize,
+}
+
+impl<V> MediaTypeMap<V> {
+    /// Constructs an empty `MediaTypeMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            types: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Inserts a value keyed by the essence of a media type or a media range.
+    ///
+    /// Returns the old value if the map already contained the essence.
+    pub fn insert(&mut self, media_type: &MediaType, value: V) -> Option<V> {
+        let old = self
+            .types
+            .entry(lowercase(media_type.ty).into())
+            .or_default()
+            .entry(lowercase(media_type.subty).into())
+            .or_default()
+            .insert(suffix_key(media_type.suffix).into(), value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    /// Returns the value keyed by the essence of `media_type`.
+    ///
+    /// The wildcards are compared literally;
+    /// use [`get_matching`](#method.get_matching) to match the media types against the ranges.
+    #[must_use]
+    pub fn get(&self, media_type: &MediaType) -> Option<&V> {
+        self.get_parts(media_type.ty, media_type.subty, media_type.suffix)
+    }
+
+    /// Returns the value keyed by the most specific key matching `media_type`.
+    ///
+    /// The keys are tried in the order of the exact essence, `type/*+suffix`, `type/*`,
+    /// `*/*+suffix` and `*/*`.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, MediaTypeMap};
+    /// let mut map = MediaTypeMap::new();
+    /// map.insert(&MediaType::parse("application/*").unwrap(), "binary");
+    /// map.insert(&MediaType::parse("application/*+json").unwrap(), "json");
+    ///
+    /// let problem = MediaType::parse("application/problem+json").unwrap();
+    /// assert_eq!(map.get_matching(&problem), Some(&"json"));
+    /// ```
+    #[must_use]
+    pub fn get_matching(&self, media_type: &MediaType) -> Option<&V> {
+        let suffixed = |ty| {
+            media_type
+                .suffix
+                .and_then(|suffix| self.get_parts(ty, _STAR, Some(suffix)))
+        };
+        self.get_parts(media_type.ty, media_type.subty, media_type.suffix)
+            .or_else(|| suffixed(media_type.ty))
+            .or_else(|| self.get_parts(media_type.ty, _STAR, None))
+            .or_else(|| suffixed(_STAR))
+            .or_else(|| self.get_parts(_STAR, _STAR, None))
+    }
+
+    /// Removes the value keyed by the essence of `media_type` and returns it.
+    pub fn remove(&mut self, media_type: &MediaType) -> Option<V> {
+        let subtypes = self.types.get_mut(&*lowercase(media_type.ty))?;
+        let suffixes = subtypes.get_mut(&*lowercase(media_type.subty))?;
+        let value = suffixes.remove(&*suffix_key(media_type.suffix))?;
+        if suffixes.is_empty() {
+            subtypes.remove(&*lowercase(media_type.subty));
+            if subtypes.is_empty() {
+                self.types.remove(&*lowercase(media_type.ty));
+            }
+        }
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns the number of the entries in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no entry.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all the entries.
+    pub fn clear(&mut self) {
+        self.types.clear();
+        self.len = 0;
+    }
+
+    fn get_parts(&self, ty: Name, subty: Name, suffix: Option<Name>) -> Option<&V> {
+        self.types
+            .get(&*lowercase(ty))?
+            .get(&*lowercase(subty))?
+            .get(&*suffix_key(suffix))
+    }
+}
+
+impl<V> Default for MediaTypeMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, V> Extend<(MediaType<'a>, V)> for MediaTypeMap<V> {
+    fn extend<T: IntoIterator<Item = (MediaType<'a>, V)>>(&mut self, iter: T) {
+        for (media_type, value) in iter {
+            self.insert(&media_type, value);
+        }
+    }
+}
+
+impl<'a, V> FromIterator<(MediaType<'a>, V)> for MediaTypeMap<V> {
+    fn from_iter<T: IntoIterator<Item = (MediaType<'a>, V)>>(iter: T) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+fn lowercase(name: Name<'_>) -> Cow<'_, str> {
+    let s = name.as_str();
+    if s.bytes().any(|b| b.is_ascii_uppercase()) {
+        Cow::Owned(s.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+fn suffix_key(suffix: Option<Name<'_>>) -> Cow<'_, str> {
+    suffix.map_or(Cow::Borrowed(""), lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map() {
+        let mut map = MediaTypeMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(&MediaType::parse("text/*").unwrap(), 1), None);
+        assert_eq!(
+            map.insert(&MediaType::parse("Text/Plain").unwrap(), 2),
+            None
+        );
+        assert_eq!(
+            map.insert(&MediaType::parse("text/plain+x").unwrap(), 3),
+            None
+        );
+        assert_eq!(
+            map.insert(&MediaType::parse("text/plain").unwrap(), 4),
+            Some(2)
+        );
+        assert_eq!(map.len(), 3);
+
+        let get = |map: &MediaTypeMap<i32>, s| {
+            let media_type = MediaType::parse(s).unwrap();
+            (
+                map.get(&media_type).copied(),
+                map.get_matching(&media_type).copied(),
+            )
+        };
+        assert_eq!(get(&map, "text/plain; charset=UTF-8"), (Some(4), Some(4)));
+        assert_eq!(get(&map, "text/plain+X"), (Some(3), Some(3)));
+        assert_eq!(get(&map, "text/html"), (None, Some(1)));
+        assert_eq!(get(&map, "image/png"), (None, None));
+
+        map.insert(&MediaType::new(_STAR, _STAR), 0);
+        assert_eq!(get(&map, "image/png"), (None, Some(0)));
+        map.insert(&MediaType::parse("*/*+xml").unwrap(), 5);
+        assert_eq!(get(&map, "image/svg+xml"), (None, Some(5)));
+        assert_eq!(get(&map, "text/html+xml"), (None, Some(1)));
+        map.insert(&MediaType::parse("text/*+xml").unwrap(), 6);
+        assert_eq!(get(&map, "text/html+xml"), (None, Some(6)));
+        assert_eq!(map.len(), 6);
+        map.remove(&MediaType::parse("*/*+xml").unwrap());
+        map.remove(&MediaType::parse("text/*+xml").unwrap());
+
+        assert_eq!(map.remove(&MediaType::new(TEXT, PLAIN)), Some(4));
+        assert_eq!(map.remove(&MediaType::new(TEXT, PLAIN)), None);
+        assert_eq!(get(&map, "text/plain"), (None, Some(1)));
+        assert_eq!(map.len(), 3);
+
+        map.clear();
+        assert!(map.is_empty());
+        assert_eq!(get(&map, "text/plain"), (None, None));
+    }
+}
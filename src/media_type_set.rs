@@ -0,0 +1,144 @@
+use super::{media_type::*, media_type_map::*};
+
+/// A set of media types and media ranges.
+///
+/// This is a [`MediaTypeMap`](./struct.MediaTypeMap.html) without values,
+/// so lookups take constant time regardless of the number of the entries.
+/// The names are compared case-insensitively and the parameters are ignored.
+///
+/// ```
+/// use mediatype::{MediaType, MediaTypeSet};
+///
+/// let allowed = ["image/*", "application/pdf", "application/vnd.api+json"]
+///     .into_iter()
+///     .map(|s| MediaType::parse(s).unwrap())
+///     .collect::<MediaTypeSet>();
+///
+/// let matches = |s| allowed.matches(&MediaType::parse(s).unwrap());
+/// assert!(matches("image/png"));
+/// assert!(matches("Application/PDF; version=1.7"));
+/// assert!(matches("application/vnd.api+json"));
+/// assert!(!matches("application/vnd.api"));
+/// assert!(!matches("text/plain"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MediaTypeSet(MediaTypeMap<()>);
+
+impl MediaTypeSet {
+    /// Constructs an empty `MediaTypeSet`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the essence of a media type or a media range to the set.
+    ///
+    /// Returns `true` if the set did not contain the essence.
+    pub fn insert(&mut self, media_type: &MediaType) -> bool {
+        self.0.insert(media_type, ()).is_none()
+    }
+
+    /// Removes the essence of `media_type` from the set.
+    ///
+    /// Returns `true` if the set contained the essence.
+    pub fn remove(&mut self, media_type: &MediaType) -> bool {
+        self.0.remove(media_type).is_some()
+    }
+
+    /// Returns `true` if the set contains the essence of `media_type`.
+    ///
+    /// The wildcards are compared literally;
+    /// use [`matches`](#method.matches) to match the media types against the ranges.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, MediaTypeSet};
+    /// let mut set = MediaTypeSet::new();
+    /// set.insert(&MediaType::new(TEXT, _STAR));
+    /// assert!(set.contains(&MediaType::new(TEXT, _STAR)));
+    /// assert!(!set.contains(&MediaType::new(TEXT, PLAIN)));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, media_type: &MediaType) -> bool {
+        self.0.get(media_type).is_some()
+    }
+
+    /// Returns `true` if `media_type` is in the set or matches a media range in the set.
+    ///
+    /// `*/*` matches any media type and `type/*` matches any subtype of the type.
+    /// `type/*+suffix` and `*/*+suffix` additionally require the suffix.
+    #[must_use]
+    pub fn matches(&self, media_type: &MediaType) -> bool {
+        self.0.get_matching(media_type).is_some()
+    }
+
+    /// Returns the number of the essences in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set contains no essence.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes all the essences.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl<'a> Extend<MediaType<'a>> for MediaTypeSet {
+    fn extend<T: IntoIterator<Item = MediaType<'a>>>(&mut self, iter: T) {
+        self.0
+            .extend(iter.into_iter().map(|media_type| (media_type, ())));
+    }
+}
+
+impl<'a> FromIterator<MediaType<'a>> for MediaTypeSet {
+    fn from_iter<T: IntoIterator<Item = MediaType<'a>>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::names::*;
+
+    #[test]
+    fn set() {
+        let mut set = MediaTypeSet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(&MediaType::parse("image/svg+xml").unwrap()));
+        assert!(!set.insert(&MediaType::parse("IMAGE/SVG+XML; charset=UTF-8").unwrap()));
+        assert!(set.insert(&MediaType::new(IMAGE, SVG)));
+        assert!(set.insert(&MediaType::new(TEXT, _STAR)));
+        assert_eq!(set.len(), 3);
+
+        let check = |set: &MediaTypeSet, s| {
+            let media_type = MediaType::parse(s).unwrap();
+            (set.contains(&media_type), set.matches(&media_type))
+        };
+        assert_eq!(check(&set, "image/svg+xml"), (true, true));
+        assert_eq!(check(&set, "image/Svg"), (true, true));
+        assert_eq!(check(&set, "image/svg+zip"), (false, false));
+        assert_eq!(check(&set, "text/plain"), (false, true));
+        assert_eq!(check(&set, "text/*"), (true, true));
+        assert_eq!(check(&set, "image/png"), (false, false));
+
+        set.insert(&MediaType::new(_STAR, _STAR));
+        assert_eq!(check(&set, "image/png"), (false, true));
+
+        assert!(set.remove(&MediaType::new(IMAGE, SVG)));
+        assert!(!set.remove(&MediaType::new(IMAGE, SVG)));
+        assert_eq!(check(&set, "image/svg"), (false, true));
+
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(check(&set, "image/svg+xml"), (false, false));
+    }
+}
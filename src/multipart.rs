@@ -0,0 +1,212 @@
+use super::{error::*, media_type::*, media_type_buf::*, names::*, params::*, value::*};
+use std::borrow::Cow;
+
+// The maximum length of a boundary.
+const MAX_BOUNDARY_LENGTH: usize = 70;
+
+// Returns `true` if `s` matches the `boundary` syntax of RFC 2046.
+fn is_valid_boundary(s: &str) -> bool {
+    (1..=MAX_BOUNDARY_LENGTH).contains(&s.len())
+        && !s.ends_with(' ')
+        && s.bytes().all(|b| {
+            b.is_ascii_alphanumeric()
+                || matches!(
+                    b,
+                    b'\''
+                        | b'('
+                        | b')'
+                        | b'+'
+                        | b'_'
+                        | b','
+                        | b'-'
+                        | b'.'
+                        | b'/'
+                        | b':'
+                        | b'='
+                        | b'?'
+                        | b' '
+                )
+        })
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns the unquoted `boundary` parameter.
+    ///
+    /// Returns `None` if the parameter is missing or does not match the syntax
+    /// of [RFC 2046](https://www.rfc-editor.org/rfc/rfc2046#section-5.1.1):
+    /// 1 to 70 characters from a restricted set, not ending with a space.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("multipart/mixed; boundary=\"simple boundary\"").unwrap();
+    /// assert_eq!(media_type.boundary().unwrap(), "simple boundary");
+    ///
+    /// let media_type = MediaType::parse("multipart/mixed; boundary=\"trailing \"").unwrap();
+    /// assert_eq!(media_type.boundary(), None);
+    /// ```
+    #[must_use]
+    pub fn boundary(&self) -> Option<Cow<'_, str>> {
+        self.get_param(BOUNDARY)
+            .map(|value| value.unquoted_str())
+            .filter(|boundary| is_valid_boundary(boundary))
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the unquoted `boundary` parameter.
+    ///
+    /// Returns `None` if the parameter is missing or does not match the syntax
+    /// of [RFC 2046](https://www.rfc-editor.org/rfc/rfc2046#section-5.1.1).
+    #[must_use]
+    pub fn boundary(&self) -> Option<Cow<'_, str>> {
+        self.get_param(BOUNDARY)
+            .map(|value| value.unquoted_str())
+            .filter(|boundary| is_valid_boundary(boundary))
+    }
+
+    /// Constructs `multipart/form-data` with the `boundary` parameter, quoting it if necessary.
+    ///
+    /// ```
+    /// # use mediatype::{MediaTypeBuf, MediaTypeError};
+    /// let media_type = MediaTypeBuf::multipart_form_data_with_boundary("simple boundary").unwrap();
+    /// assert_eq!(media_type.as_str(), "multipart/form-data; boundary=\"simple boundary\"");
+    /// assert_eq!(media_type.boundary().unwrap(), "simple boundary");
+    ///
+    /// assert_eq!(
+    ///     MediaTypeBuf::multipart_form_data_with_boundary("a;b"),
+    ///     Err(MediaTypeError::InvalidParamValue)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MediaTypeError::InvalidParamValue`] if `boundary` does not match the syntax
+    /// of [RFC 2046](https://www.rfc-editor.org/rfc/rfc2046#section-5.1.1).
+    pub fn multipart_form_data_with_boundary(boundary: &str) -> Result<Self, MediaTypeError> {
+        if !is_valid_boundary(boundary) {
+            return Err(MediaTypeError::InvalidParamValue);
+        }
+        // The boundary never contains `"` or `\`, so it is quoted without escaping,
+        // as some multipart parsers compare the raw value.
+        let quoted = format!("\"{boundary}\"");
+        let value = Value::new(boundary)
+            .or_else(|| Value::new(&quoted))
+            .ok_or(MediaTypeError::InvalidParamValue)?;
+        Self::try_from_parts(MULTIPART, FORM_DATA, None, &[(BOUNDARY, value)])
+    }
+
+    /// Constructs `multipart/form-data` with a random `boundary` parameter.
+    ///
+    /// The boundary consists of 32 alphanumeric characters.
+    /// It is unlikely to appear in the body parts, but is not cryptographically secure.
+    ///
+    /// This function requires the `random-boundary` feature.
+    ///
+    /// ```
+    /// # use mediatype::MediaTypeBuf;
+    /// let media_type = MediaTypeBuf::multipart_form_data();
+    /// assert_eq!(media_type.boundary().unwrap().len(), 32);
+    /// assert_ne!(media_type, MediaTypeBuf::multipart_form_data());
+    /// ```
+    #[cfg(feature = "random-boundary")]
+    #[must_use]
+    pub fn multipart_form_data() -> Self {
+        Self::multipart_form_data_with_boundary(&random_boundary())
+            .expect("generated boundary should be valid")
+    }
+}
+
+// Generates a random alphanumeric boundary from the randomly seeded hasher of the standard library.
+#[cfg(feature = "random-boundary")]
+fn random_boundary() -> String {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+        time::SystemTime,
+    };
+
+    const CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    let state = RandomState::new();
+    let nanos = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_nanos());
+    (0..32)
+        .map(|i| {
+            let mut hasher = state.build_hasher();
+            hasher.write_u128(nanos);
+            hasher.write_usize(i);
+            CHARS[(hasher.finish() % CHARS.len() as u64) as usize] as char
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn boundary() {
+        let boundary = |s: &str| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            media_type.boundary().map(Cow::into_owned)
+        };
+        assert_eq!(
+            boundary("multipart/form-data; boundary=----WebKitFormBoundary7MA4YWxkTrZu0gW"),
+            Some("----WebKitFormBoundary7MA4YWxkTrZu0gW".into())
+        );
+        assert_eq!(
+            boundary("multipart/mixed; boundary=\"gc0p4Jq0M:2Yt08j?34z\""),
+            Some("gc0p4Jq0M:2Yt08j?34z".into())
+        );
+        assert_eq!(
+            boundary("multipart/mixed; BOUNDARY=\"a\\(b\\)\""),
+            Some("a(b)".into())
+        );
+        assert_eq!(
+            boundary(&format!("multipart/mixed; boundary={}", "a".repeat(70))),
+            Some("a".repeat(70))
+        );
+        assert_eq!(
+            boundary(&format!("multipart/mixed; boundary={}", "a".repeat(71))),
+            None
+        );
+        assert_eq!(boundary("multipart/mixed; boundary=\"a;b\""), None);
+        assert_eq!(boundary("multipart/mixed; boundary=a%b"), None);
+        assert_eq!(boundary("multipart/mixed"), None);
+    }
+
+    #[test]
+    fn multipart_form_data_with_boundary() {
+        let build = |boundary| {
+            MediaTypeBuf::multipart_form_data_with_boundary(boundary)
+                .map(|media_type| media_type.to_string())
+        };
+        assert_eq!(
+            build("----WebKitFormBoundary7MA4YWxkTrZu0gW"),
+            Ok("multipart/form-data; boundary=----WebKitFormBoundary7MA4YWxkTrZu0gW".into())
+        );
+        assert_eq!(
+            build("gc0p4Jq0M:2Yt08j?34z"),
+            Ok("multipart/form-data; boundary=\"gc0p4Jq0M:2Yt08j?34z\"".into())
+        );
+        assert_eq!(build(""), Err(MediaTypeError::InvalidParamValue));
+        assert_eq!(build("trailing "), Err(MediaTypeError::InvalidParamValue));
+        assert_eq!(
+            build(&"a".repeat(71)),
+            Err(MediaTypeError::InvalidParamValue)
+        );
+    }
+
+    #[cfg(feature = "random-boundary")]
+    #[test]
+    fn random_boundary() {
+        let boundaries = (0..16)
+            .map(|_| super::random_boundary())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(boundaries.len(), 16);
+        assert!(boundaries
+            .iter()
+            .all(|boundary| boundary.len() == 32 && is_valid_boundary(boundary)));
+    }
+}
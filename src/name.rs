@@ -29,6 +29,32 @@ impl<'a> Name<'a> {
         }
     }
 
+    /// Constructs a `Name` with the exact `restricted-name` grammar of
+    /// [RFC 6838](https://www.rfc-editor.org/rfc/rfc6838#section-4.2).
+    ///
+    /// In addition to the requirements of [`new`](#method.new),
+    /// this rejects `*`, `%` and `'`, which are accepted for wildcards and real-world names
+    /// but not allowed in registered names.
+    /// If the string is not valid as a name, returns `None`.
+    ///
+    /// ```
+    /// # use mediatype::Name;
+    /// assert!(Name::new_strict("vnd.example.app+json").is_some());
+    /// assert!(Name::new_strict("*").is_none());
+    /// assert!(Name::new("*").is_some());
+    /// assert!(Name::new_strict("x-100%").is_none());
+    /// assert!(Name::new_strict("-a").is_none());
+    /// assert!(Name::new_strict(&"a".repeat(Name::MAX_LENGTH + 1)).is_none());
+    /// ```
+    #[must_use]
+    pub fn new_strict(s: &'a str) -> Option<Self> {
+        if is_restricted_name(s) && !s.contains(['*', '%', '\'']) {
+            Some(Self(s))
+        } else {
+            None
+        }
+    }
+
     /// Returns the underlying string.
     #[must_use]
     pub const fn as_str(&self) -> &'a str {
@@ -81,7 +107,10 @@ impl<'a> Ord for Name<'a> {
 
 impl<'a> Hash for Name<'a> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_ascii_lowercase().hash(state);
+        for b in self.0.bytes() {
+            state.write_u8(b.to_ascii_lowercase());
+        }
+        state.write_u8(0xff);
     }
 }
 
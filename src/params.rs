@@ -1,4 +1,5 @@
-use super::{name::*, parse::*, value::*};
+use super::{error::*, extended::*, name::*, parse::*, value::*};
+use std::borrow::Cow;
 
 /// An iterator over the parameters.
 #[derive(Debug)]
@@ -16,8 +17,12 @@ impl<'a> Params<'a> {
     }
 
     pub(crate) const fn from_indices(s: &'a str, i: &'a Indices) -> Self {
+        Self::from_ranges(s, i.params())
+    }
+
+    pub(crate) const fn from_ranges(s: &'a str, r: &'a [[usize; 4]]) -> Self {
         Self {
-            source: ParamsSource::Indices(s, i),
+            source: ParamsSource::Ranges(s, r),
             index: 0,
         }
     }
@@ -26,7 +31,7 @@ impl<'a> Params<'a> {
 #[derive(Debug)]
 enum ParamsSource<'a> {
     Slice(&'a [(Name<'a>, Value<'a>)]),
-    Indices(&'a str, &'a Indices),
+    Ranges(&'a str, &'a [[usize; 4]]),
 }
 
 impl<'a> Iterator for Params<'a> {
@@ -43,12 +48,12 @@ impl<'a> Iterator for Params<'a> {
                     Some((s[index].0, s[index].1))
                 }
             }
-            ParamsSource::Indices(s, i) => {
-                if index >= i.params().len() {
+            ParamsSource::Ranges(s, r) => {
+                if index >= r.len() {
                     None
                 } else {
                     self.index += 1;
-                    let param = i.params()[index];
+                    let param = r[index];
                     Some((
                         Name::new_unchecked(&s[param[0]..param[1]]),
                         Value::new_unchecked(&s[param[2]..param[3]]),
@@ -61,21 +66,100 @@ impl<'a> Iterator for Params<'a> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         let len = match self.source {
             ParamsSource::Slice(s) => s.len(),
-            ParamsSource::Indices(_, i) => i.params().len(),
+            ParamsSource::Ranges(_, r) => r.len(),
         };
         (len, Some(len))
     }
 }
 
+/// An iterator over the values of the parameters with a name.
+///
+/// This is created by [`ReadParams::get_params`](./trait.ReadParams.html#method.get_params).
+#[derive(Debug)]
+pub struct ParamValues<'a, 'n> {
+    params: Params<'a>,
+    name: Name<'n>,
+}
+
+impl<'a, 'n> Iterator for ParamValues<'a, 'n> {
+    type Item = Value<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let name = self.name;
+        self.params
+            .by_ref()
+            .find(|&(key, _)| key == name)
+            .map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.params.size_hint().1)
+    }
+}
+
 /// A trait for getting parameter values.
 pub trait ReadParams {
-    /// Returns the parameters.
-    fn params(&self) -> Params;
+    /// Returns the parameters in the original order.
+    ///
+    /// Duplicated names are not merged, so the parameters can be re-emitted as they were received.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, ReadParams};
+    /// let media_type = MediaType::parse("text/plain; z=1; a=2; Z=3").unwrap();
+    /// let names = media_type.params().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    /// assert_eq!(names, ["z", "a", "Z"]);
+    /// ```
+    fn params(&self) -> Params<'_>;
 
     /// Gets the parameter value by its name.
     ///
     /// If the same name appears more than once, returns the last value.
-    fn get_param(&self, name: Name) -> Option<Value>;
+    /// Use [`get_params`](#method.get_params) to get all of them.
+    fn get_param(&self, name: Name) -> Option<Value<'_>>;
+
+    /// Returns an iterator over all the values of the parameters with the name, in the original order.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, values::*, MediaType, ReadParams};
+    /// let media_type = MediaType::parse("text/plain; charset=UTF-8; Charset=ISO-8859-1").unwrap();
+    /// assert_eq!(
+    ///     media_type.get_params(CHARSET).collect::<Vec<_>>(),
+    ///     [UTF_8, ISO_8859_1]
+    /// );
+    /// assert_eq!(media_type.get_param(CHARSET), Some(ISO_8859_1));
+    /// ```
+    fn get_params<'n>(&self, name: Name<'n>) -> ParamValues<'_, 'n> {
+        ParamValues {
+            params: self.params(),
+            name,
+        }
+    }
+
+    /// Gets the decoded parameter value by its name.
+    ///
+    /// This supports extended parameters (`name*=charset'language'value`)
+    /// and parameter continuations (`name*0`, `name*1*`, ...)
+    /// defined in [RFC 2231](https://www.rfc-editor.org/rfc/rfc2231) and
+    /// [RFC 8187](https://www.rfc-editor.org/rfc/rfc8187).
+    /// The charsets `UTF-8`, `US-ASCII` and `ISO-8859-1` are supported.
+    ///
+    /// Extended parameters take precedence over continuations, and continuations take
+    /// precedence over the plain parameter. If an extended value fails to be decoded,
+    /// it is ignored. The plain value is returned unquoted.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, Name, ReadParams};
+    /// let title = Name::new("title").unwrap();
+    ///
+    /// let media_type = MediaType::parse("text/plain; title=EUR; title*=utf-8''%E2%82%AC").unwrap();
+    /// assert_eq!(media_type.get_decoded_param(title).unwrap(), "€");
+    ///
+    /// let media_type = MediaType::parse("text/plain; title*0=\"hello \"; title*1*=%F0%9F%8C%8D").unwrap();
+    /// assert_eq!(media_type.get_decoded_param(title).unwrap(), "hello 🌍");
+    /// ```
+    fn get_decoded_param(&self, name: Name) -> Option<Cow<'_, str>> {
+        decode_param(self.params(), name)
+    }
 }
 
 /// A trait for mutating parameter values.
@@ -96,11 +180,77 @@ pub trait WriteParams<'a>: ReadParams {
     ///     "text/plain; format=fixed; charset=UTF-8"
     /// );
     /// ```
+    ///
+    /// The name and the value are borrowed, so they must outlive `'a`.
+    /// Owned parameters are not supported by this trait;
+    /// [`MediaTypeBuf::set_param`](./struct.MediaTypeBuf.html#method.set_param)
+    /// copies them instead, which allows setting parameters from temporary strings.
     fn set_param<'n: 'a, 'v: 'a>(&mut self, name: Name<'n>, value: Value<'v>);
 
+    /// Validates the name and the unquoted value, and sets the parameter.
+    ///
+    /// Returns the unquoted previous value, if any.
+    /// The value is quoted with [`Value::try_quote`], but a borrowed media type
+    /// can not hold the quoted string, so values which need quoting are rejected.
+    /// [`MediaTypeBuf::set_param_str`](./struct.MediaTypeBuf.html#method.set_param_str)
+    /// accepts them.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, MediaTypeError, WriteParams};
+    /// let mut media_type = MediaType::new(TEXT, PLAIN);
+    /// assert_eq!(media_type.set_param_str("charset", "US-ASCII"), Ok(None));
+    /// assert_eq!(
+    ///     media_type.set_param_str("charset", "UTF-8"),
+    ///     Ok(Some("US-ASCII".into()))
+    /// );
+    /// assert_eq!(media_type.to_string(), "text/plain; charset=UTF-8");
+    ///
+    /// let err = media_type.set_param_str("title", "a b").unwrap_err();
+    /// assert_eq!(err.kind(), MediaTypeError::InvalidParamValue);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is not valid, or the value is not valid or needs quoting.
+    /// The span of the error is relative to the offending argument.
+    fn set_param_str<'s: 'a>(
+        &mut self,
+        name: &'s str,
+        value: &'s str,
+    ) -> Result<Option<String>, ParseError> {
+        let name = validate_param_name(name)?;
+        let value = match Value::try_quote(value) {
+            Some(Cow::Borrowed(value)) => Value::new_unchecked(value),
+            _ => {
+                return Err(ParseError::at(
+                    MediaTypeError::InvalidParamValue,
+                    value,
+                    0..value.len(),
+                ))
+            }
+        };
+        let previous = self
+            .get_param(name)
+            .map(|value| value.unquoted_str().into_owned());
+        self.set_param(name, value);
+        Ok(previous)
+    }
+
     /// Removes all parameters with the name.
     fn remove_params(&mut self, name: Name);
 
     /// Removes all parameters.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, WriteParams};
+    /// let mut media_type = MediaType::parse("text/plain; charset=UTF-8; format=fixed").unwrap();
+    /// media_type.clear_params();
+    /// assert_eq!(media_type.to_string(), "text/plain");
+    /// ```
     fn clear_params(&mut self);
 }
+
+pub(crate) fn validate_param_name(name: &str) -> Result<Name<'_>, ParseError> {
+    Name::new(name)
+        .ok_or_else(|| ParseError::at(MediaTypeError::InvalidParamName, name, 0..name.len()))
+}
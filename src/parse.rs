@@ -1,6 +1,30 @@
-use super::{error::*, name::*};
+use super::{error::*, name::*, value::*};
 use std::{num::NonZeroU8, ops::Range};
 
+/// The byte ranges of the components of a parsed media type.
+///
+/// This is the low-level result of the parser used by [`MediaTypeBuf`](./struct.MediaTypeBuf.html).
+/// The ranges refer to the parsed string, so they can be used to slice any buffer
+/// holding the same bytes without constructing a media type.
+///
+/// ```
+/// use mediatype::Indices;
+///
+/// let s = "image/svg+xml; charset=UTF-8";
+/// let (indices, len) = Indices::parse(s).unwrap();
+/// assert_eq!(len, s.len());
+///
+/// let b = s.as_bytes();
+/// assert_eq!(&b[indices.ty()], b"image");
+/// assert_eq!(&b[indices.subty()], b"svg");
+/// assert_eq!(indices.suffix().map(|range| &b[range]), Some(&b"xml"[..]));
+///
+/// let [name_start, name_end, value_start, value_end] = indices.params()[0];
+/// assert_eq!(&b[name_start..name_end], b"charset");
+/// assert_eq!(&b[value_start..value_end], b"UTF-8");
+///
+/// assert_eq!(Indices::parse("text/plain; ").unwrap().1, "text/plain".len());
+/// ```
 #[derive(Debug, Clone)]
 pub struct Indices {
     ty: NonZeroU8,
@@ -10,16 +34,22 @@ pub struct Indices {
 }
 
 impl Indices {
+    /// Returns the range of the top-level type.
+    #[must_use]
     pub const fn ty(&self) -> Range<usize> {
         0..self.ty.get() as _
     }
 
+    /// Returns the range of the subtype.
+    #[must_use]
     pub const fn subty(&self) -> Range<usize> {
         let start = self.ty.get() as usize + 1;
         let end = start + self.subty.get() as usize;
         start..end
     }
 
+    /// Returns the range of the suffix.
+    #[must_use]
     pub const fn suffix(&self) -> Option<Range<usize>> {
         let start = self.ty.get() as usize + 1 + self.subty.get() as usize + 1;
         let end = start + self.suffix as usize;
@@ -30,70 +60,238 @@ impl Indices {
         }
     }
 
+    /// Returns the parameters in the original order.
+    ///
+    /// Each parameter is `[name_start, name_end, value_start, value_end]`.
+    /// The range of a quoted value includes the quotes.
+    #[must_use]
     pub const fn params(&self) -> &[[usize; 4]] {
         &self.params
     }
 
-    pub fn parse(s: &str) -> Result<(Self, usize), MediaTypeError> {
-        // ty.len() + '/' + subty.len() + '+' + suffix.len()
-        const MAX_ESSENCE_LENGTH: usize = Name::MAX_LENGTH * 3 + 2;
+    pub(crate) fn from_lengths(
+        ty: usize,
+        subty: usize,
+        suffix: usize,
+        params: Box<[[usize; 4]]>,
+    ) -> Option<Self> {
+        Some(Self {
+            ty: NonZeroU8::new(ty.try_into().ok()?)?,
+            subty: NonZeroU8::new(subty.try_into().ok()?)?,
+            suffix: suffix.try_into().ok()?,
+            params,
+        })
+    }
 
-        let (ty, right) = match s[..MAX_ESSENCE_LENGTH.min(s.len())].split_once('/') {
-            Some(pair) => pair,
-            _ => return Err(MediaTypeError::InvalidTypeName),
-        };
+    // Moves the parameters out, leaving the indices of the essence.
+    pub(crate) fn take_params(&mut self) -> Box<[[usize; 4]]> {
+        std::mem::take(&mut self.params)
+    }
 
-        if !is_restricted_name(ty) {
-            return Err(MediaTypeError::InvalidTypeName);
+    pub(crate) fn from_raw(
+        s: &str,
+        ty: usize,
+        subty: usize,
+        suffix: usize,
+        params: Box<[[usize; 4]]>,
+    ) -> Option<Self> {
+        let indices = Self::from_lengths(ty, subty, suffix, params)?;
+
+        if !s
+            .get(indices.ty.get().into()..)
+            .is_some_and(|s| s.starts_with('/'))
+            || !is_restricted_name(s.get(indices.ty())?)
+            || !is_restricted_name(s.get(indices.subty())?)
+        {
+            return None;
         }
 
-        let suffix_end = right
-            .find(|c: char| !is_restricted_char(c))
-            .unwrap_or(right.len());
-        let suffix_start = right[..suffix_end].rfind('+');
+        let mut end = indices.subty().end;
+        if let Some(suffix) = indices.suffix() {
+            if !s[end..].starts_with('+') || !is_restricted_name(s.get(suffix.clone())?) {
+                return None;
+            }
+            end = suffix.end;
+        }
 
-        let (subty, suffix) = suffix_start.map_or_else(
-            || (&right[..suffix_end], ""),
-            |suffix_start| (&right[..suffix_start], &right[suffix_start + 1..suffix_end]),
-        );
+        for &[key_start, key_end, value_start, value_end] in indices.params() {
+            let separator = s.get(end..key_start)?.trim_matches(is_ows);
+            if separator != ";" || s.get(key_end..value_start)? != "=" {
+                return None;
+            }
+            if !is_restricted_name(s.get(key_start..key_end)?)
+                || !is_value(s.get(value_start..value_end)?)
+            {
+                return None;
+            }
+            end = value_end;
+        }
 
-        if !is_restricted_name(subty) {
-            return Err(MediaTypeError::InvalidSubtypeName);
+        if end != s.len() {
+            return None;
         }
 
-        if !suffix.is_empty() && !is_restricted_name(&suffix[1..]) {
-            return Err(MediaTypeError::InvalidSuffix);
+        Some(indices)
+    }
+
+    /// Parses a media type and returns the indices and the length of the parsed string.
+    ///
+    /// The accepted syntax is the same as [`MediaType::parse`](./struct.MediaType.html#method.parse),
+    /// and the length excludes trailing semicolons and whitespace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string fails to be parsed.
+    pub fn parse(s: &str) -> Result<(Self, usize), ParseError> {
+        let [ty, subty, suffix] = parse_essence(s)?;
+        let mut params = Vec::new();
+        let len = parse_params_with(s, essence_len(ty, subty, suffix), |param| {
+            params.push(param);
+            Ok(())
+        })?;
+        let indices = Self::from_lengths(ty, subty, suffix, params.into_boxed_slice())
+            .ok_or_else(|| ParseError::at(MediaTypeError::InvalidTypeName, s, 0..ty))?;
+        Ok((indices, len))
+    }
+}
+
+// Parses the essence and returns the lengths of the type, the subtype and the suffix.
+pub fn parse_essence(s: &str) -> Result<[usize; 3], ParseError> {
+    // ty.len() + '/' + subty.len() + '+' + suffix.len()
+    const MAX_ESSENCE_LENGTH: usize = Name::MAX_LENGTH * 3 + 2;
+
+    let mut essence_end = MAX_ESSENCE_LENGTH.min(s.len());
+    while !s.is_char_boundary(essence_end) {
+        essence_end -= 1;
+    }
+
+    let (ty, right) = match s[..essence_end].split_once('/') {
+        Some(pair) => pair,
+        _ => {
+            let end = s.find(|c| !is_restricted_char(c)).unwrap_or(s.len());
+            return Err(ParseError::at(MediaTypeError::InvalidTypeName, s, 0..end));
         }
+    };
 
-        let sub_end = ty.len() + 1 + subty.len();
-        let params_start = sub_end
-            + if suffix.is_empty() {
-                0
-            } else {
-                suffix.len() + 1
-            };
-
-        let (mut params, params_len) = parse_params(&s[params_start..])?;
-        for elem in &mut params {
-            for v in elem.iter_mut() {
-                *v += params_start;
-            }
+    if !is_restricted_name(ty) {
+        return Err(ParseError::at(
+            MediaTypeError::InvalidTypeName,
+            s,
+            0..ty.len(),
+        ));
+    }
+
+    let suffix_end = right
+        .find(|c: char| !is_restricted_char(c))
+        .unwrap_or(right.len());
+    // The suffix follows the last `+`. A trailing `+` is a part of the subtype.
+    let suffix_start = right[..suffix_end]
+        .rfind('+')
+        .filter(|&start| start + 1 < suffix_end);
+
+    let (subty, suffix) = suffix_start.map_or_else(
+        || (&right[..suffix_end], ""),
+        |suffix_start| (&right[..suffix_start], &right[suffix_start + 1..suffix_end]),
+    );
+
+    let subty_start = ty.len() + 1;
+    if !is_restricted_name(subty) {
+        return Err(ParseError::at(
+            MediaTypeError::InvalidSubtypeName,
+            s,
+            subty_start..subty_start + subty.len(),
+        ));
+    }
+
+    if !suffix.is_empty() && !is_restricted_name(suffix) {
+        let suffix_start = subty_start + subty.len() + 1;
+        return Err(ParseError::at(
+            MediaTypeError::InvalidSuffix,
+            s,
+            suffix_start..suffix_start + suffix.len(),
+        ));
+    }
+
+    Ok([ty.len(), subty.len(), suffix.len()])
+}
+
+// Returns the lengths of the subtype and the suffix.
+// Without an explicit suffix, the subtype is split at the last `+` as the parser does.
+pub fn split_suffix(subty: Name, suffix: Option<Name>) -> Result<(usize, usize), MediaTypeError> {
+    if let Some(suffix) = suffix {
+        return Ok((subty.as_str().len(), suffix.as_str().len()));
+    }
+    let subty = subty.as_str();
+    match subty.rfind('+').filter(|&start| start + 1 < subty.len()) {
+        Some(start) if !is_restricted_name(&subty[start + 1..]) => {
+            Err(MediaTypeError::InvalidSuffix)
         }
+        Some(start) => Ok((start, subty.len() - start - 1)),
+        None => Ok((subty.len(), 0)),
+    }
+}
+
+pub const fn essence_len(ty: usize, subty: usize, suffix: usize) -> usize {
+    ty + 1 + subty + if suffix == 0 { 0 } else { suffix + 1 }
+}
 
-        Ok((
-            Self {
-                ty: NonZeroU8::new(ty.len().try_into().unwrap()).unwrap(),
-                subty: NonZeroU8::new(subty.len().try_into().unwrap()).unwrap(),
-                suffix: suffix.len().try_into().unwrap(),
-                params: params.into_boxed_slice(),
-            },
-            params_start + params_len,
-        ))
+// Parses the parameters starting at `start` and calls `f` with the indices of each parameter.
+// Returns the end position of the last parameter.
+pub fn parse_params_with<F>(s: &str, start: usize, mut f: F) -> Result<usize, ParseError>
+where
+    F: FnMut([usize; 4]) -> Result<(), ParseError>,
+{
+    let mut offset = start;
+    while let Some((name, value)) = parse_param(&s[offset..]).map_err(|err| err.offset(offset))? {
+        f([
+            offset + name.start,
+            offset + name.end,
+            offset + value.start,
+            offset + value.end,
+        ])?;
+        offset += value.end;
     }
+    Ok(offset)
+}
+
+// Parses the parameters leniently, skipping empty and invalid parameters.
+pub fn parse_params_lenient(mut s: &str) -> Vec<(Name<'_>, Value<'_>)> {
+    let mut params = Vec::new();
+    loop {
+        s = s.trim_start_matches(|c| is_ows(c) || c == ';');
+        if s.is_empty() {
+            break;
+        }
+
+        let name_end = s.find(['=', ';']).unwrap_or(s.len());
+        let name = s[..name_end].trim_end_matches(is_ows);
+        s = &s[name_end..];
+        let value = match s.strip_prefix('=') {
+            Some(right) => right,
+            None => continue,
+        };
+
+        let value_end = if let Some(quoted) = value.strip_prefix('\"') {
+            match parse_quoted_value(quoted) {
+                Ok(len) => len + 1,
+                Err(_) => break,
+            }
+        } else {
+            value.find(';').unwrap_or(value.len())
+        };
+        s = &value[value_end..];
+        s = &s[s.find(';').unwrap_or(s.len())..];
+
+        let value = value[..value_end].trim_end_matches(is_ows);
+        if let (Some(name), Some(value)) = (Name::new(name), Value::new(value)) {
+            params.push((name, value));
+        }
+    }
+    params
 }
 
 #[cfg(test)]
-fn parse_to_string(s: &str) -> Result<String, MediaTypeError> {
+fn parse_to_string(s: &str) -> Result<String, ParseError> {
     use std::fmt::Write;
 
     let mut out = String::new();
@@ -116,6 +314,163 @@ fn parse_to_string(s: &str) -> Result<String, MediaTypeError> {
     Ok(out)
 }
 
+pub fn str_from_bytes(b: &[u8]) -> Result<&str, ParseError> {
+    match std::str::from_utf8(b) {
+        Ok(s) => Ok(s),
+        Err(err) => {
+            // Non-UTF-8 bytes may only appear in quoted parameter values,
+            // so report the error the parser would give for the lossy string.
+            let lossy = String::from_utf8_lossy(b);
+            let kind = Indices::parse(&lossy)
+                .map_or_else(|err| err.kind(), |_| MediaTypeError::InvalidParamValue);
+            let start = err.valid_up_to();
+            let end = err.error_len().map_or(b.len(), |len| start + len);
+            Err(ParseError::new(
+                kind,
+                &String::from_utf8_lossy(&b[start..end]),
+                start..end,
+            ))
+        }
+    }
+}
+
+// The ranges of the type, the subtype, the suffix and the parameters.
+pub type CommentedRanges = (
+    Range<usize>,
+    Range<usize>,
+    Option<Range<usize>>,
+    Vec<ParamRange>,
+);
+
+// Parses a media type which may contain folding whitespace between tokens,
+// and RFC 822 comments if `comments` is `true`.
+pub fn parse_folded(s: &str, comments: bool) -> Result<CommentedRanges, ParseError> {
+    let token_end = |start: usize| {
+        s[start..]
+            .find(|c| !is_restricted_char(c))
+            .map_or(s.len(), |len| start + len)
+    };
+    let trimmed_end = || {
+        s.trim_end_matches(|c| is_ows(c) || c == '\r' || c == '\n')
+            .len()
+    };
+
+    let ty_start = skip_cfws(s, 0, comments)?;
+    let ty = ty_start..token_end(ty_start);
+    let slash = skip_cfws(s, ty.end, comments)?;
+    if !is_restricted_name(&s[ty.clone()]) || !s[slash..].starts_with('/') {
+        return Err(ParseError::at(MediaTypeError::InvalidTypeName, s, ty));
+    }
+
+    let subty_start = skip_cfws(s, slash + 1, comments)?;
+    let subty_end = token_end(subty_start);
+    let (subty, suffix) = match s[subty_start..subty_end]
+        .rfind('+')
+        .map(|plus| subty_start + plus)
+        .filter(|&plus| plus + 1 < subty_end)
+    {
+        Some(plus) => (subty_start..plus, Some(plus + 1..subty_end)),
+        None => (subty_start..subty_end, None),
+    };
+    if !is_restricted_name(&s[subty.clone()]) {
+        return Err(ParseError::at(MediaTypeError::InvalidSubtypeName, s, subty));
+    }
+    if let Some(suffix) = suffix
+        .clone()
+        .filter(|suffix| !is_restricted_name(&s[suffix.clone()]))
+    {
+        return Err(ParseError::at(MediaTypeError::InvalidSuffix, s, suffix));
+    }
+
+    let mut params = Vec::new();
+    let mut pos = skip_cfws(s, subty_end, comments)?;
+    while pos < s.len() {
+        if !s[pos..].starts_with(';') {
+            return Err(ParseError::at(
+                MediaTypeError::InvalidParams,
+                s,
+                pos..trimmed_end(),
+            ));
+        }
+        let name_start = skip_cfws(s, pos + 1, comments)?;
+        if name_start == s.len() {
+            break;
+        }
+        let name = name_start..token_end(name_start);
+        let eq = skip_cfws(s, name.end, comments)?;
+        if name.is_empty() || !s[eq..].starts_with('=') {
+            return Err(ParseError::at(
+                MediaTypeError::InvalidParams,
+                s,
+                name_start..trimmed_end(),
+            ));
+        }
+        if !is_restricted_name(&s[name.clone()]) {
+            return Err(ParseError::at(MediaTypeError::InvalidParamName, s, name));
+        }
+
+        let value_start = skip_cfws(s, eq + 1, comments)?;
+        let value = if s[value_start..].starts_with('"') {
+            let len = parse_quoted_value(&s[value_start + 1..])
+                .map_err(|kind| ParseError::at(kind, s, value_start..s.len()))?;
+            value_start..value_start + 1 + len
+        } else {
+            value_start..token_end(value_start)
+        };
+        params.push((name, value.clone()));
+        pos = skip_cfws(s, value.end, comments)?;
+    }
+    Ok((ty, subty, suffix, params))
+}
+
+// Checks that every line break is followed by whitespace, as in `obs-fold`. (RFC 7230, Section 3.2.4)
+pub fn check_folds(s: &str) -> Result<(), ParseError> {
+    let bytes = s.as_bytes();
+    match bytes.iter().enumerate().find(|&(i, &b)| match b {
+        b'\r' => {
+            bytes.get(i + 1) != Some(&b'\n') || !matches!(bytes.get(i + 2), Some(b' ' | b'\t'))
+        }
+        b'\n' => i == 0 || bytes[i - 1] != b'\r',
+        _ => false,
+    }) {
+        Some((i, _)) => Err(ParseError::at(MediaTypeError::InvalidParams, s, i..s.len())),
+        None => Ok(()),
+    }
+}
+
+// Skips whitespace and comments from `pos` and returns the position of the next token.
+fn skip_cfws(s: &str, mut pos: usize, comments: bool) -> Result<usize, ParseError> {
+    loop {
+        let rest = &s[pos..];
+        pos += rest.len()
+            - rest
+                .trim_start_matches(|c| is_ows(c) || c == '\r' || c == '\n')
+                .len();
+        if !comments || !s[pos..].starts_with('(') {
+            return Ok(pos);
+        }
+
+        let mut depth = 0;
+        let mut escaped = false;
+        let end = s[pos..].char_indices().find_map(|(i, c)| {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i + 1);
+                    }
+                }
+                _ => (),
+            }
+            None
+        });
+        pos += end.ok_or_else(|| ParseError::at(MediaTypeError::InvalidParams, s, pos..s.len()))?;
+    }
+}
+
 pub fn is_restricted_name(s: &str) -> bool {
     s.len() <= Name::MAX_LENGTH
         && s.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '*')
@@ -138,66 +493,70 @@ pub const fn is_ows(c: char) -> bool {
     c == ' ' || c == '\t'
 }
 
-fn parse_params(s: &str) -> Result<(Vec<[usize; 4]>, usize), MediaTypeError> {
-    let mut vec = Vec::new();
-    let mut offset = 0;
-    let mut len = 0;
-
-    while let Some((name, value)) = parse_param(&s[offset..])? {
-        vec.push([
-            offset + name.start,
-            offset + name.end,
-            offset + value.start,
-            offset + value.end,
-        ]);
-        len = offset + value.end;
-        offset += value.end;
-    }
-
-    Ok((vec, len))
-}
+pub type ParamRange = (Range<usize>, Range<usize>);
 
-type ParamRange = (Range<usize>, Range<usize>);
+fn parse_param(s: &str) -> Result<Option<ParamRange>, ParseError> {
+    // The span of the invalid parameter syntax, excluding the separator and whitespace.
+    let invalid_params = || {
+        let end = s.trim_end_matches(is_ows).len();
+        let start = s.len() - s.trim_start_matches(|c| is_ows(c) || c == ';').len();
+        let start = if start < end {
+            start
+        } else {
+            s.len() - s.trim_start_matches(is_ows).len()
+        };
+        ParseError::at(MediaTypeError::InvalidParams, s, start..end)
+    };
 
-fn parse_param(s: &str) -> Result<Option<ParamRange>, MediaTypeError> {
     let (ows, right) = match s.split_once(';') {
         Some((ows, right)) if ows.chars().all(is_ows) && right.chars().all(is_ows) => {
             return Ok(None)
         }
         Some((ows, right)) if ows.chars().all(is_ows) => (ows, right),
         _ if s.chars().all(is_ows) => return Ok(None),
-        _ => return Err(MediaTypeError::InvalidParams),
+        _ => return Err(invalid_params()),
     };
 
     let (name, value) = match right.split_once('=') {
         Some(pair) => pair,
-        _ => return Err(MediaTypeError::InvalidParams),
+        _ => return Err(invalid_params()),
     };
 
     let key_trimmed = name.trim_start_matches(is_ows).len();
     let key_start = ows.len() + 1 + name.len() - key_trimmed;
     let key_range = key_start..key_start + key_trimmed;
     if !is_restricted_name(&s[key_range.clone()]) {
-        return Err(MediaTypeError::InvalidParamName);
+        return Err(ParseError::at(
+            MediaTypeError::InvalidParamName,
+            s,
+            key_range,
+        ));
     }
 
     let value_start = key_range.end + 1;
-    if let Some(value) = value.strip_prefix('\"') {
-        let value_end = value_start + parse_quoted_value(value)? + 1;
-        let value_range = value_start..value_end;
-        Ok(Some((key_range, value_range)))
+    let value_end = value_start
+        + parse_value(value).map_err(|kind| ParseError::at(kind, s, value_start..s.len()))?;
+    Ok(Some((key_range, value_start..value_end)))
+}
+
+// Returns the length of the quoted or unquoted value at the start of `s`.
+// An unquoted value may be empty.
+pub fn parse_value(s: &str) -> Result<usize, MediaTypeError> {
+    if let Some(quoted) = s.strip_prefix('\"') {
+        Ok(parse_quoted_value(quoted)? + 1)
     } else {
-        let value_end = value_start
-            + value
-                .chars()
-                .take_while(|&c| is_restricted_char(c))
-                .map(char::len_utf8)
-                .sum::<usize>();
-        let value_range = value_start..value_end;
-        Ok(Some((key_range, value_range)))
+        Ok(s.chars()
+            .take_while(|&c| is_restricted_char(c))
+            .map(char::len_utf8)
+            .sum())
     }
 }
 
+// Returns `true` if the whole `s` is a value accepted by the parser.
+pub fn is_value(s: &str) -> bool {
+    parse_value(s) == Ok(s.len())
+}
+
 pub fn parse_quoted_value(s: &str) -> Result<usize, MediaTypeError> {
     let mut len = 0;
     let mut escaped = false;
@@ -260,13 +619,21 @@ mod tests {
             parse_to_string("image/svg+xml; charset=\"UT\\\"F-8\""),
             Ok("image/svg+xml; charset=\"UT\\\"F-8\"".into())
         );
+        assert_eq!(
+            parse_to_string("text/plain; title=\"hello world; a=b\"; charset=UTF-8"),
+            Ok("text/plain; title=\"hello world; a=b\"; charset=UTF-8".into())
+        );
+        assert_eq!(
+            parse_to_string("text/plain; title=\"\"; charset=UTF-8"),
+            Ok("text/plain; title=\"\"; charset=UTF-8".into())
+        );
         assert_eq!(
             parse_to_string("multipart/form-data ; boundary=--boundary13234"),
             Ok("multipart/form-data; boundary=--boundary13234".into())
         );
 
         let s = "text/plain";
-        let long_str = format!("{};{}", s, " ".repeat(std::u16::MAX as usize - 2 - s.len()));
+        let long_str = format!("{};{}", s, " ".repeat(u16::MAX as usize - 2 - s.len()));
         assert_eq!(parse_to_string(&long_str), Ok("text/plain".into()));
 
         let long_name = "a".repeat(Name::MAX_LENGTH);
@@ -276,57 +643,133 @@ mod tests {
 
     #[test]
     fn parse_error() {
-        assert_eq!(parse_to_string(""), Err(MediaTypeError::InvalidTypeName));
+        assert_eq!(
+            parse_to_string(""),
+            Err(ParseError::new(MediaTypeError::InvalidTypeName, "", 0..0))
+        );
         assert_eq!(
             parse_to_string("textplain"),
-            Err(MediaTypeError::InvalidTypeName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "textplain",
+                0..9
+            ))
         );
         assert_eq!(
             parse_to_string("text//plain"),
-            Err(MediaTypeError::InvalidSubtypeName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidSubtypeName,
+                "",
+                5..5
+            ))
         );
         assert_eq!(
             parse_to_string(" text/plain"),
-            Err(MediaTypeError::InvalidTypeName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                " text",
+                0..5
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain; charsetUTF-8"),
-            Err(MediaTypeError::InvalidParams)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "charsetUTF-8",
+                12..24
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain;;"),
-            Err(MediaTypeError::InvalidParams)
+            Err(ParseError::new(MediaTypeError::InvalidParams, ";;", 10..12))
         );
         assert_eq!(
             parse_to_string("text/plain;;;"),
-            Err(MediaTypeError::InvalidParams)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                ";;;",
+                10..13
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain; charset=\"UTF-8"),
-            Err(MediaTypeError::InvalidParamValue)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "\"UTF-8",
+                20..26
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain; charset==UTF-8"),
-            Err(MediaTypeError::InvalidParams)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "=UTF-8",
+                20..26
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain; \r\n charset=UTF-8;"),
-            Err(MediaTypeError::InvalidParamName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "\r\n charset",
+                12..22
+            ))
+        );
+
+        let long_str = format!("{}/plain", "t".repeat(u16::MAX as usize));
+        assert_eq!(
+            parse_to_string(&long_str),
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                &long_str[..65535],
+                0..65535
+            ))
         );
 
-        let long_str = format!("{}/plain", "t".repeat(std::u16::MAX as usize));
+        let long_str = format!("{}é/plain", "t".repeat(Name::MAX_LENGTH * 3 + 1));
         assert_eq!(
             parse_to_string(&long_str),
-            Err(MediaTypeError::InvalidTypeName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                &long_str[..382],
+                0..382
+            ))
         );
 
         assert_eq!(
             parse_to_string("текст/plain"),
-            Err(MediaTypeError::InvalidTypeName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidTypeName,
+                "текст",
+                0..10
+            ))
         );
         assert_eq!(
             parse_to_string("text/plain; кодування=UTF-8"),
-            Err(MediaTypeError::InvalidParamName)
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamName,
+                "кодування",
+                12..30
+            ))
         );
     }
+
+    #[test]
+    fn params_lenient() {
+        fn parse(s: &str) -> Vec<(&str, &str)> {
+            parse_params_lenient(s)
+                .into_iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect()
+        }
+
+        assert_eq!(parse(""), []);
+        assert_eq!(parse(" ;; ; "), []);
+        assert_eq!(parse(";a=b;;c=d;"), [("a", "b"), ("c", "d")]);
+        assert_eq!(parse("; a = b ; c=d"), [("c", "d")]);
+        assert_eq!(parse("; a=\"b;c\" ; d=e"), [("a", "\"b;c\""), ("d", "e")]);
+        assert_eq!(parse("; a=\"b\"c; d=e"), [("a", "\"b\""), ("d", "e")]);
+        assert_eq!(parse("; a; b=\"c"), []);
+        assert_eq!(parse("; a=b c; d=é; f=g"), [("f", "g")]);
+    }
 }
@@ -0,0 +1,165 @@
+/// Options for [`MediaType::parse_with`](./struct.MediaType.html#method.parse_with).
+///
+/// The default options accept the same syntax as [`MediaType::parse`](./struct.MediaType.html#method.parse).
+///
+/// ```
+/// use mediatype::{MediaType, ParseOptions};
+///
+/// let s = "text/plain; charset=UTF-8;";
+/// assert!(MediaType::parse_with(s, ParseOptions::default()).is_ok());
+/// assert!(MediaType::parse_with(s, ParseOptions::strict()).is_err());
+///
+/// let s = " text/plain;; charset=UTF-8; invalid ";
+/// assert_eq!(
+///     MediaType::parse_with(s, ParseOptions::lenient()).unwrap().to_string(),
+///     "text/plain; charset=UTF-8"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    mode: ParseMode,
+    duplicate_params: DuplicateParams,
+}
+
+/// How to handle parameter names which appear more than once.
+///
+/// Parameter names are compared case-insensitively.
+/// If duplicated parameters are kept, [`ReadParams::get_param`](./trait.ReadParams.html#tymethod.get_param)
+/// and the comparisons of media types take the last value of each name.
+///
+/// ```
+/// use mediatype::{DuplicateParams, MediaType, ParseOptions};
+///
+/// let s = "text/plain; charset=UTF-8; CHARSET=US-ASCII";
+/// let parse = |policy| {
+///     let options = ParseOptions::default().duplicate_params(policy);
+///     MediaType::parse_with(s, options).map(|media_type| media_type.to_string())
+/// };
+/// assert_eq!(parse(DuplicateParams::Keep).unwrap(), s);
+/// assert!(parse(DuplicateParams::Reject).is_err());
+/// assert_eq!(parse(DuplicateParams::First).unwrap(), "text/plain; charset=UTF-8");
+/// assert_eq!(parse(DuplicateParams::Last).unwrap(), "text/plain; CHARSET=US-ASCII");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DuplicateParams {
+    /// Keeps all the parameters as they appear.
+    #[default]
+    Keep,
+    /// Rejects the media type with [`MediaTypeError::DuplicateParam`](./enum.MediaTypeError.html#variant.DuplicateParam).
+    Reject,
+    /// Keeps only the first occurrence of each name.
+    First,
+    /// Keeps only the last occurrence of each name.
+    Last,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) enum ParseMode {
+    Strict,
+    #[default]
+    Default,
+    Lenient,
+    Email,
+    Folded,
+}
+
+impl ParseOptions {
+    /// Returns the options for the strict grammar.
+    ///
+    /// The media type must match `type "/" subtype *( OWS ";" OWS parameter )`
+    /// ([RFC 7231](https://www.rfc-editor.org/rfc/rfc7231#section-3.1.1.1))
+    /// with the restricted names of [RFC 6838](https://www.rfc-editor.org/rfc/rfc6838#section-4.2).
+    /// Trailing semicolons and whitespace are rejected,
+    /// as well as parameter names which appear more than once
+    /// unless [`duplicate_params`](#method.duplicate_params) says otherwise.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            mode: ParseMode::Strict,
+            duplicate_params: DuplicateParams::Reject,
+        }
+    }
+
+    /// Returns the options for the lenient grammar.
+    ///
+    /// In addition to the default syntax, this tolerates the following things as browsers do:
+    ///
+    /// - Leading and trailing whitespace.
+    /// - Empty parameters and stray semicolons.
+    /// - Invalid parameters, which are ignored.
+    /// - A bare `*` without a subtype, which is treated as `*/*`.
+    #[must_use]
+    pub const fn lenient() -> Self {
+        Self {
+            mode: ParseMode::Lenient,
+            duplicate_params: DuplicateParams::Keep,
+        }
+    }
+
+    /// Returns the options for email headers.
+    ///
+    /// In addition to the default syntax, this skips comments and folding whitespace
+    /// between the tokens ([RFC 822](https://www.rfc-editor.org/rfc/rfc822#section-3.1.4)),
+    /// as they may appear in the `Content-Type` header fields of emails.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, ParseOptions};
+    /// let s = "text/plain (plain text); charset=\"us-ascii\" (Plain text)";
+    /// assert_eq!(
+    ///     MediaType::parse_with(s, ParseOptions::email()).unwrap().to_string(),
+    ///     "text/plain; charset=\"us-ascii\""
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn email() -> Self {
+        Self {
+            mode: ParseMode::Email,
+            duplicate_params: DuplicateParams::Keep,
+        }
+    }
+
+    /// Returns the options for header values with obsolete line folding.
+    ///
+    /// In addition to the default syntax, this accepts line breaks followed by whitespace
+    /// (`obs-fold`, [RFC 7230](https://www.rfc-editor.org/rfc/rfc7230#section-3.2.4))
+    /// between the tokens, as they may appear in old HTTP/1.1 and MIME headers.
+    /// The folds are skipped without copying the string, so they are still rejected
+    /// inside quoted strings.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, ParseOptions};
+    /// let s = "multipart/mixed;\r\n\tboundary=\"simple boundary\"";
+    /// assert!(MediaType::parse(s).is_err());
+    /// assert_eq!(
+    ///     MediaType::parse_with(s, ParseOptions::folded()).unwrap().to_string(),
+    ///     "multipart/mixed; boundary=\"simple boundary\""
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn folded() -> Self {
+        Self {
+            mode: ParseMode::Folded,
+            duplicate_params: DuplicateParams::Keep,
+        }
+    }
+
+    /// Sets how to handle parameter names which appear more than once.
+    ///
+    /// The default is [`DuplicateParams::Keep`] except for the strict options,
+    /// which use [`DuplicateParams::Reject`].
+    #[must_use]
+    pub const fn duplicate_params(self, policy: DuplicateParams) -> Self {
+        Self {
+            duplicate_params: policy,
+            ..self
+        }
+    }
+
+    pub(crate) const fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    pub(crate) const fn duplicate_params_policy(&self) -> DuplicateParams {
+        self.duplicate_params
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+/// A quality value of the `q` parameter.
+///
+/// The value is between `0` and `1` with up to three decimal places.
+/// ([RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-12.4.2))
+///
+/// ```
+/// # use mediatype::QValue;
+/// let q = QValue::new("0.5").unwrap();
+/// assert_eq!(q.to_string(), "0.5");
+/// assert!(q < QValue::ONE);
+/// assert_eq!(q.as_f32(), 0.5);
+///
+/// assert_eq!(QValue::new("1.5"), None);
+/// assert_eq!(QValue::new("0.1234"), None);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QValue(u16);
+
+impl QValue {
+    /// The quality value `0`, which means "not acceptable".
+    pub const ZERO: Self = Self(0);
+
+    /// The quality value `1`, which is the default.
+    pub const ONE: Self = Self(1000);
+
+    /// Constructs a `QValue` from a string like `0.8`.
+    ///
+    /// Returns `None` if the string does not match the `qvalue` syntax.
+    #[must_use]
+    pub fn new(s: &str) -> Option<Self> {
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if frac.len() > 3 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let frac = frac
+            .bytes()
+            .chain(std::iter::repeat(b'0'))
+            .take(3)
+            .fold(0, |n, b| n * 10 + u16::from(b - b'0'));
+        match int {
+            "0" => Some(Self(frac)),
+            "1" if frac == 0 => Some(Self::ONE),
+            _ => None,
+        }
+    }
+
+    /// Constructs a `QValue` from thousandths.
+    ///
+    /// Returns `None` if `n` is greater than `1000`.
+    ///
+    /// ```
+    /// # use mediatype::QValue;
+    /// assert_eq!(QValue::from_thousandths(250).unwrap().to_string(), "0.25");
+    /// assert_eq!(QValue::from_thousandths(1001), None);
+    /// ```
+    #[must_use]
+    pub const fn from_thousandths(n: u16) -> Option<Self> {
+        if n <= 1000 {
+            Some(Self(n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value in thousandths.
+    #[must_use]
+    pub const fn as_thousandths(&self) -> u16 {
+        self.0
+    }
+
+    /// Returns the value as `f32`.
+    #[must_use]
+    pub fn as_f32(&self) -> f32 {
+        f32::from(self.0) / 1000.0
+    }
+}
+
+impl Default for QValue {
+    fn default() -> Self {
+        Self::ONE
+    }
+}
+
+impl From<QValue> for f32 {
+    fn from(q: QValue) -> Self {
+        q.as_f32()
+    }
+}
+
+impl fmt::Display for QValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0 => f.write_str("0"),
+            1000 => f.write_str("1"),
+            n => {
+                let frac = format!("{:03}", n);
+                write!(f, "0.{}", frac.trim_end_matches('0'))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let thousandths = |s| QValue::new(s).map(|q| q.as_thousandths());
+        assert_eq!(thousandths("0"), Some(0));
+        assert_eq!(thousandths("0."), Some(0));
+        assert_eq!(thousandths("0.5"), Some(500));
+        assert_eq!(thousandths("0.125"), Some(125));
+        assert_eq!(thousandths("1"), Some(1000));
+        assert_eq!(thousandths("1.000"), Some(1000));
+        assert_eq!(thousandths("1.001"), None);
+        assert_eq!(thousandths("0.1234"), None);
+        assert_eq!(thousandths("2"), None);
+        assert_eq!(thousandths(".5"), None);
+        assert_eq!(thousandths("0.-1"), None);
+        assert_eq!(thousandths(""), None);
+    }
+
+    #[test]
+    fn display() {
+        for (n, s) in [
+            (0, "0"),
+            (1, "0.001"),
+            (10, "0.01"),
+            (500, "0.5"),
+            (125, "0.125"),
+            (1000, "1"),
+        ] {
+            let q = QValue::from_thousandths(n).unwrap();
+            assert_eq!(q.to_string(), s);
+            assert_eq!(QValue::new(s), Some(q));
+        }
+    }
+
+    #[test]
+    fn ord() {
+        assert!(QValue::ZERO < QValue::new("0.001").unwrap());
+        assert!(QValue::new("0.9").unwrap() < QValue::ONE);
+        assert_eq!(QValue::new("0.50"), QValue::new("0.5"));
+        assert_eq!(QValue::default(), QValue::ONE);
+    }
+}
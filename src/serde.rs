@@ -9,7 +9,7 @@ impl<'a> Serialize for MediaType<'a> {
     where
         S: Serializer,
     {
-        self.to_string().serialize(serializer)
+        serializer.collect_str(self)
     }
 }
 
@@ -29,7 +29,7 @@ impl Serialize for MediaTypeBuf {
     where
         S: Serializer,
     {
-        self.to_string().serialize(serializer)
+        serializer.collect_str(self)
     }
 }
 
@@ -44,6 +44,76 @@ impl<'de> Deserialize<'de> for MediaTypeBuf {
     }
 }
 
+/// Serializes [`MediaTypeBuf`] in the compact binary format.
+///
+/// The value is written as the bytes of [`MediaTypeBuf::to_bytes`],
+/// so deserialization validates the indices instead of parsing the string again.
+/// This suits binary formats like postcard and bincode.
+///
+/// Use it with `#[serde(with = "mediatype::compact")]` on a `MediaTypeBuf` field.
+///
+/// ```
+/// # use mediatype::{compact, MediaTypeBuf};
+/// let media_type: MediaTypeBuf = "image/svg+xml; charset=UTF-8".parse().unwrap();
+///
+/// let mut bytes = Vec::new();
+/// compact::serialize(&media_type, &mut serde_json::Serializer::new(&mut bytes)).unwrap();
+///
+/// let decoded = compact::deserialize(&mut serde_json::Deserializer::from_slice(&bytes)).unwrap();
+/// assert_eq!(media_type, decoded);
+/// ```
+pub mod compact {
+    use super::*;
+    use serde::de::{Error, SeqAccess, Visitor};
+    use std::fmt;
+
+    /// Serializes `media_type` as bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S>(media_type: &MediaTypeBuf, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&media_type.to_bytes())
+    }
+
+    /// Deserializes a `MediaTypeBuf` from bytes generated by [`serialize`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not valid.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<MediaTypeBuf, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = MediaTypeBuf;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an encoded media type")
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            MediaTypeBuf::from_bytes(v).ok_or_else(|| E::custom("invalid encoded media type"))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +128,20 @@ mod tests {
         assert_eq!(original, decoded);
     }
 
+    #[test]
+    fn serialize() {
+        let media_type = MediaType::parse("IMAGE/SVG+XML;  charset=UTF-8").unwrap();
+        assert_eq!(
+            serde_json::to_string(&media_type).unwrap(),
+            "\"IMAGE/SVG+XML; charset=UTF-8\""
+        );
+        let media_type = MediaTypeBuf::from_str("IMAGE/SVG+XML;  charset=UTF-8").unwrap();
+        assert_eq!(
+            serde_json::to_string(&media_type).unwrap(),
+            "\"IMAGE/SVG+XML; charset=UTF-8\""
+        );
+    }
+
     #[test]
     fn serde_from_value() {
         let original = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8;").unwrap();
@@ -65,4 +149,32 @@ mod tests {
         let decoded: MediaTypeBuf = serde_json::from_value(value).unwrap();
         assert_eq!(original, decoded);
     }
+
+    #[test]
+    fn compact() {
+        for s in [
+            "image/svg+xml; charset=UTF-8",
+            "text/plain; a=",
+            "text/plain; title=\"\"",
+        ] {
+            let original = MediaTypeBuf::from_str(s).unwrap();
+            let mut json = Vec::new();
+            super::compact::serialize(&original, &mut serde_json::Serializer::new(&mut json))
+                .unwrap();
+            let decoded =
+                super::compact::deserialize(&mut serde_json::Deserializer::from_slice(&json))
+                    .unwrap();
+            assert_eq!(original, decoded);
+            assert_eq!(original.as_str(), decoded.as_str());
+        }
+
+        let original = MediaTypeBuf::from_str("image/svg+xml; charset=UTF-8").unwrap();
+
+        let mut invalid = original.to_bytes();
+        invalid[0] = 0;
+        let json = serde_json::to_vec(&invalid).unwrap();
+        assert!(
+            super::compact::deserialize(&mut serde_json::Deserializer::from_slice(&json)).is_err()
+        );
+    }
 }
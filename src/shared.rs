@@ -0,0 +1,132 @@
+use super::{error::*, media_type::*, media_type_buf::*};
+use std::{fmt, ops::Deref, str::FromStr, sync::Arc};
+
+/// A shared media type which can be cloned in O(1).
+///
+/// A [`MediaTypeBuf`] is shared via [`Arc`], so cloning only increments the reference count,
+/// and the value can be attached to many messages or sent to other threads cheaply.
+/// It dereferences to the shared [`MediaTypeBuf`].
+///
+/// ```
+/// use mediatype::{names::*, SharedMediaType};
+///
+/// let json: SharedMediaType = "application/json; charset=utf-8".parse().unwrap();
+/// let copy = json.clone();
+/// assert!(SharedMediaType::ptr_eq(&json, &copy));
+/// assert_eq!(copy.subty(), JSON);
+/// assert_eq!(copy, "application/json; charset=utf-8");
+/// ```
+///
+/// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SharedMediaType(Arc<MediaTypeBuf>);
+
+impl SharedMediaType {
+    /// Returns `true` if the two values share the same allocation.
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+
+    /// Returns the inner [`Arc`].
+    ///
+    /// [`Arc`]: https://doc.rust-lang.org/std/sync/struct.Arc.html
+    #[must_use]
+    pub fn into_arc(self) -> Arc<MediaTypeBuf> {
+        self.0
+    }
+}
+
+impl Deref for SharedMediaType {
+    type Target = MediaTypeBuf;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<MediaTypeBuf> for SharedMediaType {
+    fn as_ref(&self) -> &MediaTypeBuf {
+        &self.0
+    }
+}
+
+impl From<MediaTypeBuf> for SharedMediaType {
+    fn from(media_type: MediaTypeBuf) -> Self {
+        Self(Arc::new(media_type))
+    }
+}
+
+impl From<Arc<MediaTypeBuf>> for SharedMediaType {
+    fn from(media_type: Arc<MediaTypeBuf>) -> Self {
+        Self(media_type)
+    }
+}
+
+impl From<MediaType<'_>> for SharedMediaType {
+    fn from(media_type: MediaType) -> Self {
+        MediaTypeBuf::from(media_type).into()
+    }
+}
+
+impl FromStr for SharedMediaType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MediaTypeBuf::from_str(s).map(Self::from)
+    }
+}
+
+impl PartialEq<MediaTypeBuf> for SharedMediaType {
+    fn eq(&self, other: &MediaTypeBuf) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<MediaType<'_>> for SharedMediaType {
+    fn eq(&self, other: &MediaType) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<str> for SharedMediaType {
+    fn eq(&self, other: &str) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<&str> for SharedMediaType {
+    fn eq(&self, other: &&str) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl fmt::Display for SharedMediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{names::*, ReadParams};
+
+    #[test]
+    fn shared() {
+        let a = SharedMediaType::from(MediaType::new(TEXT, PLAIN));
+        let b = a.clone();
+        assert!(SharedMediaType::ptr_eq(&a, &b));
+        assert_eq!(Arc::strong_count(&b.clone().into_arc()), 3);
+
+        let c = SharedMediaType::from_str("TEXT/PLAIN").unwrap();
+        assert!(!SharedMediaType::ptr_eq(&a, &c));
+        assert_eq!(a, c);
+        assert_eq!(c, MediaType::new(TEXT, PLAIN));
+        assert_eq!(c.to_string(), "TEXT/PLAIN");
+        assert_eq!(c.params().count(), 0);
+
+        let thread = std::thread::spawn(move || b.ty() == TEXT);
+        assert!(thread.join().unwrap());
+    }
+}
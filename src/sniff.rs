@@ -0,0 +1,366 @@
+#![cfg(feature = "sniff")]
+
+use super::{media_type::*, media_type_buf::*, name::*};
+
+const fn mt(ty: &'static str, subty: &'static str) -> MediaType<'static> {
+    MediaType::new(Name::new_unchecked(ty), Name::new_unchecked(subty))
+}
+
+// (pattern, mask, media type)
+type Pattern = (&'static [u8], &'static [u8], MediaType<'static>);
+
+const IMAGE_PATTERNS: &[Pattern] = &[
+    (
+        b"\x00\x00\x01\x00",
+        b"\xFF\xFF\xFF\xFF",
+        mt("image", "x-icon"),
+    ),
+    (
+        b"\x00\x00\x02\x00",
+        b"\xFF\xFF\xFF\xFF",
+        mt("image", "x-icon"),
+    ),
+    (b"BM", b"\xFF\xFF", mt("image", "bmp")),
+    (b"GIF87a", b"\xFF\xFF\xFF\xFF\xFF\xFF", mt("image", "gif")),
+    (b"GIF89a", b"\xFF\xFF\xFF\xFF\xFF\xFF", mt("image", "gif")),
+    (
+        b"RIFF\x00\x00\x00\x00WEBPVP",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF\xFF\xFF",
+        mt("image", "webp"),
+    ),
+    (
+        b"\x89PNG\x0D\x0A\x1A\x0A",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        mt("image", "png"),
+    ),
+    (b"\xFF\xD8\xFF", b"\xFF\xFF\xFF", mt("image", "jpeg")),
+];
+
+const AUDIO_VIDEO_PATTERNS: &[Pattern] = &[
+    (b".snd", b"\xFF\xFF\xFF\xFF", mt("audio", "basic")),
+    (
+        b"FORM\x00\x00\x00\x00AIFF",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        mt("audio", "aiff"),
+    ),
+    (b"ID3", b"\xFF\xFF\xFF", mt("audio", "mpeg")),
+    (
+        b"OggS\x00",
+        b"\xFF\xFF\xFF\xFF\xFF",
+        mt("application", "ogg"),
+    ),
+    (
+        b"MThd\x00\x00\x00\x06",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        mt("audio", "midi"),
+    ),
+    (
+        b"RIFF\x00\x00\x00\x00AVI ",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        mt("video", "avi"),
+    ),
+    (
+        b"RIFF\x00\x00\x00\x00WAVE",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        mt("audio", "wave"),
+    ),
+];
+
+const FONT_PATTERNS: &[Pattern] = &[
+    (
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00LP",
+        b"\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\xFF\xFF",
+        mt("application", "vnd.ms-fontobject"),
+    ),
+    (b"\x00\x01\x00\x00", b"\xFF\xFF\xFF\xFF", mt("font", "ttf")),
+    (b"OTTO", b"\xFF\xFF\xFF\xFF", mt("font", "otf")),
+    (b"ttcf", b"\xFF\xFF\xFF\xFF", mt("font", "collection")),
+    (b"wOFF", b"\xFF\xFF\xFF\xFF", mt("font", "woff")),
+    (b"wOF2", b"\xFF\xFF\xFF\xFF", mt("font", "woff2")),
+];
+
+const ARCHIVE_PATTERNS: &[Pattern] = &[
+    (
+        b"\x1F\x8B\x08",
+        b"\xFF\xFF\xFF",
+        mt("application", "x-gzip"),
+    ),
+    (b"PK\x03\x04", b"\xFF\xFF\xFF\xFF", mt("application", "zip")),
+    (
+        b"Rar!\x1A\x07\x00",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        mt("application", "x-rar-compressed"),
+    ),
+];
+
+// HTML tags which must be followed by a tag-terminating byte. Matched case-insensitively.
+const HTML_TAGS: &[&[u8]] = &[
+    b"<!DOCTYPE HTML",
+    b"<HTML",
+    b"<HEAD",
+    b"<SCRIPT",
+    b"<IFRAME",
+    b"<H1",
+    b"<DIV",
+    b"<FONT",
+    b"<TABLE",
+    b"<A",
+    b"<STYLE",
+    b"<TITLE",
+    b"<B",
+    b"<BODY",
+    b"<BR",
+    b"<P",
+    b"<!--",
+];
+
+const OTHER_PATTERNS: &[Pattern] = &[
+    (b"%PDF-", b"\xFF\xFF\xFF\xFF\xFF", mt("application", "pdf")),
+    (
+        b"%!PS-Adobe-",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        mt("application", "postscript"),
+    ),
+    (b"\xFE\xFF", b"\xFF\xFF", mt("text", "plain")),
+    (b"\xFF\xFE", b"\xFF\xFF", mt("text", "plain")),
+    (b"\xEF\xBB\xBF", b"\xFF\xFF\xFF", mt("text", "plain")),
+];
+
+/// Determines the media type of a resource from its leading bytes.
+///
+/// This implements the rules for identifying an unknown MIME type
+/// of the [WHATWG MIME Sniffing Standard](https://mimesniff.spec.whatwg.org/#identifying-a-resource-with-an-unknown-mime-type)
+/// with scriptable types (HTML, XML and PDF) enabled.
+///
+/// Returns `None` if no pattern matches. In that case, the standard treats the resource as
+/// `text/plain` if it contains no binary data bytes, and `application/octet-stream` otherwise.
+///
+/// MP3 files without an ID3 tag are not detected.
+///
+/// This function requires the `sniff` feature.
+///
+/// ```
+/// # use mediatype::{names::*, sniff, MediaType};
+/// assert_eq!(
+///     sniff(b"\x89PNG\r\n\x1A\n\0\0\0\rIHDR").unwrap(),
+///     MediaType::new(IMAGE, PNG)
+/// );
+/// assert_eq!(
+///     sniff(b"  <!doctype html><p>Hello").unwrap(),
+///     MediaType::new(TEXT, HTML)
+/// );
+/// assert_eq!(sniff(b"Hello"), None);
+/// ```
+#[must_use]
+pub fn sniff(data: &[u8]) -> Option<MediaTypeBuf> {
+    sniff_media_type(data).map(MediaTypeBuf::from)
+}
+
+fn sniff_media_type(data: &[u8]) -> Option<MediaType<'static>> {
+    let start = data
+        .iter()
+        .position(|&b| !is_whitespace_byte(b))
+        .unwrap_or(data.len());
+    if HTML_TAGS.iter().any(|tag| {
+        let data = &data[start..];
+        data.len() > tag.len()
+            && data[..tag.len()].eq_ignore_ascii_case(tag)
+            && matches!(data[tag.len()], b' ' | b'>')
+    }) {
+        return Some(mt("text", "html"));
+    }
+    if matches(&data[start..], b"<?xml", b"\xFF\xFF\xFF\xFF\xFF") {
+        return Some(mt("text", "xml"));
+    }
+
+    OTHER_PATTERNS
+        .iter()
+        .chain(IMAGE_PATTERNS)
+        .find(|(pattern, mask, _)| matches(data, pattern, mask))
+        .map(|(_, _, media_type)| media_type.clone())
+        .or_else(|| sniff_audio_video(data))
+        .or_else(|| {
+            FONT_PATTERNS
+                .iter()
+                .chain(ARCHIVE_PATTERNS)
+                .find(|(pattern, mask, _)| matches(data, pattern, mask))
+                .map(|(_, _, media_type)| media_type.clone())
+        })
+}
+
+fn sniff_audio_video(data: &[u8]) -> Option<MediaType<'static>> {
+    if let Some((_, _, media_type)) = AUDIO_VIDEO_PATTERNS
+        .iter()
+        .find(|(pattern, mask, _)| matches(data, pattern, mask))
+    {
+        Some(media_type.clone())
+    } else if is_mp4(data) {
+        Some(mt("video", "mp4"))
+    } else if is_webm(data) {
+        Some(mt("video", "webm"))
+    } else {
+        None
+    }
+}
+
+fn matches(data: &[u8], pattern: &[u8], mask: &[u8]) -> bool {
+    data.len() >= pattern.len()
+        && data
+            .iter()
+            .zip(pattern)
+            .zip(mask)
+            .all(|((&d, &p), &m)| d & m == p)
+}
+
+fn is_whitespace_byte(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-mp4
+fn is_mp4(data: &[u8]) -> bool {
+    if data.len() < 12 {
+        return false;
+    }
+    let box_size = u32::from_be_bytes([data[0], data[1], data[2], data[3]]) as usize;
+    if data.len() < box_size || !box_size.is_multiple_of(4) || &data[4..8] != b"ftyp" {
+        return false;
+    }
+    if &data[8..11] == b"mp4" {
+        return true;
+    }
+    (16..box_size)
+        .step_by(4)
+        .any(|i| data.get(i..i + 3) == Some(b"mp4"))
+}
+
+// https://mimesniff.spec.whatwg.org/#signature-for-webm
+fn is_webm(data: &[u8]) -> bool {
+    if !data.starts_with(b"\x1A\x45\xDF\xA3") {
+        return false;
+    }
+    let mut iter = 4;
+    while iter < data.len() && iter < 38 {
+        if data[iter..].starts_with(b"\x42\x82") {
+            iter += 2;
+            if iter >= data.len() {
+                break;
+            }
+            iter += vint_size(data, iter);
+            if iter + 4 > data.len() {
+                break;
+            }
+            if &data[iter..iter + 4] == b"webm" {
+                return true;
+            }
+        }
+        iter += 1;
+    }
+    false
+}
+
+fn vint_size(data: &[u8], index: usize) -> usize {
+    let leading = data[index].leading_zeros() as usize;
+    leading.min(7) + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::names::*;
+
+    fn sniff_str(data: &[u8]) -> Option<String> {
+        sniff(data).map(|media_type| media_type.to_string())
+    }
+
+    #[test]
+    fn images() {
+        assert_eq!(sniff_str(b"GIF89a\x01\x00"), Some("image/gif".into()));
+        assert_eq!(sniff_str(b"\xFF\xD8\xFF\xE0"), Some("image/jpeg".into()));
+        assert_eq!(sniff_str(b"BM\x00\x00"), Some("image/bmp".into()));
+        assert_eq!(
+            sniff_str(b"RIFF\x24\x00\x00\x00WEBPVP8 "),
+            Some("image/webp".into())
+        );
+        assert_eq!(sniff_str(b"\x00\x00\x01\x00"), Some("image/x-icon".into()));
+        assert_eq!(sniff_str(b"\x89PNG"), None);
+    }
+
+    #[test]
+    fn markup() {
+        assert_eq!(sniff_str(b"<html>"), Some("text/html".into()));
+        assert_eq!(sniff_str(b"\r\n<HEAD >"), Some("text/html".into()));
+        assert_eq!(sniff_str(b"<!-- comment -->"), Some("text/html".into()));
+        assert_eq!(sniff_str(b"<html"), None);
+        assert_eq!(sniff_str(b"<htmlx>"), None);
+        assert_eq!(
+            sniff_str(b" <?xml version=\"1.0\"?>"),
+            Some("text/xml".into())
+        );
+        assert_eq!(sniff_str(b"%PDF-1.7"), Some("application/pdf".into()));
+        assert_eq!(
+            sniff_str(b"%!PS-Adobe-3.0"),
+            Some("application/postscript".into())
+        );
+        assert_eq!(sniff_str(b"\xEF\xBB\xBFabc"), Some("text/plain".into()));
+        assert_eq!(sniff_str(b"\xFF\xFEa\x00"), Some("text/plain".into()));
+    }
+
+    #[test]
+    fn audio_video() {
+        assert_eq!(sniff_str(b"ID3\x04"), Some("audio/mpeg".into()));
+        assert_eq!(sniff_str(b"OggS\x00\x02"), Some("application/ogg".into()));
+        assert_eq!(
+            sniff_str(b"RIFF\x24\x00\x00\x00WAVEfmt "),
+            Some("audio/wave".into())
+        );
+        assert_eq!(
+            sniff_str(b"\x00\x00\x00\x18ftypmp42\x00\x00\x00\x00isommp42"),
+            Some("video/mp4".into())
+        );
+        assert_eq!(
+            sniff_str(b"\x00\x00\x00\x18ftypisom\x00\x00\x00\x00isommp41"),
+            Some("video/mp4".into())
+        );
+        assert_eq!(
+            sniff_str(b"\x00\x00\x00\x18ftypisom\x00\x00\x00\x00isomavc1"),
+            None
+        );
+        assert_eq!(
+            sniff_str(b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x84webm"),
+            Some("video/webm".into())
+        );
+        assert_eq!(
+            sniff_str(b"\x1A\x45\xDF\xA3\x9F\x42\x86\x81\x01\x42\x82\x88matroska"),
+            None
+        );
+    }
+
+    #[test]
+    fn fonts_and_archives() {
+        assert_eq!(sniff_str(b"wOF2\x00\x01"), Some("font/woff2".into()));
+        assert_eq!(sniff_str(b"\x00\x01\x00\x00\x00"), Some("font/ttf".into()));
+        let mut eot = vec![0xAB; 34];
+        eot.extend_from_slice(b"LP");
+        assert_eq!(
+            sniff_str(&eot),
+            Some("application/vnd.ms-fontobject".into())
+        );
+        assert_eq!(
+            sniff_str(b"PK\x03\x04\x14\x00"),
+            Some("application/zip".into())
+        );
+        assert_eq!(
+            sniff_str(b"\x1F\x8B\x08\x00"),
+            Some("application/x-gzip".into())
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(sniff(b""), None);
+        assert_eq!(sniff(b"   "), None);
+        assert_eq!(sniff(b"plain text"), None);
+        assert_eq!(sniff(b"\x00\x01\x02"), None);
+        assert_eq!(sniff(b"<p>").unwrap(), MediaType::new(TEXT, HTML));
+    }
+}
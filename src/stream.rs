@@ -0,0 +1,119 @@
+use super::{error::*, media_type_buf::*, parse::*};
+
+/// A push-based parser for media types split across multiple buffers.
+///
+/// The chunks are appended to the buffer which becomes the storage of
+/// the resulting [`MediaTypeBuf`], so no temporary string is created.
+///
+/// ```
+/// use mediatype::{MediaTypeBuf, MediaTypeParser};
+///
+/// let mut parser = MediaTypeParser::new();
+/// parser.push(b"multipart/form-");
+/// parser.push(b"data; boundary=");
+/// parser.push(b"----1234");
+/// let media_type = parser.finish().unwrap();
+/// assert_eq!(media_type, "multipart/form-data; boundary=----1234");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MediaTypeParser {
+    buf: Vec<u8>,
+}
+
+impl MediaTypeParser {
+    /// Constructs an empty `MediaTypeParser`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { buf: Vec::new() }
+    }
+
+    /// Constructs an empty `MediaTypeParser` with at least the specified capacity.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Appends a chunk of bytes.
+    ///
+    /// A chunk may end in the middle of a token or a UTF-8 sequence.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// Returns the number of the bytes pushed so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if no bytes are pushed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Parses the pushed bytes as a media type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes fail to be parsed.
+    ///
+    /// The span of the error is a byte range in the concatenation of the chunks.
+    pub fn finish(self) -> Result<MediaTypeBuf, ParseError> {
+        match String::from_utf8(self.buf) {
+            Ok(s) => MediaTypeBuf::from_string(s),
+            Err(err) => Err(str_from_bytes(err.as_bytes()).unwrap_err()),
+        }
+    }
+}
+
+impl<'a> Extend<&'a [u8]> for MediaTypeParser {
+    fn extend<T: IntoIterator<Item = &'a [u8]>>(&mut self, iter: T) {
+        for chunk in iter {
+            self.push(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finish() {
+        let s = "text/plain; title=\"é\"".as_bytes();
+        for i in 0..s.len() {
+            let mut parser = MediaTypeParser::with_capacity(s.len());
+            parser.extend([&s[..i], &s[i..]]);
+            assert_eq!(parser.len(), s.len());
+            assert_eq!(parser.finish().unwrap().as_str(), "text/plain; title=\"é\"");
+        }
+
+        let mut parser = MediaTypeParser::new();
+        assert!(parser.is_empty());
+        parser.push(b"text/");
+        parser.push(b"plain; title=\"\xc3\"");
+        assert_eq!(
+            parser.finish(),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParamValue,
+                "\u{fffd}",
+                19..20
+            ))
+        );
+
+        let mut parser = MediaTypeParser::new();
+        parser.push(b"text/plain;");
+        parser.push(b" charset");
+        assert_eq!(
+            parser.finish(),
+            Err(ParseError::new(
+                MediaTypeError::InvalidParams,
+                "charset",
+                12..19
+            ))
+        );
+    }
+}
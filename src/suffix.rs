@@ -0,0 +1,326 @@
+use super::{media_type::*, media_type_buf::*, name::*, names::*};
+use std::fmt;
+
+/// A structured syntax suffix.
+///
+/// The registered suffixes are listed in the
+/// [IANA registry](https://www.iana.org/assignments/media-type-structured-suffix/),
+/// and all of them are available as constants in [`names`](./names/index.html).
+///
+/// ```
+/// # use mediatype::{names::*, MediaType};
+/// let media_type = MediaType::from_parts(APPLICATION, GEOPACKAGE, Some(SQLITE3), &[]);
+/// assert_eq!(media_type.to_string(), "application/geopackage+sqlite3");
+/// assert_eq!(media_type.suffix, Some(SQLITE3));
+/// ```
+///
+/// ```
+/// # use mediatype::{MediaType, Suffix};
+/// let media_type = MediaType::parse("application/vnd.api+JSON").unwrap();
+/// match media_type.structured_suffix() {
+///     Some(Suffix::Json) => (),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Suffix<'a> {
+    /// `+json`
+    Json,
+    /// `+xml`
+    Xml,
+    /// `+cbor`
+    Cbor,
+    /// `+zstd`
+    Zstd,
+    /// `+der`
+    Der,
+    /// `+jwt`
+    Jwt,
+    /// Any other suffix.
+    Other(Name<'a>),
+}
+
+impl<'a> Suffix<'a> {
+    /// Constructs a `Suffix` from a name, ignoring case.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, Name, Suffix};
+    /// assert_eq!(Suffix::from_name(XML), Suffix::Xml);
+    /// assert_eq!(Suffix::from_name(Name::new("XML").unwrap()), Suffix::Xml);
+    /// assert_eq!(Suffix::from_name(ZIP), Suffix::Other(ZIP));
+    /// ```
+    #[must_use]
+    pub fn from_name(name: Name<'a>) -> Self {
+        [
+            (JSON, Self::Json),
+            (XML, Self::Xml),
+            (CBOR, Self::Cbor),
+            (ZSTD, Self::Zstd),
+            (DER, Self::Der),
+            (JWT, Self::Jwt),
+        ]
+        .into_iter()
+        .find(|&(known, _)| known == name)
+        .map_or(Self::Other(name), |(_, suffix)| suffix)
+    }
+
+    /// Returns the name of the suffix.
+    #[must_use]
+    pub const fn name(&self) -> Name<'a> {
+        match self {
+            Self::Json => JSON,
+            Self::Xml => XML,
+            Self::Cbor => CBOR,
+            Self::Zstd => ZSTD,
+            Self::Der => DER,
+            Self::Jwt => JWT,
+            Self::Other(name) => *name,
+        }
+    }
+}
+
+impl<'a> From<Name<'a>> for Suffix<'a> {
+    fn from(name: Name<'a>) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl<'a> fmt::Display for Suffix<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+// Returns `true` if the media type is `application/<syntax>` or `*/*+<syntax>`.
+fn is_syntax(ty: Name, subty: Name, suffix: Option<Name>, syntax: Name) -> bool {
+    suffix.map_or(subty == syntax && ty == APPLICATION, |suffix| {
+        suffix == syntax
+    })
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns `true` if the media type is `application/json` or has the `+json` suffix.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// assert!(MediaType::parse("application/json; charset=UTF-8").unwrap().is_json());
+    /// assert!(MediaType::parse("application/problem+json").unwrap().is_json());
+    /// assert!(!MediaType::parse("application/json-seq").unwrap().is_json());
+    /// assert!(!MediaType::parse("text/json+xml").unwrap().is_json());
+    /// ```
+    #[must_use]
+    pub fn is_json(&self) -> bool {
+        is_syntax(self.ty, self.subty, self.suffix, JSON)
+    }
+
+    /// Returns `true` if the media type is `application/xml`, `text/xml` or has the `+xml` suffix.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// assert!(MediaType::parse("text/xml").unwrap().is_xml());
+    /// assert!(MediaType::parse("image/svg+xml").unwrap().is_xml());
+    /// ```
+    #[must_use]
+    pub fn is_xml(&self) -> bool {
+        is_syntax(self.ty, self.subty, self.suffix, XML)
+            || (self.ty == TEXT && self.subty == XML && self.suffix.is_none())
+    }
+
+    /// Returns `true` if the media type is `application/cbor` or has the `+cbor` suffix.
+    #[must_use]
+    pub fn is_cbor(&self) -> bool {
+        is_syntax(self.ty, self.subty, self.suffix, CBOR)
+    }
+
+    /// Returns the structured syntax suffix.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, Suffix};
+    /// let media_type = MediaType::parse("image/svg+xml").unwrap();
+    /// assert_eq!(media_type.structured_suffix(), Some(Suffix::Xml));
+    /// assert_eq!(MediaType::new(TEXT, PLAIN).structured_suffix(), None);
+    /// ```
+    #[must_use]
+    pub fn structured_suffix(&self) -> Option<Suffix<'a>> {
+        self.suffix.map(Suffix::from_name)
+    }
+
+    /// Returns a copy of the media type with the suffix added or replaced.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType};
+    /// let media_type = MediaType::parse("application/vnd.foo+json; v=1").unwrap();
+    /// assert_eq!(
+    ///     media_type.with_suffix(CBOR).to_string(),
+    ///     "application/vnd.foo+cbor; v=1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_suffix(&self, suffix: Name<'a>) -> Self {
+        Self {
+            suffix: Some(suffix),
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of the media type without the suffix.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("application/vnd.foo+json").unwrap();
+    /// assert_eq!(media_type.without_suffix().to_string(), "application/vnd.foo");
+    /// ```
+    #[must_use]
+    pub fn without_suffix(&self) -> Self {
+        Self {
+            suffix: None,
+            ..self.clone()
+        }
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns `true` if the media type is `application/json` or has the `+json` suffix.
+    #[must_use]
+    pub fn is_json(&self) -> bool {
+        self.essence().is_json()
+    }
+
+    /// Returns `true` if the media type is `application/xml`, `text/xml` or has the `+xml` suffix.
+    #[must_use]
+    pub fn is_xml(&self) -> bool {
+        self.essence().is_xml()
+    }
+
+    /// Returns `true` if the media type is `application/cbor` or has the `+cbor` suffix.
+    #[must_use]
+    pub fn is_cbor(&self) -> bool {
+        self.essence().is_cbor()
+    }
+
+    /// Returns the structured syntax suffix.
+    ///
+    /// ```
+    /// # use mediatype::{MediaTypeBuf, Suffix};
+    /// let media_type: MediaTypeBuf = "application/ld+json".parse().unwrap();
+    /// assert_eq!(media_type.structured_suffix(), Some(Suffix::Json));
+    /// ```
+    #[must_use]
+    pub fn structured_suffix(&self) -> Option<Suffix<'_>> {
+        self.essence().structured_suffix()
+    }
+
+    /// Returns a copy of the media type with the suffix added or replaced.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaTypeBuf};
+    /// let media_type: MediaTypeBuf = "application/vnd.foo; v=1".parse().unwrap();
+    /// assert_eq!(media_type.with_suffix(JSON).as_str(), "application/vnd.foo+json; v=1");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `suffix` is constructed by [`Name::new_unchecked`] and is not valid.
+    #[must_use]
+    pub fn with_suffix(&self, suffix: Name) -> Self {
+        let mut media_type = self.clone();
+        media_type.set_suffix(Some(suffix));
+        media_type
+    }
+
+    /// Returns a copy of the media type without the suffix.
+    #[must_use]
+    pub fn without_suffix(&self) -> Self {
+        let mut media_type = self.clone();
+        media_type.set_suffix(None);
+        media_type
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn with_suffix() {
+        let transform = |s: &str| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            (
+                media_type.with_suffix(CBOR).to_string(),
+                media_type.without_suffix().to_string(),
+            )
+        };
+        assert_eq!(
+            transform("application/vnd.foo+json; charset=UTF-8"),
+            (
+                "application/vnd.foo+cbor; charset=UTF-8".into(),
+                "application/vnd.foo; charset=UTF-8".into()
+            )
+        );
+        assert_eq!(
+            transform("application/vnd.foo"),
+            (
+                "application/vnd.foo+cbor".into(),
+                "application/vnd.foo".into()
+            )
+        );
+    }
+
+    #[test]
+    fn from_name() {
+        for suffix in [
+            Suffix::Json,
+            Suffix::Xml,
+            Suffix::Cbor,
+            Suffix::Zstd,
+            Suffix::Der,
+            Suffix::Jwt,
+        ] {
+            assert_eq!(Suffix::from_name(suffix.name()), suffix);
+            let upper = suffix.name().as_str().to_ascii_uppercase();
+            assert_eq!(Suffix::from_name(Name::new(&upper).unwrap()), suffix);
+        }
+        assert_eq!(Suffix::from_name(GZIP), Suffix::Other(GZIP));
+        assert_eq!(Suffix::Other(GZIP).name(), GZIP);
+        assert_eq!(Suffix::Jwt.to_string(), "jwt");
+    }
+
+    #[test]
+    fn structured_suffix() {
+        let media_type = MediaType::parse("application/did+ld+json").unwrap();
+        assert_eq!(media_type.structured_suffix(), Some(Suffix::Json));
+        let media_type = MediaType::parse("application/vnd.a+gzip").unwrap();
+        assert_eq!(media_type.structured_suffix(), Some(Suffix::Other(GZIP)));
+        let media_type = MediaTypeBuf::from_parts(APPLICATION, JSON, None, &[]);
+        assert_eq!(media_type.structured_suffix(), None);
+    }
+
+    #[test]
+    fn is_syntax() {
+        let check = |s: &str| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            (
+                media_type.is_json(),
+                media_type.is_xml(),
+                media_type.is_cbor(),
+            )
+        };
+        assert_eq!(check("application/json"), (true, false, false));
+        assert_eq!(
+            check("APPLICATION/JSON; charset=UTF-8"),
+            (true, false, false)
+        );
+        assert_eq!(check("application/vnd.api+json"), (true, false, false));
+        assert_eq!(check("text/json"), (false, false, false));
+        assert_eq!(check("application/json+xml"), (false, true, false));
+        assert_eq!(check("application/xml"), (false, true, false));
+        assert_eq!(check("text/xml"), (false, true, false));
+        assert_eq!(check("image/svg+xml"), (false, true, false));
+        assert_eq!(check("application/cbor"), (false, false, true));
+        assert_eq!(check("application/senml+cbor"), (false, false, true));
+        assert_eq!(check("application/cbor-seq"), (false, false, false));
+        assert_eq!(check("*/*"), (false, false, false));
+    }
+}
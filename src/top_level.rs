@@ -0,0 +1,327 @@
+use super::{media_type::*, media_type_buf::*, name::*, names::*};
+use std::fmt;
+
+/// A top-level type.
+///
+/// ```
+/// # use mediatype::{MediaType, TopLevelType};
+/// let media_type = MediaType::parse("IMAGE/png").unwrap();
+/// match media_type.top_level() {
+///     TopLevelType::Image => (),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TopLevelType<'a> {
+    /// `application`
+    Application,
+    /// `audio`
+    Audio,
+    /// `example`
+    Example,
+    /// `font`
+    Font,
+    /// `haptics`
+    Haptics,
+    /// `image`
+    Image,
+    /// `message`
+    Message,
+    /// `model`
+    Model,
+    /// `multipart`
+    Multipart,
+    /// `text`
+    Text,
+    /// `video`
+    Video,
+    /// Any other type, including `*`.
+    Other(Name<'a>),
+}
+
+impl<'a> TopLevelType<'a> {
+    /// Constructs a `TopLevelType` from a name, ignoring case.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, Name, TopLevelType};
+    /// assert_eq!(TopLevelType::from_name(TEXT), TopLevelType::Text);
+    /// assert_eq!(TopLevelType::from_name(Name::new("Text").unwrap()), TopLevelType::Text);
+    /// assert_eq!(TopLevelType::from_name(_STAR), TopLevelType::Other(_STAR));
+    /// ```
+    #[must_use]
+    pub fn from_name(name: Name<'a>) -> Self {
+        [
+            (APPLICATION, Self::Application),
+            (AUDIO, Self::Audio),
+            (EXAMPLE, Self::Example),
+            (FONT, Self::Font),
+            (HAPTICS, Self::Haptics),
+            (IMAGE, Self::Image),
+            (MESSAGE, Self::Message),
+            (MODEL, Self::Model),
+            (MULTIPART, Self::Multipart),
+            (TEXT, Self::Text),
+            (VIDEO, Self::Video),
+        ]
+        .into_iter()
+        .find(|&(known, _)| known == name)
+        .map_or(Self::Other(name), |(_, ty)| ty)
+    }
+
+    /// Returns the name of the type.
+    #[must_use]
+    pub const fn name(&self) -> Name<'a> {
+        match self {
+            Self::Application => APPLICATION,
+            Self::Audio => AUDIO,
+            Self::Example => EXAMPLE,
+            Self::Font => FONT,
+            Self::Haptics => HAPTICS,
+            Self::Image => IMAGE,
+            Self::Message => MESSAGE,
+            Self::Model => MODEL,
+            Self::Multipart => MULTIPART,
+            Self::Text => TEXT,
+            Self::Video => VIDEO,
+            Self::Other(name) => *name,
+        }
+    }
+}
+
+impl<'a> From<Name<'a>> for TopLevelType<'a> {
+    fn from(name: Name<'a>) -> Self {
+        Self::from_name(name)
+    }
+}
+
+impl<'a> fmt::Display for TopLevelType<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns the top-level type.
+    ///
+    /// ```
+    /// # use mediatype::{names::*, MediaType, TopLevelType};
+    /// assert_eq!(MediaType::new(VIDEO, MP4).top_level(), TopLevelType::Video);
+    /// ```
+    #[must_use]
+    pub fn top_level(&self) -> TopLevelType<'a> {
+        TopLevelType::from_name(self.ty)
+    }
+
+    /// Returns `true` if the top-level type is `text`.
+    ///
+    /// The type is compared case-insensitively.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// assert!(MediaType::parse("Text/HTML").unwrap().is_text());
+    /// assert!(!MediaType::parse("application/xhtml+xml").unwrap().is_text());
+    /// ```
+    #[must_use]
+    pub fn is_text(&self) -> bool {
+        self.ty == TEXT
+    }
+
+    /// Returns `true` if the top-level type is `image`.
+    #[must_use]
+    pub fn is_image(&self) -> bool {
+        self.ty == IMAGE
+    }
+
+    /// Returns `true` if the top-level type is `audio`.
+    #[must_use]
+    pub fn is_audio(&self) -> bool {
+        self.ty == AUDIO
+    }
+
+    /// Returns `true` if the top-level type is `video`.
+    #[must_use]
+    pub fn is_video(&self) -> bool {
+        self.ty == VIDEO
+    }
+
+    /// Returns `true` if the top-level type is `multipart`.
+    #[must_use]
+    pub fn is_multipart(&self) -> bool {
+        self.ty == MULTIPART
+    }
+
+    /// Returns `true` if the top-level type is `font`.
+    #[must_use]
+    pub fn is_font(&self) -> bool {
+        self.ty == FONT
+    }
+
+    /// Returns `true` if the top-level type is `model`.
+    #[must_use]
+    pub fn is_model(&self) -> bool {
+        self.ty == MODEL
+    }
+
+    /// Returns `true` if the top-level type is `message`.
+    #[must_use]
+    pub fn is_message(&self) -> bool {
+        self.ty == MESSAGE
+    }
+
+    /// Returns `true` if the top-level type is `haptics`.
+    #[must_use]
+    pub fn is_haptics(&self) -> bool {
+        self.ty == HAPTICS
+    }
+
+    /// Returns `true` if the top-level type is `example`.
+    #[must_use]
+    pub fn is_example(&self) -> bool {
+        self.ty == EXAMPLE
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the top-level type.
+    #[must_use]
+    pub fn top_level(&self) -> TopLevelType<'_> {
+        self.essence().top_level()
+    }
+
+    /// Returns `true` if the top-level type is `text`.
+    #[must_use]
+    pub fn is_text(&self) -> bool {
+        self.essence().is_text()
+    }
+
+    /// Returns `true` if the top-level type is `image`.
+    #[must_use]
+    pub fn is_image(&self) -> bool {
+        self.essence().is_image()
+    }
+
+    /// Returns `true` if the top-level type is `audio`.
+    #[must_use]
+    pub fn is_audio(&self) -> bool {
+        self.essence().is_audio()
+    }
+
+    /// Returns `true` if the top-level type is `video`.
+    #[must_use]
+    pub fn is_video(&self) -> bool {
+        self.essence().is_video()
+    }
+
+    /// Returns `true` if the top-level type is `multipart`.
+    #[must_use]
+    pub fn is_multipart(&self) -> bool {
+        self.essence().is_multipart()
+    }
+
+    /// Returns `true` if the top-level type is `font`.
+    #[must_use]
+    pub fn is_font(&self) -> bool {
+        self.essence().is_font()
+    }
+
+    /// Returns `true` if the top-level type is `model`.
+    #[must_use]
+    pub fn is_model(&self) -> bool {
+        self.essence().is_model()
+    }
+
+    /// Returns `true` if the top-level type is `message`.
+    #[must_use]
+    pub fn is_message(&self) -> bool {
+        self.essence().is_message()
+    }
+
+    /// Returns `true` if the top-level type is `haptics`.
+    #[must_use]
+    pub fn is_haptics(&self) -> bool {
+        self.essence().is_haptics()
+    }
+
+    /// Returns `true` if the top-level type is `example`.
+    #[must_use]
+    pub fn is_example(&self) -> bool {
+        self.essence().is_example()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn from_name() {
+        for ty in [
+            TopLevelType::Application,
+            TopLevelType::Audio,
+            TopLevelType::Example,
+            TopLevelType::Font,
+            TopLevelType::Haptics,
+            TopLevelType::Image,
+            TopLevelType::Message,
+            TopLevelType::Model,
+            TopLevelType::Multipart,
+            TopLevelType::Text,
+            TopLevelType::Video,
+        ] {
+            assert_eq!(TopLevelType::from_name(ty.name()), ty);
+            let upper = ty.name().as_str().to_ascii_uppercase();
+            assert_eq!(TopLevelType::from_name(Name::new(&upper).unwrap()), ty);
+        }
+        let chemical = Name::new("chemical").unwrap();
+        assert_eq!(
+            TopLevelType::from_name(chemical),
+            TopLevelType::Other(chemical)
+        );
+        assert_eq!(TopLevelType::Other(chemical).to_string(), "chemical");
+    }
+
+    #[test]
+    fn top_level() {
+        let media_type = MediaTypeBuf::from_str("Multipart/form-data; boundary=x").unwrap();
+        assert_eq!(media_type.top_level(), TopLevelType::Multipart);
+        assert_eq!(
+            MediaType::new(_STAR, _STAR).top_level(),
+            TopLevelType::Other(_STAR)
+        );
+    }
+
+    #[test]
+    fn predicates() {
+        let check = |s: &str| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            [
+                media_type.is_text(),
+                media_type.is_image(),
+                media_type.is_audio(),
+                media_type.is_video(),
+                media_type.is_multipart(),
+                media_type.is_font(),
+                media_type.is_model(),
+                media_type.is_message(),
+                media_type.is_haptics(),
+                media_type.is_example(),
+            ]
+            .iter()
+            .position(|&b| b)
+        };
+        assert_eq!(check("TEXT/plain"), Some(0));
+        assert_eq!(check("image/svg+xml"), Some(1));
+        assert_eq!(check("Audio/ogg"), Some(2));
+        assert_eq!(check("video/mp4"), Some(3));
+        assert_eq!(check("multipart/mixed; boundary=x"), Some(4));
+        assert_eq!(check("Font/woff2"), Some(5));
+        assert_eq!(check("model/gltf+json"), Some(6));
+        assert_eq!(check("message/rfc822"), Some(7));
+        assert_eq!(check("haptics/ivs"), Some(8));
+        assert_eq!(check("example/foo"), Some(9));
+        assert_eq!(check("application/json"), None);
+        assert_eq!(check("*/*"), None);
+    }
+}
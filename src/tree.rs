@@ -0,0 +1,253 @@
+use super::{media_type::*, media_type_buf::*, name::*};
+
+/// A registration tree of a media type.
+///
+/// The tree is determined by the facet of the subtype.
+/// ([RFC 6838](https://www.rfc-editor.org/rfc/rfc6838#section-3))
+///
+/// ```
+/// # use mediatype::{MediaType, RegistrationTree};
+/// let media_type = MediaType::parse("application/vnd.api+json").unwrap();
+/// assert_eq!(media_type.registration_tree(), RegistrationTree::Vendor);
+/// assert_eq!(media_type.subty_without_facet(), "api");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RegistrationTree {
+    /// The standards tree without a facet, like `text/plain`.
+    Standards,
+    /// The vendor tree with the `vnd.` facet.
+    Vendor,
+    /// The personal or vanity tree with the `prs.` facet.
+    Personal,
+    /// The unregistered tree with the `x.` facet, or the legacy `x-` prefix.
+    Unregistered,
+}
+
+impl RegistrationTree {
+    /// Returns the facet of the tree including the trailing `.`,
+    /// or `None` for the standards tree.
+    ///
+    /// ```
+    /// # use mediatype::RegistrationTree;
+    /// assert_eq!(RegistrationTree::Vendor.facet(), Some("vnd."));
+    /// assert_eq!(RegistrationTree::Standards.facet(), None);
+    /// ```
+    #[must_use]
+    pub const fn facet(&self) -> Option<&'static str> {
+        match self {
+            Self::Standards => None,
+            Self::Vendor => Some("vnd."),
+            Self::Personal => Some("prs."),
+            Self::Unregistered => Some("x."),
+        }
+    }
+}
+
+const FACETS: &[(&str, RegistrationTree)] = &[
+    ("vnd.", RegistrationTree::Vendor),
+    ("prs.", RegistrationTree::Personal),
+    ("x.", RegistrationTree::Unregistered),
+    ("x-", RegistrationTree::Unregistered),
+];
+
+// Splits the subtype into the registration tree and the rest.
+fn split_facet(subty: Name<'_>) -> (RegistrationTree, &str) {
+    let s = subty.as_str();
+    FACETS
+        .iter()
+        .find_map(|&(facet, tree)| {
+            let prefix = s.get(..facet.len())?;
+            (prefix.eq_ignore_ascii_case(facet) && s.len() > facet.len())
+                .then(|| (tree, &s[facet.len()..]))
+        })
+        .unwrap_or((RegistrationTree::Standards, s))
+}
+
+fn has_x_prefix(subty: Name<'_>) -> bool {
+    split_facet(subty).0 == RegistrationTree::Unregistered && subty.as_str()[1..].starts_with('-')
+}
+
+// Splits the subtype in the vendor tree into the producer and the product.
+fn split_vendor(subty: Name<'_>) -> Option<(&str, Option<&str>)> {
+    match split_facet(subty) {
+        (RegistrationTree::Vendor, rest) => Some(match rest.split_once('.') {
+            Some((producer, product)) => (producer, Some(product)),
+            None => (rest, None),
+        }),
+        _ => None,
+    }
+}
+
+impl<'a> MediaType<'a> {
+    /// Returns the registration tree of the subtype.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, RegistrationTree};
+    /// let tree = |s| MediaType::parse(s).unwrap().registration_tree();
+    /// assert_eq!(tree("text/plain"), RegistrationTree::Standards);
+    /// assert_eq!(tree("audio/prs.sid"), RegistrationTree::Personal);
+    /// assert_eq!(tree("application/x-www-form-urlencoded"), RegistrationTree::Unregistered);
+    /// ```
+    #[must_use]
+    pub fn registration_tree(&self) -> RegistrationTree {
+        split_facet(self.subty).0
+    }
+
+    /// Returns `true` if the subtype has the legacy `x-` prefix.
+    /// ([RFC 6648](https://www.rfc-editor.org/rfc/rfc6648))
+    ///
+    /// Unlike [`registration_tree`](#method.registration_tree), this does not include the `x.` facet.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// assert!(MediaType::parse("image/X-PNG").unwrap().has_unregistered_prefix());
+    /// assert!(!MediaType::parse("application/x.foo").unwrap().has_unregistered_prefix());
+    /// ```
+    #[must_use]
+    pub fn has_unregistered_prefix(&self) -> bool {
+        has_x_prefix(self.subty)
+    }
+
+    /// Returns the subtype without the facet of the registration tree.
+    ///
+    /// The suffix is not included.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("application/vnd.openstreetmap.data+xml").unwrap();
+    /// assert_eq!(media_type.subty_without_facet(), "openstreetmap.data");
+    /// ```
+    #[must_use]
+    pub fn subty_without_facet(&self) -> &'a str {
+        split_facet(self.subty).1
+    }
+
+    /// Returns the producer and the product of a subtype in the vendor tree,
+    /// or `None` for the other trees.
+    ///
+    /// The producer is the first segment after `vnd.`,
+    /// and the product is the rest of the subtype without the suffix, if any.
+    ///
+    /// ```
+    /// # use mediatype::MediaType;
+    /// let media_type = MediaType::parse("application/vnd.github.v3+json").unwrap();
+    /// assert_eq!(media_type.vendor(), Some(("github", Some("v3"))));
+    ///
+    /// let media_type = MediaType::parse("application/vnd.api+json").unwrap();
+    /// assert_eq!(media_type.vendor(), Some(("api", None)));
+    ///
+    /// let media_type = MediaType::parse("application/json").unwrap();
+    /// assert_eq!(media_type.vendor(), None);
+    /// ```
+    #[must_use]
+    pub fn vendor(&self) -> Option<(&'a str, Option<&'a str>)> {
+        split_vendor(self.subty)
+    }
+}
+
+impl MediaTypeBuf {
+    /// Returns the registration tree of the subtype.
+    #[must_use]
+    pub fn registration_tree(&self) -> RegistrationTree {
+        self.essence().registration_tree()
+    }
+
+    /// Returns `true` if the subtype has the legacy `x-` prefix.
+    /// ([RFC 6648](https://www.rfc-editor.org/rfc/rfc6648))
+    #[must_use]
+    pub fn has_unregistered_prefix(&self) -> bool {
+        self.essence().has_unregistered_prefix()
+    }
+
+    /// Returns the subtype without the facet of the registration tree.
+    ///
+    /// The suffix is not included.
+    #[must_use]
+    pub fn subty_without_facet(&self) -> &str {
+        self.essence().subty_without_facet()
+    }
+
+    /// Returns the producer and the product of a subtype in the vendor tree,
+    /// or `None` for the other trees.
+    ///
+    /// See [`MediaType::vendor`](./struct.MediaType.html#method.vendor) for the details.
+    #[must_use]
+    pub fn vendor(&self) -> Option<(&str, Option<&str>)> {
+        self.essence().vendor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn registration_tree() {
+        let split = |s| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            (
+                media_type.registration_tree(),
+                media_type.subty_without_facet().to_string(),
+            )
+        };
+        assert_eq!(
+            split("text/plain"),
+            (RegistrationTree::Standards, "plain".into())
+        );
+        assert_eq!(
+            split("application/VND.MS-Excel"),
+            (RegistrationTree::Vendor, "MS-Excel".into())
+        );
+        assert_eq!(
+            split("application/prs.cww"),
+            (RegistrationTree::Personal, "cww".into())
+        );
+        assert_eq!(
+            split("application/x.foo+json"),
+            (RegistrationTree::Unregistered, "foo".into())
+        );
+        assert_eq!(
+            split("application/x-tar"),
+            (RegistrationTree::Unregistered, "tar".into())
+        );
+        assert_eq!(
+            split("application/vnd"),
+            (RegistrationTree::Standards, "vnd".into())
+        );
+        assert_eq!(
+            split("application/vndx.a"),
+            (RegistrationTree::Standards, "vndx.a".into())
+        );
+    }
+
+    #[test]
+    fn has_unregistered_prefix() {
+        let check = |s| MediaTypeBuf::from_str(s).unwrap().has_unregistered_prefix();
+        assert!(check("application/x-www-form-urlencoded"));
+        assert!(check("image/X-icon"));
+        assert!(!check("application/x.foo"));
+        assert!(!check("application/xml"));
+        assert!(!check("application/x-"));
+    }
+
+    #[test]
+    fn vendor() {
+        let vendor = |s| {
+            let media_type = MediaTypeBuf::from_str(s).unwrap();
+            media_type
+                .vendor()
+                .map(|(producer, product)| (producer.to_string(), product.map(str::to_string)))
+        };
+        assert_eq!(
+            vendor("application/vnd.github.v3.raw+json"),
+            Some(("github".into(), Some("v3.raw".into())))
+        );
+        assert_eq!(
+            vendor("application/VND.MS-Excel"),
+            Some(("MS-Excel".into(), None))
+        );
+        assert_eq!(vendor("application/prs.cww"), None);
+        assert_eq!(vendor("text/plain"), None);
+    }
+}
@@ -32,16 +32,14 @@ impl<'a> Value<'a> {
     /// Constructs a `Value`.
     ///
     /// If the string is not valid as a value, returns `None`.
+    /// Empty values, both unquoted and quoted, are accepted as the parser does.
     #[must_use]
     pub fn new(s: &'a str) -> Option<Self> {
-        if let Some(quoted) = s.strip_prefix('\"') {
-            if quoted.len() > 1 && parse_quoted_value(quoted) == Ok(quoted.len()) {
-                return Some(Self(s));
-            }
-        } else if is_restricted_str(s) {
-            return Some(Self(s));
+        if is_value(s) {
+            Some(Self(s))
+        } else {
+            None
         }
-        None
     }
 
     /// Returns the underlying string.
@@ -50,10 +48,32 @@ impl<'a> Value<'a> {
         self.0
     }
 
+    /// Returns `true` if the value is a quoted string.
+    ///
+    /// ```
+    /// # use mediatype::Value;
+    /// assert!(Value::new("\"UTF-8\"").unwrap().is_quoted());
+    /// assert!(!Value::new("UTF-8").unwrap().is_quoted());
+    /// ```
+    #[must_use]
+    pub const fn is_quoted(&self) -> bool {
+        matches!(self.0.as_bytes().first(), Some(b'"'))
+    }
+
     /// Returns the unquoted string.
+    ///
+    /// Quoted strings may contain spaces, `;` and `=`, and escape characters with `\`.
+    ///
+    /// ```
+    /// # use mediatype::{MediaType, Name, ReadParams};
+    /// let media_type = MediaType::parse(r#"text/plain; title="hello \"world\"; a=b""#).unwrap();
+    /// let title = media_type.get_param(Name::new("title").unwrap()).unwrap();
+    /// assert_eq!(title.as_str(), r#""hello \"world\"; a=b""#);
+    /// assert_eq!(title.unquoted_str(), r#"hello "world"; a=b"#);
+    /// ```
     #[must_use]
-    pub fn unquoted_str(&self) -> Cow<'_, str> {
-        if self.0.starts_with('"') {
+    pub fn unquoted_str(&self) -> Cow<'a, str> {
+        if self.is_quoted() {
             let inner = &self.0[1..self.0.len() - 1];
             if inner.contains('\\') {
                 let mut s = String::with_capacity(inner.len());
@@ -83,6 +103,9 @@ impl<'a> Value<'a> {
 
     /// Generates a quoted string if necessary.
     ///
+    /// An empty string is quoted, as an unquoted value must not be empty
+    /// ([RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#section-5.6.2)).
+    ///
     /// ```
     /// # use mediatype::Value;
     /// assert_eq!(Value::quote("UTF-8"), "UTF-8");
@@ -91,10 +114,11 @@ impl<'a> Value<'a> {
     ///     Value::quote(r#" "What's wrong?" "#),
     ///     "\" \\\"What's wrong\\?\\\" \""
     /// );
+    /// assert_eq!(Value::quote(""), "\"\"");
     /// ```
     #[must_use]
     pub fn quote(s: &str) -> Cow<'_, str> {
-        if is_restricted_str(s) {
+        if !s.is_empty() && is_restricted_str(s) {
             Cow::Borrowed(s)
         } else {
             let inner = s.chars().flat_map(|c| {
@@ -109,6 +133,30 @@ impl<'a> Value<'a> {
         }
     }
 
+    /// Generates a quoted string if necessary, rejecting the strings which can not be a value.
+    ///
+    /// Unlike [`quote`](#method.quote), this returns `None` for control characters
+    /// except the horizontal tab, which are not allowed even in quoted strings
+    /// ([RFC 7230](https://www.rfc-editor.org/rfc/rfc7230#section-3.2.6)).
+    /// A returned string is always valid for [`Value::new`](#method.new).
+    ///
+    /// ```
+    /// # use mediatype::Value;
+    /// let quoted = Value::try_quote("my file.txt").unwrap();
+    /// assert_eq!(quoted, "\"my file.txt\"");
+    /// assert_eq!(Value::new(&quoted).unwrap().unquoted_str(), "my file.txt");
+    /// assert_eq!(Value::try_quote("a\r\nb"), None);
+    /// assert_eq!(Value::try_quote("").unwrap(), "\"\"");
+    /// ```
+    #[must_use]
+    pub fn try_quote(s: &str) -> Option<Cow<'_, str>> {
+        if s.chars().any(|c| c.is_ascii_control() && c != '\t') {
+            None
+        } else {
+            Some(Self::quote(s))
+        }
+    }
+
     pub(crate) const fn new_unchecked(s: &'a str) -> Self {
         Self(s)
     }
@@ -0,0 +1,64 @@
+use super::{name::*, value::*};
+use std::ops::Range;
+
+/// A trait for traversing the components of a media type.
+///
+/// Each method receives a component and its byte range (span) in the string form of the media type.
+/// All methods do nothing by default.
+///
+/// ```
+/// use mediatype::{MediaTypeBuf, MediaTypeVisitor, Name, Value};
+/// use std::ops::Range;
+///
+/// #[derive(Default)]
+/// struct Highlighter(Vec<(&'static str, Range<usize>)>);
+///
+/// impl MediaTypeVisitor for Highlighter {
+///     fn visit_type(&mut self, _ty: Name, span: Range<usize>) {
+///         self.0.push(("type", span));
+///     }
+///
+///     fn visit_param(
+///         &mut self,
+///         _name: Name,
+///         name_span: Range<usize>,
+///         _value: Value,
+///         value_span: Range<usize>,
+///     ) {
+///         self.0.push(("name", name_span));
+///         self.0.push(("value", value_span));
+///     }
+/// }
+///
+/// let media_type: MediaTypeBuf = "text/plain; charset=UTF-8".parse().unwrap();
+/// let mut highlighter = Highlighter::default();
+/// media_type.visit(&mut highlighter);
+/// assert_eq!(
+///     highlighter.0,
+///     [("type", 0..4), ("name", 12..19), ("value", 20..25)]
+/// );
+/// ```
+pub trait MediaTypeVisitor {
+    /// Visits the top-level type.
+    fn visit_type(&mut self, _ty: Name, _span: Range<usize>) {}
+
+    /// Visits the subtype.
+    fn visit_subtype(&mut self, _subty: Name, _span: Range<usize>) {}
+
+    /// Visits the suffix.
+    ///
+    /// This method is not called if there is no suffix.
+    fn visit_suffix(&mut self, _suffix: Name, _span: Range<usize>) {}
+
+    /// Visits a parameter.
+    ///
+    /// This method is called for each parameter in the original order.
+    fn visit_param(
+        &mut self,
+        _name: Name,
+        _name_span: Range<usize>,
+        _value: Value,
+        _value_span: Range<usize>,
+    ) {
+    }
+}
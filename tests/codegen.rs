@@ -23,6 +23,8 @@ fn generated_code_is_fresh() {
             VALUES_HEADER,
         );
 
+    fresh = fresh && generate_registry("src/consts/registry.txt", "src/consts/registry.rs");
+
     if !fresh {
         panic!("generated code is not fresh, please commit updates");
     }
@@ -40,7 +42,7 @@ fn generate_consts(
         .map(|&(pf, name, comment)| (pf, name, comment, String::with_capacity(1024)))
         .collect::<Vec<_>>();
 
-    let input = fs::read_to_string(&input).expect("failed to read input file");
+    let input = fs::read_to_string(input).expect("failed to read input file");
     for line in input.lines() {
         let (ident, name) = if let Some(pair) = line.split_once('=') {
             pair
@@ -85,6 +87,62 @@ fn generate_consts(
     old == new
 }
 
+fn generate_registry(input: &str, dst: &str) -> bool {
+    let mut new = String::with_capacity(1024 * 256);
+    new.push_str(REGISTRY_HEADER);
+    new.push('\n');
+
+    let input = fs::read_to_string(input).expect("failed to read input file");
+    let mut current = None;
+    for line in input.lines() {
+        let (ty, subty) = line.split_once('/').expect("invalid media type");
+        let (subty, suffix) = match subty.rsplit_once('+') {
+            Some((subty, suffix)) if !suffix.is_empty() => (subty, Some(suffix)),
+            _ => (subty, None),
+        };
+        for name in [ty, subty].iter().chain(&suffix) {
+            assert!(
+                mediatype::Name::new(name).is_some(),
+                "invalid name: {}",
+                name
+            );
+        }
+
+        if current != Some(ty) {
+            if current.is_some() {
+                new.push_str("}\n");
+            }
+            writeln!(new, "\n/// Media types with the `{}` top-level type.", ty).unwrap();
+            writeln!(new, "pub mod {} {{", ty).unwrap();
+            current = Some(ty);
+        }
+
+        let ident = upper_snake_case(&line[ty.len() + 1..].replace('+', "_plus_"));
+        let ident = ident.trim_end_matches('_');
+        let suffix = suffix.map_or_else(
+            || "None".to_string(),
+            |suffix| format!("Some(crate::Name::new_unchecked(\"{}\"))", suffix),
+        );
+        writeln!(new, "    /// `{}`", line).unwrap();
+        writeln!(
+            new,
+            "    pub const {}: crate::MediaType = crate::MediaType::from_parts(crate::Name::new_unchecked(\"{}\"), crate::Name::new_unchecked(\"{}\"), {}, &[]);",
+            ident, ty, subty, suffix
+        )
+        .unwrap();
+    }
+    if current.is_some() {
+        new.push_str("}\n");
+    }
+
+    let old = fs::read_to_string(dst).unwrap_or_default();
+    if old != new {
+        fs::write(dst, &new).expect("failed to write destination file");
+    }
+
+    old == new
+}
+
 fn upper_snake_case(s: &str) -> String {
     let s = s
         .split_inclusive(char::is_uppercase)
@@ -124,3 +182,8 @@ const VALUES_HEADER: &str = r#"//! Predefined parameter values, @generated in te
 //! # Sources
 //! - <https://www.iana.org/assignments/character-sets/character-sets.xhtml>
 //! - <https://datatracker.ietf.org/doc/html/rfc3676>"#;
+
+const REGISTRY_HEADER: &str = r#"//! Registered media types, @generated in tests/codegen.rs
+//!
+//! # Sources
+//! - <https://www.iana.org/assignments/media-types/media-types.xhtml>"#;